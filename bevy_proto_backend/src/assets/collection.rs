@@ -0,0 +1,210 @@
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+
+use bevy::asset::{Asset, AssetIo, AssetIoError, AssetServer, Handle};
+use bevy::log::error;
+use bevy::prelude::{Resource, World};
+use bevy::utils::HashMap;
+
+use crate::assets::ProtoAsset;
+
+impl<T: Asset> ProtoAsset<T> {
+    /// Loads every asset in the given folder, sorted by file name.
+    ///
+    /// This is used by fields marked with `#[schematic(asset(collection))]`.
+    /// See [`read_collection_paths`] for details on how the files are sorted.
+    ///
+    /// The folder's contents are only read the first time and then cached.
+    /// Collections of preloaded fields are instead resolved when their prototype is loaded
+    /// (and again whenever it's reloaded), so this never needs to read them.
+    ///
+    /// If the folder cannot be read, an error is logged and an empty list is returned.
+    pub fn load_collection(world: &mut World, path: &str) -> Vec<Handle<T>> {
+        let path = Path::new(path);
+        let cached = world
+            .get_resource::<ProtoAssetCollections>()
+            .and_then(|collections| collections.get(path))
+            .map(<[PathBuf]>::to_vec);
+
+        let paths = match cached {
+            Some(paths) => paths,
+            None => {
+                let asset_io = world.resource::<AssetServer>().asset_io();
+                let paths = read_collection_paths(asset_io, path).unwrap_or_else(|err| {
+                    error!("could not load asset collection {:?}: {}", path, err);
+                    Vec::new()
+                });
+                world
+                    .get_resource_or_insert_with(ProtoAssetCollections::default)
+                    .insert(path.to_path_buf(), paths.clone());
+                paths
+            }
+        };
+
+        let asset_server = world.resource::<AssetServer>();
+        paths
+            .into_iter()
+            .map(|path| asset_server.load(path))
+            .collect()
+    }
+}
+
+/// Resource caching the sorted contents of each asset collection folder.
+///
+/// Collections are added when a prototype with preloaded collections is registered,
+/// or when a lazy collection is first [loaded].
+///
+/// [loaded]: ProtoAsset::load_collection
+#[derive(Resource, Default)]
+pub(crate) struct ProtoAssetCollections {
+    collections: HashMap<PathBuf, Vec<PathBuf>>,
+}
+
+impl ProtoAssetCollections {
+    /// Returns the sorted paths within the given collection folder, if it's been resolved.
+    pub fn get(&self, path: &Path) -> Option<&[PathBuf]> {
+        self.collections.get(path).map(Vec::as_slice)
+    }
+
+    /// Caches the sorted paths within the given collection folder.
+    pub fn insert(&mut self, path: PathBuf, paths: Vec<PathBuf>) {
+        self.collections.insert(path, paths);
+    }
+}
+
+/// Returns the paths of all files directly within the given folder.
+///
+/// Paths are sorted by file name such that runs of digits are compared numerically,
+/// meaning `frames/2.png` comes before `frames/10.png`.
+/// Subfolders are ignored.
+pub(crate) fn read_collection_paths(
+    asset_io: &dyn AssetIo,
+    path: &Path,
+) -> Result<Vec<PathBuf>, AssetIoError> {
+    let mut paths = asset_io
+        .read_directory(path)?
+        .filter(|path| !asset_io.is_dir(path))
+        .collect::<Vec<_>>();
+
+    paths.sort_by(|a, b| {
+        let a = a.file_name().unwrap_or_default().to_string_lossy();
+        let b = b.file_name().unwrap_or_default().to_string_lossy();
+        natural_cmp(&a, &b)
+    });
+
+    Ok(paths)
+}
+
+/// Compares two strings such that runs of digits are compared by their numeric value.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+
+    loop {
+        match (a.peek().copied(), b.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let mut take_number = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.peek().copied().filter(char::is_ascii_digit) {
+                        digits.push(c);
+                        chars.next();
+                    }
+                    digits
+                };
+
+                let x = take_number(&mut a);
+                let y = take_number(&mut b);
+                let ordering = x
+                    .trim_start_matches('0')
+                    .len()
+                    .cmp(&y.trim_start_matches('0').len())
+                    .then_with(|| x.trim_start_matches('0').cmp(y.trim_start_matches('0')))
+                    .then_with(|| x.len().cmp(&y.len()));
+
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a.next();
+                b.next();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::{AddAsset, AssetPlugin};
+    use bevy::core::TaskPoolPlugin;
+    use bevy::prelude::App;
+    use bevy::reflect::{TypePath, TypeUuid};
+
+    use super::*;
+
+    #[derive(TypeUuid, TypePath)]
+    #[uuid = "9e2c4a6b-1d3f-4e5a-8b7c-0f1e2d3c4b5a"]
+    struct Frame;
+
+    #[test]
+    fn should_load_cached_collections() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .add_asset::<Frame>();
+
+        // The folder doesn't exist, so this must come from the cache
+        let mut collections = ProtoAssetCollections::default();
+        collections.insert(
+            PathBuf::from("frames"),
+            vec![
+                PathBuf::from("frames/2.png"),
+                PathBuf::from("frames/10.png"),
+            ],
+        );
+        app.insert_resource(collections);
+
+        let frames = ProtoAsset::<Frame>::load_collection(&mut app.world, "frames")
+            .iter()
+            .map(Handle::id)
+            .collect::<Vec<_>>();
+
+        let asset_server = app.world.resource::<AssetServer>();
+        assert_eq!(
+            vec![
+                asset_server.get_handle::<Frame, _>("frames/2.png").id(),
+                asset_server.get_handle::<Frame, _>("frames/10.png").id(),
+            ],
+            frames
+        );
+    }
+
+    #[test]
+    fn should_sort_numbers_naturally() {
+        let mut names = vec![
+            "10.png",
+            "2.png",
+            "1.png",
+            "frame_02.png",
+            "frame_1.png",
+            "0.png",
+        ];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            vec![
+                "0.png",
+                "1.png",
+                "2.png",
+                "10.png",
+                "frame_1.png",
+                "frame_02.png"
+            ],
+            names
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::proto::Prototypical;
 use bevy::asset::Handle;
-use bevy::prelude::Event;
+use bevy::prelude::{Event, EventReader};
 
 /// Asset lifecycle events for [prototype] assets.
 ///
@@ -84,3 +84,43 @@ impl<T: Prototypical> ProtoAssetEvent<T> {
         }
     }
 }
+
+/// Extension trait for reading [`ProtoAssetEvent`]s.
+///
+/// # Example
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_proto_backend::assets::{ProtoAssetEvent, ProtoAssetEventReader};
+/// # use bevy_proto_backend::proto::Prototypical;
+/// fn on_player_event<T: Prototypical<Id = String>>(mut events: EventReader<ProtoAssetEvent<T>>) {
+///   for event in events.for_prototype("Player") {
+///     // ...
+///   }
+/// }
+/// ```
+pub trait ProtoAssetEventReader<T: Prototypical> {
+    /// Returns an iterator over the unread events for the [prototype] with the given ID.
+    ///
+    /// Events for other prototypes are consumed and skipped.
+    ///
+    /// [prototype]: Prototypical
+    fn for_prototype<'a, I>(
+        &'a mut self,
+        id: I,
+    ) -> Box<dyn Iterator<Item = &'a ProtoAssetEvent<T>> + 'a>
+    where
+        I: for<'b> PartialEq<&'b T::Id> + 'a;
+}
+
+impl<'w, 's, T: Prototypical> ProtoAssetEventReader<T> for EventReader<'w, 's, ProtoAssetEvent<T>> {
+    fn for_prototype<'a, I>(
+        &'a mut self,
+        id: I,
+    ) -> Box<dyn Iterator<Item = &'a ProtoAssetEvent<T>> + 'a>
+    where
+        I: for<'b> PartialEq<&'b T::Id> + 'a,
+    {
+        Box::new(self.iter().filter(move |event| id == event.id()))
+    }
+}
@@ -78,6 +78,12 @@
 //! }
 //! ```
 //!
+//! Asset schematics may also contain their own inline assets,
+//! using `#[asset_schematic(asset(inline))]`, to any depth.
+//! Each nested asset is given an ID derived from that of its parent and its field,
+//! so sibling assets never collide and re-applying the schematic reuses the same assets
+//! (unless marked `unique`).
+//!
 //! Asset schematics have a lot of moving parts that need to be registered in the app.
 //! To make things easier, this crate comes with an [extension trait] which can be used
 //! to automatically register all of the necessary types.
@@ -99,11 +105,13 @@
 //! [extension trait]: AssetSchematicAppExt
 
 pub use bevy_proto_derive::AssetSchematic;
+pub(crate) use collection::{read_collection_paths, ProtoAssetCollections};
 pub use event::*;
 pub use extension::*;
 pub use proto::*;
 pub use schematic::*;
 
+mod collection;
 mod event;
 mod extension;
 mod proto;
@@ -5,7 +5,7 @@ use crate::schematics::{
 };
 use bevy::asset::{Asset, AssetServer, Assets, Handle, HandleId};
 use bevy::prelude::Reflect;
-use bevy::reflect::TypeUuid;
+use bevy::reflect::{FromType, ReflectRef, TypeRegistryInternal, TypeUuid};
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
 
@@ -18,6 +18,7 @@ use std::hash::{Hash, Hasher};
 /// [`Schematic::Input`]: crate::schematics::Schematic::Input
 /// [derive macro]: bevy_proto_derive::Schematic
 #[derive(Reflect)]
+#[reflect(ProtoAsset)]
 pub enum ProtoAsset<T: Asset> {
     /// The path to an asset relative to the `assets` directory.
     AssetPath(String),
@@ -81,7 +82,10 @@ impl<T: Asset> From<Handle<T>> for ProtoAsset<T> {
 impl<T: Asset> FromSchematicInput<ProtoAsset<T>> for Handle<T> {
     fn from_input(input: ProtoAsset<T>, _id: SchematicId, context: &mut SchematicContext) -> Self {
         match input {
-            ProtoAsset::AssetPath(path) => context.world().resource::<AssetServer>().load(path),
+            ProtoAsset::AssetPath(path) => {
+                let handle = context.world().resource::<AssetServer>().load(path);
+                context.lazy_asset_handle(handle)
+            }
             ProtoAsset::Handle(handle) => {
                 context.world().resource::<AssetServer>().get_handle(handle)
             }
@@ -114,6 +118,7 @@ impl<T: Asset> FromSchematicPreloadInput<ProtoAsset<T>> for Handle<T> {
 /// [`Schematic::Input`]: crate::schematics::Schematic::Input
 /// [derive macro]: bevy_proto_derive::Schematic
 #[derive(Reflect)]
+#[reflect(ProtoAsset)]
 pub enum InlinableProtoAsset<T: AssetSchematic> {
     /// The input to an [`AssetSchematic`] of type `T`.
     ///
@@ -203,3 +208,214 @@ impl<T: PreloadAssetSchematic> FromSchematicPreloadInput<InlinableProtoAsset<T>>
         }
     }
 }
+
+/// [Type data] used to identify [`ProtoAsset`] and [`InlinableProtoAsset`] types
+/// through reflection.
+///
+/// This is registered automatically along with those types.
+///
+/// [Type data]: bevy::reflect::TypeData
+#[derive(Clone)]
+pub struct ReflectProtoAsset;
+
+impl<T: Asset> FromType<ProtoAsset<T>> for ReflectProtoAsset {
+    fn from_type() -> Self {
+        Self
+    }
+}
+
+impl<T: AssetSchematic> FromType<InlinableProtoAsset<T>> for ReflectProtoAsset {
+    fn from_type() -> Self {
+        Self
+    }
+}
+
+/// Recursively collects the paths of all lazy (i.e. non-preloaded) assets within the given value.
+///
+/// These are any [`ProtoAsset::AssetPath`] or [`InlinableProtoAsset::AssetPath`] values,
+/// which are otherwise only loaded once the containing schematic is applied.
+/// They are identified by their [`ReflectProtoAsset`] type data,
+/// so their types must be registered in the given registry.
+pub(crate) fn collect_lazy_asset_paths(
+    value: &dyn Reflect,
+    registry: &TypeRegistryInternal,
+    paths: &mut Vec<String>,
+) {
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            for field in value.iter_fields() {
+                collect_lazy_asset_paths(field, registry, paths);
+            }
+        }
+        ReflectRef::TupleStruct(value) => {
+            for field in value.iter_fields() {
+                collect_lazy_asset_paths(field, registry, paths);
+            }
+        }
+        ReflectRef::Tuple(value) => {
+            for field in value.iter_fields() {
+                collect_lazy_asset_paths(field, registry, paths);
+            }
+        }
+        ReflectRef::List(value) => {
+            for item in value.iter() {
+                collect_lazy_asset_paths(item, registry, paths);
+            }
+        }
+        ReflectRef::Array(value) => {
+            for item in value.iter() {
+                collect_lazy_asset_paths(item, registry, paths);
+            }
+        }
+        ReflectRef::Map(value) => {
+            for (_, item) in value.iter() {
+                collect_lazy_asset_paths(item, registry, paths);
+            }
+        }
+        ReflectRef::Enum(value) => {
+            // Look up by name so that dynamic values are also recognized
+            let is_proto_asset = registry
+                .get_with_name(value.type_name())
+                .map(|registration| registration.data::<ReflectProtoAsset>().is_some())
+                .unwrap_or_default();
+
+            if is_proto_asset && value.variant_name() == "AssetPath" {
+                if let Some(path) = value
+                    .field_at(0)
+                    .and_then(|path| path.downcast_ref::<String>())
+                {
+                    paths.push(path.clone());
+                }
+                return;
+            }
+
+            for field in value.iter_fields() {
+                collect_lazy_asset_paths(field.value(), registry, paths);
+            }
+        }
+        ReflectRef::Value(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use bevy::asset::{AddAsset, AssetPlugin};
+    use bevy::core::TaskPoolPlugin;
+    use bevy::prelude::App;
+    use indexmap::IndexSet;
+
+    use crate::schematics::{FromSchematicInput, SchematicContext, SchematicId};
+    use crate::tree::EntityTree;
+    use bevy_proto_derive::AssetSchematic;
+
+    use super::*;
+
+    #[derive(AssetSchematic, Reflect, TypeUuid)]
+    #[uuid = "8d3b7f0e-1c2a-4e5f-9b6d-7a8c9e0f1a2b"]
+    struct Texture {
+        size: u32,
+    }
+
+    #[derive(AssetSchematic, Reflect, TypeUuid)]
+    #[uuid = "2e4f6a8c-0b1d-4f3e-8a5c-7e9b1d3f5a7c"]
+    #[asset_schematic(input(vis = pub(crate)))]
+    struct Level {
+        #[asset_schematic(asset(inline))]
+        background: Handle<Texture>,
+        #[asset_schematic(asset(inline))]
+        foreground: Handle<Texture>,
+    }
+
+    #[test]
+    fn should_load_nested_inline_assets() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .add_asset::<Texture>()
+            .add_asset::<Level>();
+
+        let input = InlinableProtoAsset::<Level>::Asset(LevelInput {
+            background: InlinableProtoAsset::Asset(Texture { size: 1 }),
+            foreground: InlinableProtoAsset::Asset(Texture { size: 2 }),
+        });
+
+        let prototypes = IndexSet::new();
+        let tree = EntityTree::single(None, &prototypes);
+        let mut context = SchematicContext::new(&mut app.world, &tree);
+        let id = SchematicId::new(HandleId::random::<Level>(), TypeId::of::<Level>());
+        let handle = Handle::<Level>::from_input(input, id, &mut context);
+
+        let level = app.world.resource::<Assets<Level>>().get(&handle).unwrap();
+        let textures = app.world.resource::<Assets<Texture>>();
+
+        assert_ne!(level.background.id(), level.foreground.id());
+        assert_eq!(1, textures.get(&level.background).unwrap().size);
+        assert_eq!(2, textures.get(&level.foreground).unwrap().size);
+    }
+
+    /// An enum that looks like a [`ProtoAsset`] but is not one.
+    #[derive(Reflect)]
+    enum Imposter {
+        AssetPath(String),
+    }
+
+    #[derive(Reflect)]
+    struct Lazy {
+        texture: ProtoAsset<Texture>,
+        textures: Vec<Option<ProtoAsset<Texture>>>,
+        inline: InlinableProtoAsset<Texture>,
+        imposter: Imposter,
+    }
+
+    #[test]
+    fn should_collect_lazy_asset_paths() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<ProtoAsset<Texture>>();
+        registry.register::<InlinableProtoAsset<Texture>>();
+        registry.register::<Imposter>();
+
+        let value = Lazy {
+            texture: ProtoAsset::AssetPath(String::from("a.png")),
+            textures: vec![
+                Some(ProtoAsset::AssetPath(String::from("b.png"))),
+                None,
+                Some(ProtoAsset::Handle(Handle::default())),
+            ],
+            inline: InlinableProtoAsset::AssetPath(String::from("c.png")),
+            imposter: Imposter::AssetPath(String::from("d.png")),
+        };
+
+        let mut paths = Vec::new();
+        collect_lazy_asset_paths(&value, &registry, &mut paths);
+        assert_eq!(vec!["a.png", "b.png", "c.png"], paths);
+    }
+
+    /// Converts a lazy [`ProtoAsset`] path with [weak lazy assets] enabled.
+    ///
+    /// [weak lazy assets]: crate::proto::Config::weak_lazy_assets
+    fn load_weak_lazy(app: &mut App, path: &str) -> Handle<Texture> {
+        let prototypes = IndexSet::new();
+        let tree = EntityTree::single(None, &prototypes);
+        let mut context = SchematicContext::new(&mut app.world, &tree).with_weak_lazy_assets(true);
+        let id = SchematicId::new(HandleId::random::<Texture>(), TypeId::of::<Texture>());
+        Handle::<Texture>::from_input(ProtoAsset::AssetPath(path.to_string()), id, &mut context)
+    }
+
+    #[test]
+    fn should_only_weaken_loaded_lazy_assets() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .add_asset::<Texture>();
+
+        // Weakening a handle that is still loading would drop the load
+        let loading = load_weak_lazy(&mut app, "loading.png");
+        assert!(loading.is_strong());
+
+        app.world
+            .resource_mut::<Assets<Texture>>()
+            .set_untracked(HandleId::from("loaded.png"), Texture { size: 1 });
+        let loaded = load_weak_lazy(&mut app, "loaded.png");
+        assert!(loaded.is_weak());
+    }
+}
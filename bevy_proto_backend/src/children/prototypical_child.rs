@@ -28,6 +28,18 @@ pub trait PrototypicalChild<T: Prototypical> {
     fn merge_key(&self) -> Option<&Self::Key> {
         None
     }
+
+    /// The optional local ID for this child.
+    ///
+    /// Local IDs can be used to reference this child's entity from anywhere in
+    /// the same tree (see [`ProtoEntity::Local`]).
+    /// They must be unique across that entire tree, including any children defined in other files,
+    /// otherwise the prototype will fail to register.
+    ///
+    /// [`ProtoEntity::Local`]: crate::tree::ProtoEntity::Local
+    fn local_id(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// Type alias for [`PrototypicalChild::Key`].
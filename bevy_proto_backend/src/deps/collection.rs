@@ -1,15 +1,21 @@
 use std::fmt::{Debug, Formatter};
+use std::path::{Path, PathBuf};
 
 use bevy::asset::{Asset, AssetPath, Handle, HandleUntyped, LoadContext, LoadedAsset};
+use bevy::log::error;
 use bevy::utils::hashbrown::hash_map::Iter;
 use bevy::utils::HashMap;
 
+use crate::assets::read_collection_paths;
+
 /// A collection of dependencies for a [prototype].
 ///
 /// [prototype]: crate::proto::Prototypical
 #[derive(Default)]
 pub struct Dependencies {
     deps: HashMap<AssetPath<'static>, HandleUntyped>,
+    /// The sorted contents of each asset collection folder.
+    collections: HashMap<PathBuf, Vec<PathBuf>>,
 }
 
 impl Dependencies {
@@ -38,6 +44,15 @@ impl Dependencies {
     /// Merge this [`Dependencies`] with another.
     pub fn combine(&mut self, other: Dependencies) {
         self.deps.extend(other.deps);
+        self.collections.extend(other.collections);
+    }
+
+    /// Returns an iterator over the asset collection folders added with
+    /// [`DependenciesBuilder::add_folder_dependency`], along with their sorted contents.
+    pub fn collections(&self) -> impl Iterator<Item = (&Path, &[PathBuf])> {
+        self.collections
+            .iter()
+            .map(|(path, paths)| (path.as_path(), paths.as_slice()))
     }
 
     /// Returns an iterator over the dependencies.
@@ -67,6 +82,7 @@ impl Debug for Dependencies {
 /// Builder used to construct [`Dependencies`].
 pub struct DependenciesBuilder<'a, 'ctx> {
     deps: HashMap<AssetPath<'static>, HandleUntyped>,
+    collections: HashMap<PathBuf, Vec<PathBuf>>,
     ctx: &'a mut LoadContext<'ctx>,
 }
 
@@ -74,13 +90,17 @@ impl<'a, 'ctx> DependenciesBuilder<'a, 'ctx> {
     pub fn new(ctx: &'a mut LoadContext<'ctx>) -> Self {
         Self {
             deps: HashMap::new(),
+            collections: HashMap::new(),
             ctx,
         }
     }
 
     /// Finalize this builder into a new [`Dependencies`] struct.
     pub fn build(self) -> Dependencies {
-        Dependencies { deps: self.deps }
+        Dependencies {
+            deps: self.deps,
+            collections: self.collections,
+        }
     }
 
     /// Add a new dependency at the given asset path.
@@ -90,6 +110,31 @@ impl<'a, 'ctx> DependenciesBuilder<'a, 'ctx> {
         handle
     }
 
+    /// Add every asset in the given folder as a dependency.
+    ///
+    /// The returned handles are sorted by file name, with numbers compared by value
+    /// (e.g. `2.png` comes before `10.png`).
+    /// Subfolders are ignored.
+    ///
+    /// The folder's sorted contents are also recorded in the built [`Dependencies`],
+    /// so that the folder doesn't need to be read again when the prototype is applied.
+    ///
+    /// If the folder cannot be read, an error is logged and an empty list is returned.
+    pub fn add_folder_dependency<T: Asset, P: AsRef<Path>>(&mut self, path: P) -> Vec<Handle<T>> {
+        let path = path.as_ref();
+        let paths = read_collection_paths(self.ctx.asset_io(), path).unwrap_or_else(|err| {
+            error!("could not load asset collection {:?}: {}", path, err);
+            Vec::new()
+        });
+
+        let handles = paths
+            .iter()
+            .map(|path| self.add_dependency(path.clone()))
+            .collect();
+        self.collections.insert(path.to_path_buf(), paths);
+        handles
+    }
+
     /// Add a labeled asset.
     pub fn add_asset<T: Asset, L: AsRef<str>>(&mut self, asset: T, label: L) -> Handle<T> {
         self.ctx
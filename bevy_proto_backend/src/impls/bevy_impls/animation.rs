@@ -1,3 +1,121 @@
+use bevy::animation::{AnimationClip, EntityPath, Keyframes, VariableCurve};
 use bevy::app::App;
+use bevy::core::Name;
+use bevy::math::{Quat, Vec3};
+use bevy::reflect::Reflect;
 
-pub(super) fn register(_app: &mut App) {}
+use crate::assets::AssetSchematicAppExt;
+use bevy_proto_derive::impl_external_asset_schematic;
+
+pub(super) fn register(app: &mut App) {
+    app.register_asset_schematic::<AnimationClip>()
+        .register_type::<AnimationCurveInput>()
+        .register_type::<KeyframesInput>()
+        .register_type::<Vec<AnimationCurveInput>>()
+        .register_type::<Vec<String>>()
+        .register_type::<Vec<f32>>()
+        .register_type::<Vec<Vec3>>()
+        .register_type::<Vec<Quat>>();
+}
+
+impl_external_asset_schematic! {
+    #[asset_schematic(from = AnimationClipInput)]
+    struct AnimationClip {}
+}
+
+/// The schematic input type for [`AnimationClip`].
+///
+/// Each curve targets an entity by its path of [`Name`]s, starting from the entity
+/// containing the `AnimationPlayer`.
+/// Keyframes are interpolated linearly, which is the only interpolation method
+/// currently supported by Bevy.
+///
+/// # Example
+///
+/// ```text
+/// (
+///   curves: [
+///     (
+///       path: ["Root", "Arm"],
+///       times: [0.0, 1.0, 2.0],
+///       keyframes: Translation([(0.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 0.0)]),
+///     ),
+///     (
+///       path: ["Root"],
+///       times: [0.0, 2.0],
+///       keyframes: Scale([(1.0, 1.0, 1.0), (2.0, 2.0, 2.0)]),
+///     ),
+///   ],
+/// )
+/// ```
+#[derive(Reflect)]
+pub struct AnimationClipInput {
+    pub curves: Vec<AnimationCurveInput>,
+}
+
+/// A single animation curve within an [`AnimationClipInput`].
+#[derive(Reflect)]
+pub struct AnimationCurveInput {
+    /// The path of [`Name`]s to the target entity.
+    pub path: Vec<String>,
+    /// The timestamps (in seconds) of each keyframe.
+    ///
+    /// If the number of timestamps doesn't match the number of keyframes,
+    /// the extra timestamps or keyframes are ignored.
+    pub times: Vec<f32>,
+    /// The keyframe values.
+    pub keyframes: KeyframesInput,
+}
+
+/// The schematic input type for [`Keyframes`].
+#[derive(Reflect)]
+pub enum KeyframesInput {
+    Translation(Vec<Vec3>),
+    Rotation(Vec<Quat>),
+    Scale(Vec<Vec3>),
+}
+
+impl From<AnimationClipInput> for AnimationClip {
+    fn from(value: AnimationClipInput) -> Self {
+        let mut clip = AnimationClip::default();
+
+        for curve in value.curves {
+            let path = EntityPath {
+                parts: curve.path.into_iter().map(Name::new).collect(),
+            };
+
+            let mut keyframe_timestamps = curve.times;
+            let keyframes = match curve.keyframes {
+                KeyframesInput::Translation(mut values) => {
+                    truncate(&mut keyframe_timestamps, &mut values);
+                    Keyframes::Translation(values)
+                }
+                KeyframesInput::Rotation(mut values) => {
+                    truncate(&mut keyframe_timestamps, &mut values);
+                    Keyframes::Rotation(values)
+                }
+                KeyframesInput::Scale(mut values) => {
+                    truncate(&mut keyframe_timestamps, &mut values);
+                    Keyframes::Scale(values)
+                }
+            };
+
+            clip.add_curve_to_path(
+                path,
+                VariableCurve {
+                    keyframe_timestamps,
+                    keyframes,
+                },
+            );
+        }
+
+        clip
+    }
+}
+
+/// Truncates the given timestamps and values so that they have the same length.
+fn truncate<T>(times: &mut Vec<f32>, values: &mut Vec<T>) {
+    let len = times.len().min(values.len());
+    times.truncate(len);
+    values.truncate(len);
+}
@@ -80,9 +80,36 @@ impl_external_schematic! {
 }
 
 impl_external_schematic! {
+    #[schematic(from = DirectionalLightInput)]
     struct DirectionalLight {}
+    // ---
+    /// The schematic input type for [`DirectionalLight`].
+    ///
+    /// Any omitted fields will use the same defaults as [`DirectionalLight`].
+    #[derive(Reflect)]
+    #[reflect(Default)]
+    pub struct DirectionalLightInput {
+        pub color: ProtoColor,
+        pub illuminance: f32,
+        pub shadows_enabled: bool,
+        pub shadow_depth_bias: f32,
+        pub shadow_normal_bias: f32,
+    }
+    from_to_default! {
+        DirectionalLight,
+        DirectionalLightInput,
+        |value: Input| Self {
+            color: value.color.into(),
+            illuminance: value.illuminance,
+            shadows_enabled: value.shadows_enabled,
+            shadow_depth_bias: value.shadow_depth_bias,
+            shadow_normal_bias: value.shadow_normal_bias,
+        }
+    }
 }
 
+// As of Bevy 0.11, `EnvironmentMapLight` only contains the two maps
+// (an `intensity` field is not available until a later Bevy version).
 impl_external_schematic! {
     pub struct EnvironmentMapLight {
         #[schematic(asset)]
@@ -125,11 +152,76 @@ impl_external_schematic! {
 }
 
 impl_external_schematic! {
+    #[schematic(from = PointLightInput)]
     struct PointLight {}
+    // ---
+    /// The schematic input type for [`PointLight`].
+    ///
+    /// Any omitted fields will use the same defaults as [`PointLight`].
+    #[derive(Reflect)]
+    #[reflect(Default)]
+    pub struct PointLightInput {
+        pub color: ProtoColor,
+        pub intensity: f32,
+        pub range: f32,
+        pub radius: f32,
+        pub shadows_enabled: bool,
+        pub shadow_depth_bias: f32,
+        pub shadow_normal_bias: f32,
+    }
+    from_to_default! {
+        PointLight,
+        PointLightInput,
+        |value: Input| Self {
+            color: value.color.into(),
+            intensity: value.intensity,
+            range: value.range,
+            radius: value.radius,
+            shadows_enabled: value.shadows_enabled,
+            shadow_depth_bias: value.shadow_depth_bias,
+            shadow_normal_bias: value.shadow_normal_bias,
+        }
+    }
 }
 
 impl_external_schematic! {
+    #[schematic(from = SpotLightInput)]
     struct SpotLight {}
+    // ---
+    /// The schematic input type for [`SpotLight`].
+    ///
+    /// Any omitted fields will use the same defaults as [`SpotLight`].
+    ///
+    /// Angles are in radians, where `outer_angle` should be no greater than `PI / 2`
+    /// and `inner_angle` should be no greater than `outer_angle`.
+    #[derive(Reflect)]
+    #[reflect(Default)]
+    pub struct SpotLightInput {
+        pub color: ProtoColor,
+        pub intensity: f32,
+        pub range: f32,
+        pub radius: f32,
+        pub shadows_enabled: bool,
+        pub shadow_depth_bias: f32,
+        pub shadow_normal_bias: f32,
+        pub outer_angle: f32,
+        pub inner_angle: f32,
+    }
+    from_to_default! {
+        SpotLight,
+        SpotLightInput,
+        |value: Input| Self {
+            color: value.color.into(),
+            intensity: value.intensity,
+            range: value.range,
+            radius: value.radius,
+            shadows_enabled: value.shadows_enabled,
+            shadow_depth_bias: value.shadow_depth_bias,
+            shadow_normal_bias: value.shadow_normal_bias,
+            outer_angle: value.outer_angle,
+            inner_angle: value.inner_angle,
+        }
+    }
 }
 
 impl_external_schematic! {
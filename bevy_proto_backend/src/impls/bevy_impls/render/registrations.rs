@@ -1,8 +1,11 @@
 use bevy::app::App;
-use bevy::math::Mat4;
-use bevy::reflect::{std_traits::ReflectDefault, Reflect};
+use bevy::log::error;
+use bevy::math::{Mat4, Rect, Vec2, Vec3};
+use bevy::prelude::{Entity, Image};
+use bevy::reflect::{std_traits::ReflectDefault, Reflect, ReflectDeserialize};
 use bevy::render::camera::{
     Camera, CameraRenderGraph, OrthographicProjection, PerspectiveProjection, Projection,
+    RenderTarget, ScalingMode, Viewport,
 };
 use bevy::render::mesh::shape::{
     Box, Capsule, Circle, Cube, Cylinder, Icosphere, Plane, Quad, RegularPolygon, Torus, UVSphere,
@@ -10,13 +13,17 @@ use bevy::render::mesh::shape::{
 use bevy::render::mesh::skinning::{SkinnedMesh, SkinnedMeshInverseBindposes};
 use bevy::render::mesh::Mesh;
 use bevy::render::primitives::Aabb;
+use bevy::render::render_resource::PrimitiveTopology;
 use bevy::render::view::{ColorGrading, RenderLayers, Visibility};
+use bevy::window::WindowRef;
+use serde::Deserialize;
 
-use crate::assets::AssetSchematicAppExt;
+use crate::assets::{AssetSchematicAppExt, ProtoAsset};
 use bevy_proto_derive::{impl_external_asset_schematic, impl_external_schematic};
 
-use crate::impls::macros::{from_to_default, register_schematic};
-use crate::tree::ProtoEntityList;
+use crate::impls::macros::{from_to, from_to_default, register_schematic};
+use crate::schematics::{FromSchematicInput, SchematicContext, SchematicId};
+use crate::tree::{EntityAccess, ProtoEntityList};
 
 use super::shapes::*;
 
@@ -46,17 +53,161 @@ pub(crate) fn register(app: &mut App) {
         .register_type::<IcosphereInput>()
         .register_type::<PlaneInput>()
         .register_type::<QuadInput>()
+        .register_type::<RawMeshInput>()
         .register_type::<RegularPolygonInput>()
         .register_type::<TorusInput>()
-        .register_type::<UVSphereInput>();
+        .register_type::<UVSphereInput>()
+        .register_type::<RenderTargetInput>()
+        .register_type::<WindowRefInput>()
+        .register_type::<ScalingModeInput>()
+        .register_type::<Option<Vec3>>()
+        .register_type::<Vec<Vec2>>()
+        .register_type::<Vec<Vec3>>()
+        .register_type::<Vec<u32>>();
 }
 
 impl_external_schematic! {
+    #[schematic(from = AabbInput)]
     struct Aabb {}
+    // ---
+    /// The schematic input type for [`Aabb`].
+    ///
+    /// The bounding box may be defined either by its `center` and `half_extents`
+    /// (the same form as [`Aabb`] itself) or by its `min` and `max` corners.
+    /// If both `min` and `max` are given, they take precedence over `center` and `half_extents`.
+    /// Any omitted `center` or `half_extents` will default to zero.
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    pub struct AabbInput {
+        #[reflect(default)]
+        pub center: Vec3,
+        #[reflect(default)]
+        pub half_extents: Vec3,
+        #[reflect(default)]
+        pub min: Option<Vec3>,
+        #[reflect(default)]
+        pub max: Option<Vec3>,
+    }
+    impl From<AabbInput> for Aabb {
+        fn from(value: AabbInput) -> Self {
+            match (value.min, value.max) {
+                (Some(min), Some(max)) => Self::from_min_max(min, max),
+                _ => Self {
+                    center: value.center.into(),
+                    half_extents: value.half_extents.into(),
+                },
+            }
+        }
+    }
 }
 
 impl_external_schematic! {
+    #[schematic(from = CameraInput)]
     struct Camera {}
+    // ---
+    /// The schematic input type for [`Camera`].
+    ///
+    /// Any omitted fields will use the same defaults as [`Camera`].
+    #[derive(Reflect)]
+    #[reflect(Default)]
+    pub struct CameraInput {
+        pub viewport: Option<Viewport>,
+        pub order: isize,
+        pub is_active: bool,
+        pub target: RenderTargetInput,
+        pub hdr: bool,
+        pub msaa_writeback: bool,
+    }
+    impl Default for CameraInput {
+        fn default() -> Self {
+            let camera = Camera::default();
+            Self {
+                viewport: camera.viewport,
+                order: camera.order,
+                is_active: camera.is_active,
+                target: RenderTargetInput::default(),
+                hdr: camera.hdr,
+                msaa_writeback: camera.msaa_writeback,
+            }
+        }
+    }
+    impl FromSchematicInput<CameraInput> for Camera {
+        fn from_input(input: CameraInput, id: SchematicId, context: &mut SchematicContext) -> Self {
+            Self {
+                viewport: input.viewport,
+                order: input.order,
+                is_active: input.is_active,
+                target: FromSchematicInput::from_input(input.target, id, context),
+                hdr: input.hdr,
+                msaa_writeback: input.msaa_writeback,
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// The schematic input type for [`RenderTarget`].
+///
+/// When rendering to an [`Image`], the image must be created with
+/// [`TextureUsages::RENDER_ATTACHMENT`] set in its texture descriptor.
+/// Images loaded from disk do not have this usage by default,
+/// so the target image will typically be created in code and referenced by its handle.
+///
+/// [`TextureUsages::RENDER_ATTACHMENT`]: bevy::render::render_resource::TextureUsages::RENDER_ATTACHMENT
+#[derive(Reflect)]
+#[reflect(Default)]
+pub enum RenderTargetInput {
+    /// Render to the given window.
+    Window(WindowRefInput),
+    /// Render to the given image.
+    Image(ProtoAsset<Image>),
+}
+
+impl Default for RenderTargetInput {
+    fn default() -> Self {
+        Self::Window(WindowRefInput::Primary)
+    }
+}
+
+impl FromSchematicInput<RenderTargetInput> for RenderTarget {
+    fn from_input(
+        input: RenderTargetInput,
+        id: SchematicId,
+        context: &mut SchematicContext,
+    ) -> Self {
+        match input {
+            RenderTargetInput::Window(window) => {
+                Self::Window(FromSchematicInput::from_input(window, id, context))
+            }
+            RenderTargetInput::Image(image) => Self::Image(FromSchematicInput::from_input(
+                image,
+                id.field("image"),
+                context,
+            )),
+        }
+    }
+}
+
+/// The schematic input type for [`WindowRef`].
+#[derive(Reflect)]
+pub enum WindowRefInput {
+    /// The primary window.
+    Primary,
+    /// The window on the given entity.
+    Entity(EntityAccess),
+}
+
+impl FromSchematicInput<WindowRefInput> for WindowRef {
+    fn from_input(input: WindowRefInput, id: SchematicId, context: &mut SchematicContext) -> Self {
+        match input {
+            WindowRefInput::Primary => Self::Primary,
+            WindowRefInput::Entity(entity) => {
+                Self::Entity(<Entity as FromSchematicInput<EntityAccess>>::from_input(
+                    entity, id, context,
+                ))
+            }
+        }
+    }
 }
 
 impl_external_schematic! {
@@ -88,11 +239,101 @@ impl_external_schematic! {
 }
 
 impl_external_schematic! {
+    #[schematic(from = OrthographicProjectionInput)]
     struct OrthographicProjection {}
+    // ---
+    /// The schematic input type for [`OrthographicProjection`].
+    ///
+    /// Any omitted fields will use the same defaults as [`OrthographicProjection`].
+    #[derive(Reflect)]
+    #[reflect(Default)]
+    pub struct OrthographicProjectionInput {
+        pub near: f32,
+        pub far: f32,
+        pub viewport_origin: Vec2,
+        pub scaling_mode: ScalingModeInput,
+        pub scale: f32,
+    }
+    impl Default for OrthographicProjectionInput {
+        fn default() -> Self {
+            OrthographicProjection::default().into()
+        }
+    }
+    impl From<OrthographicProjection> for OrthographicProjectionInput {
+        fn from(value: OrthographicProjection) -> Self {
+            Self {
+                near: value.near,
+                far: value.far,
+                viewport_origin: value.viewport_origin,
+                scaling_mode: value.scaling_mode.into(),
+                scale: value.scale,
+            }
+        }
+    }
+    impl From<OrthographicProjectionInput> for OrthographicProjection {
+        fn from(value: OrthographicProjectionInput) -> Self {
+            Self {
+                near: value.near,
+                far: value.far,
+                viewport_origin: value.viewport_origin,
+                scaling_mode: value.scaling_mode.into(),
+                scale: value.scale,
+                // The area is recomputed from the scaling mode whenever the viewport changes
+                area: Rect::new(-1.0, -1.0, 1.0, 1.0),
+            }
+        }
+    }
+}
+
+/// The schematic input type for [`ScalingMode`].
+#[derive(Reflect, Copy, Clone)]
+pub enum ScalingModeInput {
+    Fixed { width: f32, height: f32 },
+    WindowSize(f32),
+    AutoMin { min_width: f32, min_height: f32 },
+    AutoMax { max_width: f32, max_height: f32 },
+    FixedVertical(f32),
+    FixedHorizontal(f32),
+}
+
+from_to! {
+    ScalingMode,
+    ScalingModeInput,
+    |value: Input| match value {
+        Input::Fixed { width, height } => Self::Fixed { width, height },
+        Input::WindowSize(scale) => Self::WindowSize(scale),
+        Input::AutoMin { min_width, min_height } => Self::AutoMin { min_width, min_height },
+        Input::AutoMax { max_width, max_height } => Self::AutoMax { max_width, max_height },
+        Input::FixedVertical(height) => Self::FixedVertical(height),
+        Input::FixedHorizontal(width) => Self::FixedHorizontal(width),
+    }
 }
 
 impl_external_schematic! {
+    #[schematic(from = PerspectiveProjectionInput)]
     struct PerspectiveProjection {}
+    // ---
+    /// The schematic input type for [`PerspectiveProjection`].
+    ///
+    /// Any omitted fields will use the same defaults as [`PerspectiveProjection`].
+    #[derive(Reflect)]
+    #[reflect(Default)]
+    pub struct PerspectiveProjectionInput {
+        pub fov: f32,
+        pub aspect_ratio: f32,
+        pub near: f32,
+        pub far: f32,
+    }
+    from_to_default! {
+        PerspectiveProjection,
+        PerspectiveProjectionInput,
+        |value: Input| Self {
+            fov: value.fov,
+            aspect_ratio: value.aspect_ratio,
+            near: value.near,
+            far: value.far,
+        }
+    }
 }
 
 impl_external_schematic! {
@@ -102,15 +343,15 @@ impl_external_schematic! {
     #[derive(Reflect)]
     #[reflect(Default)]
     pub enum ProjectionInput {
-        Perspective(PerspectiveProjection),
-        Orthographic(OrthographicProjection),
+        Perspective(PerspectiveProjectionInput),
+        Orthographic(OrthographicProjectionInput),
     }
     from_to_default! {
         Projection,
         ProjectionInput,
         |value: Input| match value {
-            Input::Perspective(projection) => Self::Perspective(projection),
-            Input::Orthographic(projection) => Self::Orthographic(projection),
+            Input::Perspective(projection) => Self::Perspective(projection.into()),
+            Input::Orthographic(projection) => Self::Orthographic(projection.into()),
         }
     }
 }
@@ -119,11 +360,27 @@ impl_external_schematic! {
     #[schematic(from = RenderLayersInput)]
     struct RenderLayers();
     // ---
-    #[derive(Reflect)]
-    pub struct RenderLayersInput(u8);
+    /// The schematic input type for [`RenderLayers`].
+    ///
+    /// This may be given as a single layer (e.g. `3` or `RenderLayersInput(3)`)
+    /// or as a list of layers (e.g. `[0, 3]`).
+    #[derive(Reflect, Deserialize)]
+    #[reflect(Deserialize)]
+    #[serde(untagged)]
+    pub enum RenderLayersInput {
+        /// Shorthand for membership in a single layer.
+        Layer(u8),
+        /// Membership in each of the given layers.
+        Layers(Vec<u8>),
+    }
     impl From<RenderLayersInput> for RenderLayers {
         fn from(value: RenderLayersInput) -> Self {
-            Self::layer(value.0)
+            match value {
+                RenderLayersInput::Layer(layer) => Self::layer(layer),
+                RenderLayersInput::Layers(layers) => layers
+                    .into_iter()
+                    .fold(Self::none(), |render_layers, layer| render_layers.with(layer)),
+            }
         }
     }
 }
@@ -167,6 +424,8 @@ pub enum MeshInput {
     RegularPolygon(RegularPolygonInput),
     Torus(TorusInput),
     UvSphere(UVSphereInput),
+    /// A mesh built from raw vertex data.
+    Raw(RawMeshInput),
 }
 
 impl From<MeshInput> for Mesh {
@@ -183,6 +442,12 @@ impl From<MeshInput> for Mesh {
             MeshInput::RegularPolygon(input) => RegularPolygon::from(input).into(),
             MeshInput::Torus(input) => Torus::from(input).into(),
             MeshInput::UvSphere(input) => UVSphere::from(input).into(),
+            // Raw meshes are validated when their prototype is loaded,
+            // so this can only fail for prototypes created in code
+            MeshInput::Raw(input) => input.try_into().unwrap_or_else(|err| {
+                error!("could not create raw mesh: {}", err);
+                Mesh::new(PrimitiveTopology::TriangleList)
+            }),
         }
     }
 }
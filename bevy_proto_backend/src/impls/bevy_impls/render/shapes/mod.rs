@@ -6,6 +6,7 @@ pub use cylinders::*;
 pub use planes::*;
 pub use polygons::*;
 pub use quads::*;
+pub use raw::*;
 pub use spheres::*;
 pub use toruses::*;
 
@@ -17,5 +18,6 @@ mod cylinders;
 mod planes;
 mod polygons;
 mod quads;
+mod raw;
 mod spheres;
 mod toruses;
@@ -0,0 +1,188 @@
+use bevy::math::{Vec2, Vec3};
+use bevy::reflect::{std_traits::ReflectDefault, Reflect};
+use bevy::render::mesh::{Indices, Mesh};
+use bevy::render::render_resource::PrimitiveTopology;
+use thiserror::Error;
+
+use crate::schematics::{ReflectValidateInput, ValidateInput};
+
+/// The schematic input type for a [`Mesh`] built from raw vertex data.
+///
+/// The mesh uses a [`PrimitiveTopology::TriangleList`],
+/// so every three indices (or every three vertices, if no indices are given) form a triangle.
+///
+/// All vertex attributes other than `positions` are optional,
+/// but when given, they must contain exactly one entry per position.
+/// This is [validated] when the prototype is loaded.
+///
+/// [validated]: ValidateInput
+#[derive(Reflect, Clone, Default)]
+#[reflect(Default, ValidateInput)]
+pub struct RawMeshInput {
+    /// The position of each vertex.
+    pub positions: Vec<Vec3>,
+    /// The normal of each vertex.
+    #[reflect(default)]
+    pub normals: Vec<Vec3>,
+    /// The texture coordinates of each vertex.
+    #[reflect(default)]
+    pub uvs: Vec<Vec2>,
+    /// The vertex indices of each triangle.
+    #[reflect(default)]
+    pub indices: Vec<u32>,
+}
+
+/// An error that occurs when building a [`Mesh`] from a [`RawMeshInput`].
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RawMeshError {
+    #[error("expected {expected} {attribute} (one per position) but found {found}")]
+    AttributeLength {
+        attribute: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    #[error("expected a multiple of 3 {0} to form triangles but found {1}")]
+    IncompleteTriangle(&'static str, usize),
+    #[error("index {index} is out of bounds for {len} positions")]
+    IndexOutOfBounds { index: u32, len: usize },
+}
+
+impl RawMeshInput {
+    /// Checks that all attributes and indices are consistent with the given positions.
+    pub fn validate(&self) -> Result<(), RawMeshError> {
+        let len = self.positions.len();
+
+        let check_len = |attribute: &'static str, found: usize| {
+            if found == 0 || found == len {
+                Ok(())
+            } else {
+                Err(RawMeshError::AttributeLength {
+                    attribute,
+                    expected: len,
+                    found,
+                })
+            }
+        };
+
+        check_len("normals", self.normals.len())?;
+        check_len("uvs", self.uvs.len())?;
+
+        if self.indices.is_empty() {
+            if len % 3 != 0 {
+                return Err(RawMeshError::IncompleteTriangle("positions", len));
+            }
+        } else {
+            if self.indices.len() % 3 != 0 {
+                return Err(RawMeshError::IncompleteTriangle(
+                    "indices",
+                    self.indices.len(),
+                ));
+            }
+
+            if let Some(&index) = self.indices.iter().find(|&&index| index as usize >= len) {
+                return Err(RawMeshError::IndexOutOfBounds { index, len });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ValidateInput for RawMeshInput {
+    fn validate_input(&self) -> Result<(), String> {
+        self.validate().map_err(|err| err.to_string())
+    }
+}
+
+impl TryFrom<RawMeshInput> for Mesh {
+    type Error = RawMeshError;
+
+    fn try_from(value: RawMeshInput) -> Result<Self, Self::Error> {
+        value.validate()?;
+
+        let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, value.positions);
+
+        if !value.normals.is_empty() {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, value.normals);
+        }
+
+        if !value.uvs.is_empty() {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, value.uvs);
+        }
+
+        if !value.indices.is_empty() {
+            mesh.set_indices(Some(Indices::U32(value.indices)));
+        }
+
+        Ok(mesh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::reflect::TypeRegistryInternal;
+
+    use crate::impls::bevy_impls::render::registrations::MeshInput;
+    use crate::schematics::{validate_schematic_input, SchematicError};
+
+    use super::*;
+
+    #[test]
+    fn should_validate_attribute_lengths() {
+        let triangle = RawMeshInput {
+            positions: vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            normals: vec![Vec3::Z; 3],
+            uvs: Vec::new(),
+            indices: vec![0, 1, 2],
+        };
+        assert_eq!(Ok(()), triangle.validate());
+
+        let mismatched = RawMeshInput {
+            uvs: vec![Vec2::ZERO; 2],
+            ..triangle.clone()
+        };
+        assert_eq!(
+            Err(RawMeshError::AttributeLength {
+                attribute: "uvs",
+                expected: 3,
+                found: 2
+            }),
+            mismatched.validate()
+        );
+
+        let out_of_bounds = RawMeshInput {
+            indices: vec![0, 1, 3],
+            ..triangle
+        };
+        assert_eq!(
+            Err(RawMeshError::IndexOutOfBounds { index: 3, len: 3 }),
+            out_of_bounds.validate()
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_raw_mesh_when_loading() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<MeshInput>();
+        registry.register::<RawMeshInput>();
+
+        let valid = MeshInput::Raw(RawMeshInput {
+            positions: vec![Vec3::ZERO, Vec3::X, Vec3::Y],
+            ..Default::default()
+        });
+        assert!(validate_schematic_input("Mesh", &valid, &registry).is_ok());
+
+        let invalid = MeshInput::Raw(RawMeshInput {
+            positions: vec![Vec3::ZERO, Vec3::X],
+            ..Default::default()
+        });
+        assert!(matches!(
+            validate_schematic_input("Mesh", &invalid, &registry),
+            Err(SchematicError::InvalidInput {
+                schematic: "Mesh",
+                ..
+            })
+        ));
+    }
+}
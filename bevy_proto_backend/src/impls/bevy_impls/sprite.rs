@@ -1,6 +1,6 @@
 use bevy::app::App;
 use bevy::math::Vec2;
-use bevy::prelude::{Color, Image};
+use bevy::prelude::Image;
 use bevy::reflect::{std_traits::ReflectDefault, Reflect};
 use bevy::render::prelude::Mesh;
 use bevy::sprite::{Anchor, ColorMaterial, Mesh2dHandle, Sprite, TextureAtlas, TextureAtlasSprite};
@@ -38,6 +38,8 @@ impl_external_schematic! {
     }
 }
 
+// As of Bevy 0.11, sprites cannot be sliced (nine-patch):
+// `ImageScaleMode` and `TextureSlicer` are not available until a later Bevy version.
 impl_external_schematic! {
     struct Sprite {}
 }
@@ -81,7 +83,7 @@ impl_external_asset_schematic! {
 #[derive(Reflect)]
 #[reflect(Default)]
 pub struct ColorMaterialInput {
-    pub color: Color,
+    pub color: ProtoColor,
     pub texture: Option<ProtoAsset<Image>>,
 }
 
@@ -92,7 +94,7 @@ impl FromSchematicInput<ColorMaterialInput> for ColorMaterial {
         context: &mut SchematicContext,
     ) -> Self {
         Self {
-            color: input.color,
+            color: input.color.into(),
             texture: input.texture.map(|value| {
                 FromSchematicInput::from_input(
                     value,
@@ -113,7 +115,7 @@ impl FromSchematicPreloadInput<ColorMaterialInput> for ColorMaterial {
         dependencies: &mut DependenciesBuilder,
     ) -> Self {
         Self {
-            color: input.color,
+            color: input.color.into(),
             texture: input.texture.map(|value| {
                 FromSchematicPreloadInput::from_preload_input(
                     value,
@@ -131,7 +133,7 @@ impl Default for ColorMaterialInput {
     fn default() -> Self {
         let base = ColorMaterial::default();
         Self {
-            color: base.color,
+            color: base.color.into(),
             texture: base.texture.map(ProtoAsset::from),
         }
     }
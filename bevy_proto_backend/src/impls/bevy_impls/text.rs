@@ -1,12 +1,15 @@
-use bevy::app::App;
+use bevy::app::{App, Update};
+use bevy::asset::{AssetServer, Handle, HandleId, LoadState};
+use bevy::log::warn;
 use bevy::math::Vec2;
-use bevy::prelude::Font;
+use bevy::prelude::{Font, Query, Res, ResMut, Resource};
 use bevy::reflect::{std_traits::ReflectDefault, Reflect};
 use bevy::sprite::TextureAtlas;
 use bevy::text::{
     BreakLineOn, GlyphAtlasInfo, PositionedGlyph, Text, Text2dBounds, TextAlignment,
     TextLayoutInfo, TextSection, TextStyle,
 };
+use bevy::utils::HashMap;
 
 use crate::impls::macros::{from_to_default, from_to_input, register_schematic};
 use crate::proto::ProtoColor;
@@ -21,7 +24,14 @@ pub(super) fn register(app: &mut App) {
     // Can be removed if https://github.com/bevyengine/bevy/pull/5781 is ever merged
     app.register_type::<TextSectionInput>()
         .register_type::<Vec<TextSectionInput>>()
-        .register_type::<TextStyleInput>();
+        .register_type::<TextStyleInput>()
+        .register_type::<FontInput>();
+
+    // Only add the system once, even if multiple prototype plugins are added
+    if !app.world.contains_resource::<FontFallbacks>() {
+        app.init_resource::<FontFallbacks>()
+            .add_systems(Update, apply_font_fallbacks);
+    }
 }
 
 impl_external_schematic! {
@@ -86,7 +96,7 @@ impl_external_schematic! {
 
     #[derive(Reflect)]
     pub struct TextStyleInput {
-        pub font: ProtoAsset<Font>,
+        pub font: FontInput,
         pub font_size: f32,
         pub color: ProtoColor,
     }
@@ -123,6 +133,121 @@ impl_external_schematic! {
     }
 }
 
+/// The schematic input type for the font of a [`TextStyle`].
+///
+/// # Example
+///
+/// ```text
+/// style: (
+///   font: Fallback(
+///     path: "fonts/FancyFont.ttf",
+///     fallback: "fonts/JetBrainsMono-Regular.ttf",
+///   ),
+///   font_size: 30.0,
+///   color: White,
+/// )
+/// ```
+#[derive(Reflect)]
+pub enum FontInput {
+    /// The path to a font relative to the `assets` directory.
+    AssetPath(String),
+    /// The path to a font along with the path to a fallback font,
+    /// both relative to the `assets` directory.
+    ///
+    /// Both fonts are loaded by the [`AssetServer`] as usual.
+    /// If the font at `path` fails to load, any [`Text`] using it is updated
+    /// to use the `fallback` font instead.
+    Fallback { path: String, fallback: String },
+    /// An existing [`Handle`].
+    ///
+    /// Note: This handle should always be _weak_.
+    Handle(Handle<Font>),
+}
+
+impl FromSchematicInput<FontInput> for Handle<Font> {
+    fn from_input(input: FontInput, id: SchematicId, context: &mut SchematicContext) -> Self {
+        match input {
+            FontInput::AssetPath(path) => {
+                FromSchematicInput::from_input(ProtoAsset::AssetPath(path), id, context)
+            }
+            FontInput::Handle(handle) => {
+                FromSchematicInput::from_input(ProtoAsset::Handle(handle), id, context)
+            }
+            FontInput::Fallback { path, fallback } => {
+                let asset_server = context.world().resource::<AssetServer>();
+                let font: Handle<Font> = asset_server.load(path);
+                let fallback = asset_server.load(fallback);
+
+                match asset_server.get_load_state(&font) {
+                    LoadState::Loaded => font,
+                    LoadState::Failed => fallback,
+                    _ => {
+                        context
+                            .world_mut()
+                            .resource_mut::<FontFallbacks>()
+                            .0
+                            .insert(font.id(), fallback);
+                        font
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The fallbacks for fonts given by [`FontInput::Fallback`] that are still loading.
+#[derive(Resource, Default)]
+struct FontFallbacks(HashMap<HandleId, Handle<Font>>);
+
+/// Replaces fonts that failed to load with their fallback in every [`Text`].
+fn apply_font_fallbacks(
+    asset_server: Res<AssetServer>,
+    mut fallbacks: ResMut<FontFallbacks>,
+    mut texts: Query<&mut Text>,
+) {
+    if fallbacks.0.is_empty() {
+        return;
+    }
+
+    let mut failed = HashMap::new();
+    fallbacks
+        .0
+        .retain(|font, fallback| match asset_server.get_load_state(*font) {
+            LoadState::Failed => {
+                warn!(
+                    "could not load font {:?}: using fallback {:?}",
+                    asset_server.get_handle_path(*font),
+                    asset_server.get_handle_path(&*fallback)
+                );
+                failed.insert(*font, fallback.clone());
+                false
+            }
+            LoadState::Loaded | LoadState::Unloaded => false,
+            _ => true,
+        });
+
+    if failed.is_empty() {
+        return;
+    }
+
+    for mut text in &mut texts {
+        let uses_failed = text
+            .sections
+            .iter()
+            .any(|section| failed.contains_key(&section.style.font.id()));
+
+        if !uses_failed {
+            continue;
+        }
+
+        for section in &mut text.sections {
+            if let Some(fallback) = failed.get(&section.style.font.id()) {
+                section.style.font = fallback.clone();
+            }
+        }
+    }
+}
+
 #[derive(Reflect, Default)]
 #[reflect(Default)]
 pub struct TextLayoutInfoInput {
@@ -1,4 +1,6 @@
 use bevy::app::App;
+use bevy::math::{Quat, Vec3};
+use bevy::reflect::{std_traits::ReflectDefault, Reflect};
 use bevy::transform::components::{GlobalTransform, Transform};
 
 use crate::impls::macros::register_schematic;
@@ -6,10 +8,58 @@ use bevy_proto_derive::impl_external_schematic;
 
 pub(super) fn register(app: &mut App) {
     register_schematic!(app, Transform, GlobalTransform);
+    app.register_type::<Option<Vec3>>();
 }
 
 impl_external_schematic! {
+    #[schematic(from = TransformInput)]
     struct Transform {}
+    // ---
+    /// The schematic input type for [`Transform`].
+    ///
+    /// This accepts the same `translation`, `rotation`, and `scale` fields as [`Transform`],
+    /// any of which may be omitted to use their default values.
+    ///
+    /// Additionally, a `look_at` target may be given to compute the rotation such that
+    /// the transform faces the target, using [`Transform::looking_at`].
+    /// When set, this takes precedence over `rotation`.
+    /// The `up` direction defaults to [`Vec3::Y`].
+    #[derive(Reflect)]
+    #[reflect(Default)]
+    pub struct TransformInput {
+        pub translation: Vec3,
+        pub rotation: Quat,
+        pub scale: Vec3,
+        #[reflect(default)]
+        pub look_at: Option<Vec3>,
+        #[reflect(default)]
+        pub up: Option<Vec3>,
+    }
+    impl Default for TransformInput {
+        fn default() -> Self {
+            Self {
+                translation: Vec3::ZERO,
+                rotation: Quat::IDENTITY,
+                scale: Vec3::ONE,
+                look_at: None,
+                up: None,
+            }
+        }
+    }
+    impl From<TransformInput> for Transform {
+        fn from(value: TransformInput) -> Self {
+            let transform = Self {
+                translation: value.translation,
+                rotation: value.rotation,
+                scale: value.scale,
+            };
+
+            match value.look_at {
+                Some(target) => transform.looking_at(target, value.up.unwrap_or(Vec3::Y)),
+                None => transform,
+            }
+        }
+    }
 }
 
 impl_external_schematic! {
@@ -19,8 +19,10 @@ pub(super) fn register(app: &mut App) {
     register_schematic!(
         app,
         BackgroundColor,
+        BorderColor,
         Button,
         CalculatedClip,
+        ContentSize,
         FocusPolicy,
         Interaction,
         Label,
@@ -29,6 +31,7 @@ pub(super) fn register(app: &mut App) {
         Style,
         TextFlags,
         UiImage,
+        ZIndex,
     );
 
     // Can be removed if https://github.com/bevyengine/bevy/pull/5781 is ever merged
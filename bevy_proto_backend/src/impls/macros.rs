@@ -69,6 +69,8 @@ macro_rules! from_to {
     };
 }
 
+pub(super) use from_to;
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! from_to_input {
@@ -1,6 +1,11 @@
 pub mod bevy_impls;
 mod macros;
+#[cfg(any(feature = "bevy_rapier2d", feature = "bevy_rapier3d"))]
+pub mod rapier_impls;
 
 pub(crate) fn register_impls(app: &mut bevy::app::App) {
     bevy_impls::register_impls(app);
+
+    #[cfg(any(feature = "bevy_rapier2d", feature = "bevy_rapier3d"))]
+    rapier_impls::register_impls(app);
 }
@@ -0,0 +1,17 @@
+//! Schematics for common [`bevy_rapier`] components.
+//!
+//! These are enabled via the `bevy_rapier2d` and `bevy_rapier3d` features.
+//!
+//! [`bevy_rapier`]: https://github.com/dimforge/bevy_rapier
+
+#[cfg(feature = "bevy_rapier2d")]
+pub mod rapier2d;
+#[cfg(feature = "bevy_rapier3d")]
+pub mod rapier3d;
+
+pub(super) fn register_impls(app: &mut bevy::app::App) {
+    #[cfg(feature = "bevy_rapier2d")]
+    rapier2d::register(app);
+    #[cfg(feature = "bevy_rapier3d")]
+    rapier3d::register(app);
+}
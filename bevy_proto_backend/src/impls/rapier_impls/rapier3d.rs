@@ -0,0 +1,51 @@
+use bevy::app::App;
+use bevy::math::Vec3;
+use bevy::reflect::Reflect;
+use bevy_rapier3d::prelude::{Collider, RigidBody, Velocity};
+
+use crate::impls::macros::register_schematic;
+use bevy_proto_derive::{impl_external_schematic, Schematic};
+
+pub(super) fn register(app: &mut App) {
+    register_schematic!(app, RigidBody, Velocity, ColliderShape);
+}
+
+impl_external_schematic! {
+    enum RigidBody {}
+}
+
+impl_external_schematic! {
+    struct Velocity {}
+}
+
+/// A [`Schematic`] used to insert a 3D [`Collider`].
+///
+/// Since [`Collider`] does not implement [`Reflect`],
+/// this type describes the shape of the collider instead.
+///
+/// [`Schematic`]: crate::schematics::Schematic
+#[derive(Reflect, Schematic, Debug, Clone, PartialEq)]
+#[schematic(into = Collider)]
+pub enum ColliderShape {
+    /// A box defined by its half-extents.
+    Cuboid { half_extents: Vec3 },
+    /// A sphere defined by its radius.
+    Ball { radius: f32 },
+    /// A capsule aligned along the Y-axis.
+    Capsule { half_height: f32, radius: f32 },
+}
+
+impl From<ColliderShape> for Collider {
+    fn from(value: ColliderShape) -> Self {
+        match value {
+            ColliderShape::Cuboid { half_extents } => {
+                Collider::cuboid(half_extents.x, half_extents.y, half_extents.z)
+            }
+            ColliderShape::Ball { radius } => Collider::ball(radius),
+            ColliderShape::Capsule {
+                half_height,
+                radius,
+            } => Collider::capsule_y(half_height, radius),
+        }
+    }
+}
@@ -12,4 +12,11 @@ pub mod proto;
 mod registration;
 pub mod schematics;
 pub mod templates;
+#[cfg(test)]
+mod test_utils;
 pub mod tree;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+}
@@ -1,9 +1,13 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::sync::Arc;
 
 use bevy::asset::{AssetLoader, AssetPath, BoxedFuture, LoadContext, LoadedAsset};
 use bevy::ecs::reflect::AppTypeRegistry;
+use bevy::log::info_span;
 use bevy::prelude::{Handle, World};
+use bevy::utils::{HashMap, HashSet};
 use parking_lot::RwLock;
 
 use crate::load::{Loader, ProtoLoadContext};
@@ -14,11 +18,15 @@ pub(crate) struct ProtoAssetLoader<T: Prototypical, L: Loader<T>, C: Config<T>>
     registry: AppTypeRegistry,
     proto_registry: Arc<RwLock<LoadQueue<T>>>,
     loader: L,
+    extensions: Vec<&'static str>,
+    flags: HashSet<String>,
+    type_path_aliases: HashMap<String, String>,
     _phantom: PhantomData<C>,
 }
 
 impl<T: Prototypical, L: Loader<T>, C: Config<T>> ProtoAssetLoader<T, L, C> {
-    pub fn new(loader: L, world: &mut World) -> Self {
+    /// Creates a new asset loader handling the given extensions.
+    pub fn new(loader: L, extensions: Vec<&'static str>, world: &mut World) -> Self {
         world.init_resource::<AppTypeRegistry>();
         world.init_resource::<ProtoRegistry<T, C>>();
 
@@ -26,6 +34,9 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> ProtoAssetLoader<T, L, C> {
             registry: world.resource::<AppTypeRegistry>().clone(),
             proto_registry: world.resource::<ProtoRegistry<T, C>>().load_queue().clone(),
             loader,
+            extensions,
+            flags: world.resource::<C>().active_flags(),
+            type_path_aliases: world.resource::<C>().type_path_aliases(),
             _phantom: Default::default(),
         }
     }
@@ -39,11 +50,80 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> AssetLoader for ProtoAssetLoad
     ) -> BoxedFuture<'a, anyhow::Result<(), anyhow::Error>> {
         Box::pin(async {
             let registry = self.registry.read();
-            let mut ctx = ProtoLoadContext::<T, L>::new(&registry, &self.loader, load_context);
+
+            // Skip files that were modified without changing their contents
+            // (e.g. editors touching a file on save).
+            // Not setting any assets leaves the existing ones untouched.
+            let content_hash = {
+                let mut hasher = DefaultHasher::new();
+                bytes.hash(&mut hasher);
+                hasher.finish()
+            };
+            if self
+                .proto_registry
+                .read()
+                .is_unchanged(load_context.path(), content_hash)
+            {
+                return Ok(());
+            }
+
+            if let Some(entries) = self.loader.unbundle(bytes, load_context.path())? {
+                for entry in entries {
+                    let span = info_span!(
+                        "load_prototype",
+                        path = %load_context.path().display(),
+                        entry = %entry.name
+                    );
+                    let mut ctx = ProtoLoadContext::<T, L>::new(
+                        &registry,
+                        &self.loader,
+                        &self.extensions,
+                        &self.flags,
+                        &self.type_path_aliases,
+                        load_context,
+                    )
+                    .with_bundle_entry(entry.name);
+
+                    let (prototype, meta, mut dependency_paths) = span.in_scope(|| {
+                        let prototype = L::deserialize(&entry.bytes, &mut ctx)?;
+                        ctx.preprocess_proto(prototype)
+                    })?;
+                    dependency_paths.append(ctx.child_paths_mut());
+
+                    let label = meta
+                        .path
+                        .label()
+                        .expect("bundle entry should have an asset label");
+                    self.proto_registry
+                        .write()
+                        .queue(prototype.id().clone(), &meta.handle);
+
+                    let asset = LoadedAsset::new(prototype).with_dependencies(dependency_paths);
+                    ctx.set_labeled_asset(label, asset);
+                }
+
+                self.proto_registry
+                    .write()
+                    .set_content_hash(load_context.path(), content_hash);
+
+                return Ok(());
+            }
+
+            let span = info_span!("load_prototype", path = %load_context.path().display());
+            let mut ctx = ProtoLoadContext::<T, L>::new(
+                &registry,
+                &self.loader,
+                &self.extensions,
+                &self.flags,
+                &self.type_path_aliases,
+                load_context,
+            );
 
             // 1. Deserialize the prototype
-            let prototype = L::deserialize(bytes, &mut ctx)?;
-            let (prototype, _, mut dependency_paths) = ctx.preprocess_proto(prototype)?;
+            let (prototype, _, mut dependency_paths) = span.in_scope(|| {
+                let prototype = L::deserialize(bytes, &mut ctx)?;
+                ctx.preprocess_proto(prototype)
+            })?;
             dependency_paths.append(ctx.child_paths_mut());
 
             // 2. Register
@@ -57,11 +137,15 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> AssetLoader for ProtoAssetLoad
             let asset = LoadedAsset::new(prototype).with_dependencies(dependency_paths);
             load_context.set_default_asset(asset);
 
+            self.proto_registry
+                .write()
+                .set_content_hash(load_context.path(), content_hash);
+
             Ok(())
         })
     }
 
     fn extensions(&self) -> &[&str] {
-        self.loader.extensions()
+        &self.extensions
     }
 }
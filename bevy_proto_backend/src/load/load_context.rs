@@ -6,13 +6,14 @@ use std::path::Path;
 use bevy::asset::{Asset, AssetIo, AssetPath, HandleId, LoadContext, LoadedAsset};
 use bevy::prelude::Handle;
 use bevy::reflect::TypeRegistryInternal;
+use bevy::utils::{HashMap, HashSet};
 
 use crate::children::ProtoChildBuilder;
 use crate::deps::DependenciesBuilder;
 use crate::load::{Loader, ProtoLoadMeta};
 use crate::path::ProtoPathContext;
 use crate::proto::Prototypical;
-use crate::schematics::SchematicId;
+use crate::schematics::{validate_schematic_input, SchematicId};
 
 /// The context when loading a [prototype].
 ///
@@ -20,9 +21,14 @@ use crate::schematics::SchematicId;
 pub struct ProtoLoadContext<'a, 'ctx, T: Prototypical, L: Loader<T>> {
     registry: &'a TypeRegistryInternal,
     loader: &'a L,
+    extensions: &'a [&'static str],
+    flags: &'a HashSet<String>,
+    type_path_aliases: &'a HashMap<String, String>,
     load_context: Option<&'a mut LoadContext<'ctx>>,
     child_paths: Vec<AssetPath<'static>>,
+    local_ids: HashSet<String>,
     index_path: IndexPath,
+    bundle_entry: Option<String>,
     _phantom: PhantomData<T>,
 }
 
@@ -30,18 +36,31 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
     pub(crate) fn new(
         registry: &'a TypeRegistryInternal,
         loader: &'a L,
+        extensions: &'a [&'static str],
+        flags: &'a HashSet<String>,
+        type_path_aliases: &'a HashMap<String, String>,
         load_context: &'a mut LoadContext<'ctx>,
     ) -> Self {
         Self {
             registry,
             loader,
+            extensions,
+            flags,
+            type_path_aliases,
             load_context: Some(load_context),
             child_paths: Vec::new(),
+            local_ids: HashSet::new(),
             index_path: IndexPath::default(),
+            bundle_entry: None,
             _phantom: Default::default(),
         }
     }
 
+    pub(crate) fn with_bundle_entry(mut self, entry: String) -> Self {
+        self.bundle_entry = Some(entry);
+        self
+    }
+
     /// Bevy's type registry.
     pub fn registry(&self) -> &'a TypeRegistryInternal {
         self.registry
@@ -67,13 +86,19 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
         let mut ctx = Self {
             registry: self.registry,
             loader: self.loader,
+            extensions: self.extensions,
+            flags: self.flags,
+            type_path_aliases: self.type_path_aliases,
             load_context: self.load_context.take(),
             child_paths: Vec::new(),
+            local_ids: HashSet::new(),
             index_path: IndexPath::default(),
+            bundle_entry: self.bundle_entry.clone(),
             _phantom: Default::default(),
         };
 
         std::mem::swap(&mut ctx.child_paths, &mut self.child_paths);
+        std::mem::swap(&mut ctx.local_ids, &mut self.local_ids);
         std::mem::swap(&mut ctx.index_path, &mut self.index_path);
 
         let mut builder = ProtoChildBuilder::new(ctx);
@@ -81,6 +106,7 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
 
         self.load_context = builder.context.load_context;
         self.child_paths = builder.context.child_paths;
+        self.local_ids = builder.context.local_ids;
         self.index_path = builder.context.index_path;
 
         self.index_path.pop();
@@ -88,11 +114,47 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
         result
     }
 
+    /// The name of the [bundle entry] currently being loaded, if any.
+    ///
+    /// [bundle entry]: crate::load::ProtoBundleEntry
+    pub fn bundle_entry(&self) -> Option<&str> {
+        self.bundle_entry.as_deref()
+    }
+
     /// The loader used to load the prototype.
     pub fn loader(&self) -> &'a L {
         self.loader
     }
 
+    /// The set of active flags, as given by [`Config::active_flags`].
+    ///
+    /// [`Config::active_flags`]: crate::proto::Config::active_flags
+    pub fn flags(&self) -> &'a HashSet<String> {
+        self.flags
+    }
+
+    /// The map of deprecated type paths to their replacements,
+    /// as given by [`Config::type_path_aliases`].
+    ///
+    /// [`Config::type_path_aliases`]: crate::proto::Config::type_path_aliases
+    pub fn type_path_aliases(&self) -> &'a HashMap<String, String> {
+        self.type_path_aliases
+    }
+
+    /// Returns true if the given flag is active.
+    pub fn is_flag_active(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+
+    /// Registers a [local ID] for a child within the current file.
+    ///
+    /// Returns `false` if the ID has already been registered.
+    ///
+    /// [local ID]: crate::children::PrototypicalChild::local_id
+    pub fn register_local_id(&mut self, id: impl Into<String>) -> bool {
+        self.local_ids.insert(id.into())
+    }
+
     pub(crate) fn increment_index(&mut self) {
         self.index_path.increment();
     }
@@ -117,12 +179,15 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
     }
 
     pub(crate) fn meta(&self) -> ProtoLoadMeta<T> {
-        let label = if self.index_path.is_root() {
+        let label = match (&self.bundle_entry, self.index_path.is_root()) {
             // Root prototype
-            None
-        } else {
+            (None, true) => None,
             // Descendant prototype
-            Some(self.index_path.to_string())
+            (None, false) => Some(self.index_path.to_string()),
+            // Bundled root prototype
+            (Some(entry), true) => Some(entry.clone()),
+            // Bundled descendant prototype
+            (Some(entry), false) => Some(format!("{}/{}", entry, self.index_path)),
         };
 
         let path = AssetPath::new(self.base_path().to_owned(), label);
@@ -144,8 +209,14 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoLoadContext<'a, 'ctx, T, L> {
 
         let mut deps = DependenciesBuilder::new(self.load_context.as_mut().unwrap());
 
-        // 1. Track schematic dependencies
+        // 1. Validate and track schematic dependencies
         for (_, schematic) in prototype.schematics_mut().iter_mut() {
+            validate_schematic_input(
+                schematic.type_info().type_name(),
+                schematic.input(),
+                self.registry,
+            )?;
+
             let id = SchematicId::new(meta.handle.id(), schematic.type_info().type_id());
             schematic.preload_dependencies(id, &mut deps)?;
         }
@@ -181,7 +252,7 @@ impl<'a, 'ctx, T: Prototypical, L: Loader<T>> ProtoPathContext
     }
 
     fn extensions(&self) -> &[&'static str] {
-        self.loader.extensions()
+        self.extensions
     }
 }
 
@@ -6,6 +6,7 @@ use bevy::prelude::FromWorld;
 use std::error::Error;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
+use std::path::Path;
 
 /// Configures how a [prototype] should be loaded.
 ///
@@ -69,6 +70,42 @@ pub trait Loader<T: Prototypical>: FromWorld + Clone + Send + Sync + 'static {
         let _ = meta;
         Ok(prototype)
     }
+
+    /// Split a bundle file into the prototype files it contains.
+    ///
+    /// If this returns `Some`, each [entry] is deserialized separately using [`deserialize`]
+    /// and registered as a labeled asset of the bundle (e.g. `bundle.zip#Player.prototype.ron`).
+    /// The name of the entry being deserialized is available via [`ProtoLoadContext::bundle_entry`].
+    ///
+    /// Note that a bundle does not have a default asset,
+    /// so its prototypes should be accessed by ID rather than by the bundle's handle.
+    ///
+    /// By default, this returns `None`, meaning the file is treated as a single prototype.
+    ///
+    /// [entry]: ProtoBundleEntry
+    /// [`deserialize`]: Loader::deserialize
+    fn unbundle(
+        &self,
+        bytes: &[u8],
+        path: &Path,
+    ) -> Result<Option<Vec<ProtoBundleEntry>>, Self::Error> {
+        let _ = (bytes, path);
+        Ok(None)
+    }
+}
+
+/// A single prototype file contained within a bundle.
+///
+/// See [`Loader::unbundle`] for details.
+#[derive(Debug, Clone)]
+pub struct ProtoBundleEntry {
+    /// The name of the entry within the bundle.
+    ///
+    /// This is used as the asset label of the resulting prototype
+    /// and should include the file's extension.
+    pub name: String,
+    /// The raw bytes of the entry.
+    pub bytes: Vec<u8>,
 }
 
 /// Metadata about a [prototype] that is being loaded.
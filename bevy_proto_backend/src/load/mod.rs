@@ -1,3 +1,25 @@
+//! Items for loading [prototypes].
+//!
+//! # Asset Sources
+//!
+//! Prototypes are loaded through the [`AssetServer`] like any other asset,
+//! meaning they are read using whichever [`AssetIo`] the server was configured with.
+//! By default, this is the platform's default `AssetIo` (i.e. the `assets/` folder on desktop).
+//!
+//! Nothing in the loading process assumes a filesystem: relative [prototype paths]
+//! are resolved against the asset path of the prototype being loaded and checked
+//! using the same `AssetIo`.
+//! To load prototypes from an embedded or remote source, provide a custom `AssetIo`
+//! when setting up the [`AssetServer`].
+//!
+//! Note that this version of Bevy only supports a single `AssetIo` per [`AssetServer`],
+//! so the source cannot currently be selected on a per-load basis.
+//!
+//! [prototypes]: crate::proto::Prototypical
+//! [`AssetServer`]: bevy::asset::AssetServer
+//! [`AssetIo`]: bevy::asset::AssetIo
+//! [prototype paths]: crate::path::ProtoPath
+
 pub(crate) use asset_loader::*;
 pub use load_context::*;
 pub use loader::*;
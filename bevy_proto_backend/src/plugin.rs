@@ -1,16 +1,59 @@
 use std::marker::PhantomData;
 
-use crate::assets::ProtoAssetEvent;
+use crate::assets::{ProtoAssetCollections, ProtoAssetEvent};
 use bevy::app::{App, Plugin};
 use bevy::asset::AddAsset;
-use bevy::prelude::{FromWorld, Update};
+use bevy::prelude::{
+    apply_deferred, FromWorld, IntoSystemConfigs, IntoSystemSetConfigs, Resource, SystemSet, Update,
+};
+use bevy::utils::HashMap;
 use parking_lot::Mutex;
 
 use crate::impls;
 use crate::load::{Loader, ProtoAssetLoader};
-use crate::proto::{Config, ProtoStorage, Prototypical};
-use crate::registration::{on_proto_asset_event, ProtoRegistry};
-use crate::tree::{AccessOp, ChildAccess, EntityAccess, ProtoEntity};
+use crate::proto::{
+    add_registered_prototypes, apply_budgeted_prototypes, apply_ordered_prototypes,
+    despawn_needless_entities, resolve_proto_completions, validate_on_startup, Config,
+    ProtoApplyQueue, ProtoCompletions, ProtoOrderQueue, ProtoRegisterQueue, ProtoStorage,
+    Prototypical,
+};
+use crate::registration::{
+    on_proto_asset_event, on_prototypes_ready, reload_proto_assets, ProtoRegistry,
+};
+use crate::schematics::{Markers, ProtoExpr, ReflectSchematic};
+use crate::templates::ProtoMixins;
+use crate::tree::{
+    AccessOp, ChildAccess, EntityAccess, ProtoEntity, ProtoEntityByName, ProtoEntityList,
+};
+
+/// System sets used by the [`ProtoBackendPlugin`].
+///
+/// Both sets run in the [`Update`] schedule, in the order listed below.
+///
+/// Systems that spawn or apply [prototypes] via [`ProtoCommands`] should be ordered
+/// before [`ProtoSet::Apply`], and systems that react to the resulting entities
+/// should be ordered after it:
+///
+/// ```ignore
+/// app.add_systems(Update, (
+///   spawn_player.before(ProtoSet::Apply),
+///   inspect_player.after(ProtoSet::Apply),
+/// ));
+/// ```
+///
+/// [prototypes]: Prototypical
+/// [`ProtoCommands`]: crate::proto::ProtoCommands
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProtoSet {
+    /// Prototype assets that were loaded, modified, or removed are (re-)registered
+    /// or unregistered.
+    ///
+    /// [`ProtoAssetEvent`]s are sent during this set.
+    Register,
+    /// Commands queued before this set are flushed,
+    /// applying any pending prototype spawns, insertions, and removals to the world.
+    Apply,
+}
 
 /// Plugin to add support for the given [prototype] `P`.
 ///
@@ -18,6 +61,8 @@ use crate::tree::{AccessOp, ChildAccess, EntityAccess, ProtoEntity};
 pub struct ProtoBackendPlugin<T: Prototypical, L: Loader<T>, C: Config<T>> {
     config: Mutex<Option<C>>,
     loader: Mutex<Option<L>>,
+    extensions: Option<Vec<&'static str>>,
+    validate_on_startup: bool,
     _phantom: PhantomData<T>,
 }
 
@@ -26,6 +71,8 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> ProtoBackendPlugin<T, L, C> {
         Self {
             config: Mutex::new(None),
             loader: Mutex::new(None),
+            extensions: None,
+            validate_on_startup: false,
             _phantom: Default::default(),
         }
     }
@@ -41,19 +88,60 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> ProtoBackendPlugin<T, L, C> {
         self.loader = Mutex::new(Some(loader));
         self
     }
+
+    /// Explicitly set the file extensions handled by this plugin's asset loader,
+    /// overriding those returned by [`Loader::extensions`].
+    ///
+    /// This is useful when multiple [prototype] types are used in the same app
+    /// (e.g. the default one alongside a custom one) whose loaders would otherwise
+    /// claim the same extensions.
+    ///
+    /// As with [`Loader::extensions`], these should be ordered from most to least specific
+    /// and should not be prepended by a dot (`.`).
+    ///
+    /// # Conflicts
+    ///
+    /// Each extension may only be claimed by a single prototype type.
+    /// Extensions are compared exactly, so `"enemy.ron"` does not conflict with `"ron"`
+    /// (the asset server will prefer the longer match).
+    /// If an extension was already claimed by a previously built plugin,
+    /// building this plugin will panic with a message naming both prototype types.
+    ///
+    /// [prototype]: Prototypical
+    pub fn with_extensions<I: IntoIterator<Item = &'static str>>(mut self, extensions: I) -> Self {
+        self.extensions = Some(extensions.into_iter().collect());
+        self
+    }
+
+    /// Validate all stored prototypes once they have finished loading,
+    /// panicking with a [`ProtoValidationReport`] if any are invalid.
+    ///
+    /// Disabled by default.
+    ///
+    /// [`ProtoValidationReport`]: crate::proto::ProtoValidationReport
+    pub fn with_startup_validation(mut self, validate: bool) -> Self {
+        self.validate_on_startup = validate;
+        self
+    }
 }
 
 impl<T: Prototypical, L: Loader<T>, C: Config<T>> Plugin for ProtoBackendPlugin<T, L, C> {
     fn build(&self, app: &mut App) {
         // === Types === //
         #[cfg(feature = "bevy_render")]
-        app.register_type::<crate::proto::ProtoColor>();
+        app.register_type::<crate::proto::ProtoColor>()
+            .register_type::<crate::proto::NamedColor>();
 
         app.register_type::<ProtoEntity>()
             .register_type::<EntityAccess>()
             .register_type::<Option<EntityAccess>>()
             .register_type::<AccessOp>()
-            .register_type::<ChildAccess>();
+            .register_type::<ChildAccess>()
+            .register_type::<ProtoEntityList>()
+            .register_type::<ProtoEntityByName>()
+            .register_type::<ProtoExpr>()
+            .register_type::<Markers>()
+            .register_type_data::<Markers, ReflectSchematic>();
         impls::register_impls(app);
 
         // === Resources === //
@@ -64,7 +152,13 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> Plugin for ProtoBackendPlugin<
         }
 
         app.init_resource::<ProtoRegistry<T, C>>()
-            .init_resource::<ProtoStorage<T>>();
+            .init_resource::<ProtoStorage<T>>()
+            .init_resource::<ProtoRegisterQueue<T>>()
+            .init_resource::<ProtoApplyQueue<T, C>>()
+            .init_resource::<ProtoOrderQueue<T, C>>()
+            .init_resource::<ProtoCompletions<T, C>>()
+            .init_resource::<ProtoMixins>()
+            .init_resource::<ProtoAssetCollections>();
 
         // === Assets === //
         let loader = self
@@ -72,7 +166,26 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> Plugin for ProtoBackendPlugin<
             .lock()
             .take()
             .unwrap_or_else(|| <L as FromWorld>::from_world(&mut app.world));
-        let asset_loader = ProtoAssetLoader::<T, L, C>::new(loader, &mut app.world);
+        let extensions = self
+            .extensions
+            .clone()
+            .unwrap_or_else(|| loader.extensions().to_vec());
+
+        let mut claimed = app
+            .world
+            .get_resource_or_insert_with(ProtoExtensions::default);
+        for extension in &extensions {
+            if let Some(existing) = claimed.0.insert(*extension, std::any::type_name::<T>()) {
+                panic!(
+                    "the extension `{}` is claimed by both `{}` and `{}`: use `ProtoBackendPlugin::with_extensions` to give each prototype type a distinct set of extensions",
+                    extension,
+                    existing,
+                    std::any::type_name::<T>()
+                );
+            }
+        }
+
+        let asset_loader = ProtoAssetLoader::<T, L, C>::new(loader, extensions, &mut app.world);
 
         app.add_asset_loader(asset_loader).add_asset::<T>();
 
@@ -80,10 +193,53 @@ impl<T: Prototypical, L: Loader<T>, C: Config<T>> Plugin for ProtoBackendPlugin<
         app.add_event::<ProtoAssetEvent<T>>();
 
         // === Systems === //
-        app.add_systems(Update, on_proto_asset_event::<T, C>);
+        app.configure_sets(Update, (ProtoSet::Register, ProtoSet::Apply).chain())
+            .add_systems(
+                Update,
+                (
+                    (
+                        add_registered_prototypes::<T>,
+                        on_proto_asset_event::<T, C>,
+                        reload_proto_assets::<T, C>,
+                        on_prototypes_ready::<T, C>,
+                    )
+                        .chain()
+                        .in_set(ProtoSet::Register),
+                    (
+                        apply_deferred,
+                        apply_ordered_prototypes::<T, C>,
+                        apply_budgeted_prototypes::<T, C>,
+                        resolve_proto_completions::<T, C>,
+                        despawn_needless_entities,
+                    )
+                        .chain()
+                        .in_set(ProtoSet::Apply),
+                ),
+            );
+
+        if self.validate_on_startup {
+            app.add_systems(
+                Update,
+                validate_on_startup::<T, C>
+                    .in_set(ProtoSet::Register)
+                    .after(on_proto_asset_event::<T, C>),
+            );
+        }
+
+        #[cfg(feature = "analysis")]
+        app.add_systems(
+            Update,
+            crate::registration::report_unused_prototypes::<T, C>
+                .in_set(ProtoSet::Register)
+                .after(on_proto_asset_event::<T, C>),
+        );
     }
 }
 
+/// Tracks which prototype type claimed each asset loader extension.
+#[derive(Resource, Default)]
+struct ProtoExtensions(HashMap<&'static str, &'static str>);
+
 impl<T: Prototypical, L: Loader<T>, C: Config<T>> Default for ProtoBackendPlugin<T, L, C> {
     fn default() -> Self {
         Self::new()
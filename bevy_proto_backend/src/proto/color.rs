@@ -56,6 +56,27 @@ pub enum ProtoColor {
         /// Alpha channel. [0.0, 1.0]
         alpha: f32,
     },
+    /// HSV (hue, saturation, value) color with an alpha channel
+    ///
+    /// This is converted to an sRGBA [`Color`].
+    Hsva {
+        /// Hue channel. [0.0, 360.0]
+        hue: f32,
+        /// Saturation channel. [0.0, 1.0]
+        saturation: f32,
+        /// Value channel. [0.0, 1.0]
+        value: f32,
+        /// Alpha channel. [0.0, 1.0]
+        alpha: f32,
+    },
+    /// The color of a blackbody radiator at the given temperature, in Kelvin.
+    ///
+    /// This is useful for lighting, where candlelight is around `1900.0`,
+    /// daylight is around `6500.0`, and an overcast sky is around `7000.0`.
+    ///
+    /// The temperature is clamped to [1000.0, 40000.0] and converted to an opaque sRGBA [`Color`]
+    /// using Tanner Helland's approximation of the blackbody curve.
+    Temperature(f32),
     /// <div style="background-color:rgb(94%, 97%, 100%); width: 10px; padding: 10px; border: 1px solid;" ></div>
     AliceBlue,
     /// <div style="background-color:rgb(98%, 92%, 84%); width: 10px; padding: 10px; border: 1px solid;" ></div>
@@ -132,6 +153,77 @@ pub enum ProtoColor {
     Yellow,
     /// <div style="background-color:rgb(60%, 80%, 20%); width: 10px; padding: 10px; border: 1px solid;" ></div>
     YellowGreen,
+    /// One of the named color constants with a custom alpha channel.
+    ///
+    /// For example, `WithAlpha(Red, 0.5)` results in a translucent red.
+    WithAlpha(NamedColor, f32),
+}
+
+impl ProtoColor {
+    /// Create a [`ProtoColor::Hsva`] from the given hue, saturation, value, and alpha.
+    pub fn hsva(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+        Self::Hsva {
+            hue,
+            saturation,
+            value,
+            alpha,
+        }
+    }
+
+    /// Create a [`ProtoColor::Temperature`] from the given temperature, in Kelvin.
+    pub fn from_temperature(kelvin: f32) -> Self {
+        Self::Temperature(kelvin)
+    }
+}
+
+/// Converts an HSVA color to an sRGBA [`Color`].
+fn hsva_to_color(hue: f32, saturation: f32, value: f32, alpha: f32) -> Color {
+    let hue = hue.rem_euclid(360.0);
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let m = value - chroma;
+
+    let (red, green, blue) = match hue {
+        h if h < 60.0 => (chroma, x, 0.0),
+        h if h < 120.0 => (x, chroma, 0.0),
+        h if h < 180.0 => (0.0, chroma, x),
+        h if h < 240.0 => (0.0, x, chroma),
+        h if h < 300.0 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    Color::rgba(red + m, green + m, blue + m, alpha)
+}
+
+/// Converts a blackbody temperature (in Kelvin) to an opaque sRGBA [`Color`].
+///
+/// This uses Tanner Helland's approximation, which is accurate to within a few
+/// percent over the clamped range of [1000.0, 40000.0].
+fn temperature_to_color(kelvin: f32) -> Color {
+    let temp = kelvin.clamp(1000.0, 40000.0) / 100.0;
+
+    let red = if temp <= 66.0 {
+        255.0
+    } else {
+        329.698_73 * (temp - 60.0).powf(-0.133_204_76)
+    };
+
+    let green = if temp <= 66.0 {
+        99.470_8 * temp.ln() - 161.119_57
+    } else {
+        288.122_17 * (temp - 60.0).powf(-0.075_514_85)
+    };
+
+    let blue = if temp >= 66.0 {
+        255.0
+    } else if temp <= 19.0 {
+        0.0
+    } else {
+        138.517_73 * (temp - 10.0).ln() - 305.044_8
+    };
+
+    let channel = |value: f32| value.clamp(0.0, 255.0) / 255.0;
+    Color::rgb(channel(red), channel(green), channel(blue))
 }
 
 impl From<ProtoColor> for Color {
@@ -181,6 +273,13 @@ impl From<ProtoColor> for Color {
                 hue,
                 alpha,
             },
+            ProtoColor::Hsva {
+                hue,
+                saturation,
+                value,
+                alpha,
+            } => hsva_to_color(hue, saturation, value, alpha),
+            ProtoColor::Temperature(kelvin) => temperature_to_color(kelvin),
             ProtoColor::AliceBlue => Self::ALICE_BLUE,
             ProtoColor::AntiqueWhite => Self::ANTIQUE_WHITE,
             ProtoColor::Aquamarine => Self::AQUAMARINE,
@@ -219,6 +318,7 @@ impl From<ProtoColor> for Color {
             ProtoColor::White => Self::WHITE,
             ProtoColor::Yellow => Self::YELLOW,
             ProtoColor::YellowGreen => Self::YELLOW_GREEN,
+            ProtoColor::WithAlpha(color, alpha) => Color::from(color).with_a(alpha),
         }
     }
 }
@@ -284,3 +384,220 @@ impl Default for ProtoColor {
         }
     }
 }
+
+/// The named color constants of [`Color`], such as [`Red`] and [`AliceBlue`].
+///
+/// This is primarily used by [`ProtoColor::WithAlpha`] to adjust the alpha channel
+/// of a named color.
+/// Each variant converts to the [`ProtoColor`] variant of the same name,
+/// which in turn maps to the corresponding `Color` constant.
+///
+/// [`Red`]: NamedColor::Red
+/// [`AliceBlue`]: NamedColor::AliceBlue
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[reflect(PartialEq, Hash, Serialize, Deserialize)]
+pub enum NamedColor {
+    /// <div style="background-color:rgb(94%, 97%, 100%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    AliceBlue,
+    /// <div style="background-color:rgb(98%, 92%, 84%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    AntiqueWhite,
+    /// <div style="background-color:rgb(49%, 100%, 83%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Aquamarine,
+    /// <div style="background-color:rgb(94%, 100%, 100%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Azure,
+    /// <div style="background-color:rgb(96%, 96%, 86%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Beige,
+    /// <div style="background-color:rgb(100%, 89%, 77%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Bisque,
+    /// <div style="background-color:rgb(0%, 0%, 0%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Black,
+    /// <div style="background-color:rgb(0%, 0%, 100%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Blue,
+    /// <div style="background-color:rgb(86%, 8%, 24%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Crimson,
+    /// <div style="background-color:rgb(0%, 100%, 100%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Cyan,
+    /// <div style="background-color:rgb(25%, 25%, 25%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    DarkGray,
+    /// <div style="background-color:rgb(0%, 50%, 0%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    DarkGreen,
+    /// <div style="background-color:rgb(100%, 0%, 100%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Fuchsia,
+    /// <div style="background-color:rgb(100%, 84%, 0%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Gold,
+    /// <div style="background-color:rgb(50%, 50%, 50%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Gray,
+    /// <div style="background-color:rgb(0%, 100%, 0%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Green,
+    /// <div style="background-color:rgb(28%, 0%, 51%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Indigo,
+    /// <div style="background-color:rgb(20%, 80%, 20%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    LimeGreen,
+    /// <div style="background-color:rgb(50%, 0%, 0%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Maroon,
+    /// <div style="background-color:rgb(10%, 10%, 44%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    MidnightBlue,
+    /// <div style="background-color:rgb(0%, 0%, 50%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Navy,
+    /// <div style="background-color:rgba(0%, 0%, 0%, 0%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    None,
+    /// <div style="background-color:rgb(50%, 50%, 0%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Olive,
+    /// <div style="background-color:rgb(100%, 65%, 0%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Orange,
+    /// <div style="background-color:rgb(100%, 27%, 0%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    OrangeRed,
+    /// <div style="background-color:rgb(100%, 8%, 57%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Pink,
+    /// <div style="background-color:rgb(50%, 0%, 50%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Purple,
+    /// <div style="background-color:rgb(100%, 0%, 0%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Red,
+    /// <div style="background-color:rgb(98%, 50%, 45%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Salmon,
+    /// <div style="background-color:rgb(18%, 55%, 34%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    SeaGreen,
+    /// <div style="background-color:rgb(75%, 75%, 75%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Silver,
+    /// <div style="background-color:rgb(0%, 50%, 50%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Teal,
+    /// <div style="background-color:rgb(100%, 39%, 28%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Tomato,
+    /// <div style="background-color:rgb(25%, 88%, 82%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Turquoise,
+    /// <div style="background-color:rgb(93%, 51%, 93%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Violet,
+    /// <div style="background-color:rgb(100%, 100%, 100%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    White,
+    /// <div style="background-color:rgb(100%, 100%, 0%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    Yellow,
+    /// <div style="background-color:rgb(60%, 80%, 20%); width: 10px; padding: 10px; border: 1px solid;" ></div>
+    YellowGreen,
+}
+
+impl From<NamedColor> for ProtoColor {
+    fn from(value: NamedColor) -> Self {
+        match value {
+            NamedColor::AliceBlue => Self::AliceBlue,
+            NamedColor::AntiqueWhite => Self::AntiqueWhite,
+            NamedColor::Aquamarine => Self::Aquamarine,
+            NamedColor::Azure => Self::Azure,
+            NamedColor::Beige => Self::Beige,
+            NamedColor::Bisque => Self::Bisque,
+            NamedColor::Black => Self::Black,
+            NamedColor::Blue => Self::Blue,
+            NamedColor::Crimson => Self::Crimson,
+            NamedColor::Cyan => Self::Cyan,
+            NamedColor::DarkGray => Self::DarkGray,
+            NamedColor::DarkGreen => Self::DarkGreen,
+            NamedColor::Fuchsia => Self::Fuchsia,
+            NamedColor::Gold => Self::Gold,
+            NamedColor::Gray => Self::Gray,
+            NamedColor::Green => Self::Green,
+            NamedColor::Indigo => Self::Indigo,
+            NamedColor::LimeGreen => Self::LimeGreen,
+            NamedColor::Maroon => Self::Maroon,
+            NamedColor::MidnightBlue => Self::MidnightBlue,
+            NamedColor::Navy => Self::Navy,
+            NamedColor::None => Self::None,
+            NamedColor::Olive => Self::Olive,
+            NamedColor::Orange => Self::Orange,
+            NamedColor::OrangeRed => Self::OrangeRed,
+            NamedColor::Pink => Self::Pink,
+            NamedColor::Purple => Self::Purple,
+            NamedColor::Red => Self::Red,
+            NamedColor::Salmon => Self::Salmon,
+            NamedColor::SeaGreen => Self::SeaGreen,
+            NamedColor::Silver => Self::Silver,
+            NamedColor::Teal => Self::Teal,
+            NamedColor::Tomato => Self::Tomato,
+            NamedColor::Turquoise => Self::Turquoise,
+            NamedColor::Violet => Self::Violet,
+            NamedColor::White => Self::White,
+            NamedColor::Yellow => Self::Yellow,
+            NamedColor::YellowGreen => Self::YellowGreen,
+        }
+    }
+}
+
+impl From<NamedColor> for Color {
+    fn from(value: NamedColor) -> Self {
+        Color::from(ProtoColor::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rgba(expected: [f32; 4], color: ProtoColor) {
+        let actual = Color::from(color).as_rgba_f32();
+        for (expected, actual) in expected.iter().zip(actual) {
+            assert!(
+                (expected - actual).abs() <= 1.0 / 255.0,
+                "expected {:?}, found {:?}",
+                expected,
+                actual
+            );
+        }
+    }
+
+    #[test]
+    fn should_convert_hsva() {
+        assert_rgba([1.0, 0.0, 0.0, 1.0], ProtoColor::hsva(0.0, 1.0, 1.0, 1.0));
+        assert_rgba([0.0, 1.0, 0.0, 1.0], ProtoColor::hsva(120.0, 1.0, 1.0, 1.0));
+        assert_rgba([0.0, 0.0, 1.0, 0.5], ProtoColor::hsva(240.0, 1.0, 1.0, 0.5));
+        assert_rgba([1.0, 0.0, 1.0, 1.0], ProtoColor::hsva(300.0, 1.0, 1.0, 1.0));
+        assert_rgba([0.5, 0.5, 0.5, 1.0], ProtoColor::hsva(42.0, 0.0, 0.5, 1.0));
+        // Hue wraps around
+        assert_rgba([1.0, 0.0, 0.0, 1.0], ProtoColor::hsva(360.0, 1.0, 1.0, 1.0));
+        // #336699
+        assert_rgba(
+            [0.2, 0.4, 0.6, 1.0],
+            ProtoColor::hsva(210.0, 2.0 / 3.0, 0.6, 1.0),
+        );
+    }
+
+    #[test]
+    fn should_convert_temperature() {
+        // Reference values from Tanner Helland's blackbody approximation
+        assert_rgba(
+            [1.0, 68.0 / 255.0, 0.0, 1.0],
+            ProtoColor::from_temperature(1000.0),
+        );
+        assert_rgba([1.0, 1.0, 1.0, 1.0], ProtoColor::from_temperature(6600.0));
+        assert_rgba(
+            [202.0 / 255.0, 218.0 / 255.0, 1.0, 1.0],
+            ProtoColor::from_temperature(10000.0),
+        );
+        // Out-of-range temperatures are clamped
+        assert_rgba(
+            [1.0, 68.0 / 255.0, 0.0, 1.0],
+            ProtoColor::from_temperature(0.0),
+        );
+    }
+
+    #[test]
+    fn should_round_trip_existing_variants() {
+        let colors = [
+            Color::rgba(0.1, 0.2, 0.3, 0.4),
+            Color::rgba_linear(0.1, 0.2, 0.3, 0.4),
+            Color::hsla(120.0, 0.5, 0.5, 1.0),
+            Color::lcha(0.5, 0.5, 120.0, 1.0),
+        ];
+
+        for color in colors {
+            assert_eq!(color, Color::from(ProtoColor::from(color)));
+        }
+    }
+
+    #[test]
+    fn should_use_color_constants_for_named_colors() {
+        assert_eq!(Color::ALICE_BLUE, Color::from(NamedColor::AliceBlue));
+        assert_eq!(Color::NONE, Color::from(NamedColor::None));
+        assert_eq!(
+            Color::RED.with_a(0.5),
+            Color::from(ProtoColor::WithAlpha(NamedColor::Red, 0.5))
+        );
+    }
+}
@@ -1,12 +1,21 @@
+use std::collections::VecDeque;
 use std::marker::PhantomData;
 
-use bevy::asset::Assets;
-use bevy::ecs::system::{Command, EntityCommands, SystemParam};
-use bevy::prelude::{Commands, Entity, Mut, World};
+use bevy::asset::{Assets, HandleId};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::ecs::system::{Command, CommandQueue, EntityCommands, SystemParam};
+use bevy::hierarchy::{DespawnRecursiveExt, Parent};
+use bevy::log::{error, info_span, trace, warn};
+use bevy::prelude::{
+    AppTypeRegistry, Commands, Component, Entity, Mut, Reflect, Res, Resource, With, World,
+};
 
-use crate::proto::{Config, Prototypical};
+use crate::proto::{Config, ProtoCompletion, ProtoCompletionCommand, ProtoInstance, Prototypical};
 use crate::registration::ProtoRegistry;
-use crate::schematics::{DynamicSchematic, SchematicContext, SchematicId};
+use crate::schematics::{
+    DynamicSchematic, InsertMode, ProtoParams, SchematicContext, SchematicError, SchematicId,
+};
+use crate::templates::ProtoMixins;
 use crate::tree::EntityTreeNode;
 
 /// A system parameter similar to [`Commands`], but catered towards [prototypes].
@@ -15,6 +24,7 @@ use crate::tree::EntityTreeNode;
 #[derive(SystemParam)]
 pub struct ProtoCommands<'w, 's, T: Prototypical, C: Config<T>> {
     commands: Commands<'w, 's>,
+    registry: Res<'w, ProtoRegistry<T, C>>,
     #[system_param(ignore)]
     _phantom: PhantomData<(T, C)>,
 }
@@ -24,10 +34,70 @@ impl<'w, 's, T: Prototypical, C: Config<T>> ProtoCommands<'w, 's, T, C> {
     ///
     /// This internally calls [`Commands::spawn`].
     ///
+    /// If the prototype turns out to only contain schematics that don't [require an entity]
+    /// (such as resource schematics) and has no children, a warning is logged and the
+    /// needless entity is despawned at the end of [`ProtoSet::Apply`].
+    /// Such prototypes should be applied with [`apply`] instead.
+    ///
     /// [ID]: Prototypical::id
+    /// [require an entity]: crate::schematics::Schematic::requires_entity
+    /// [`apply`]: Self::apply
+    /// [`ProtoSet::Apply`]: crate::ProtoSet::Apply
     pub fn spawn<I: Into<T::Id>>(&mut self, id: I) -> ProtoEntityCommands<'w, 's, '_, T, C> {
+        let entity = self.commands.spawn_empty().id();
+        self.add(ProtoInsertCommand::<T, C>::spawned(id.into(), entity));
+        ProtoEntityCommands::new(entity, self)
+    }
+
+    /// Spawn the prototype with the given [ID] using the given [parameters].
+    ///
+    /// The parameters are available to every schematic in the prototype's hierarchy
+    /// and are used to evaluate [`ProtoExpr`] fields.
+    ///
+    /// [ID]: Prototypical::id
+    /// [parameters]: ProtoParams
+    /// [`ProtoExpr`]: crate::schematics::ProtoExpr
+    pub fn spawn_with_params<I: Into<T::Id>>(
+        &mut self,
+        id: I,
+        params: ProtoParams,
+    ) -> ProtoEntityCommands<'w, 's, '_, T, C> {
+        let entity = self.commands.spawn_empty().id();
+        self.add(ProtoInsertCommand::<T, C>::spawned(id.into(), entity).with_params(params));
+        ProtoEntityCommands::new(entity, self)
+    }
+
+    /// Spawn the prototype with the given [ID], spreading its application across multiple frames.
+    ///
+    /// See [`ProtoEntityCommands::insert_budgeted`] for details.
+    ///
+    /// [ID]: Prototypical::id
+    pub fn spawn_budgeted<I: Into<T::Id>>(
+        &mut self,
+        id: I,
+    ) -> ProtoEntityCommands<'w, 's, '_, T, C> {
         let mut entity = ProtoEntityCommands::new(self.commands.spawn_empty().id(), self);
-        entity.insert(id);
+        entity.insert_budgeted(id);
+        entity
+    }
+
+    /// Spawn the prototype with the given [ID], applying it according to the given `order`.
+    ///
+    /// See [`ProtoEntityCommands::insert_ordered`] for details.
+    ///
+    /// [ID]: Prototypical::id
+    pub fn spawn_ordered<I: Into<T::Id>>(
+        &mut self,
+        id: I,
+        order: i32,
+    ) -> ProtoEntityCommands<'w, 's, '_, T, C> {
+        let entity = self.commands.spawn_empty().id();
+        self.add(ProtoOrderedInsertCommand::new(
+            ProtoInsertCommand::<T, C>::spawned(id.into(), entity),
+            order,
+        ));
+        let mut entity = ProtoEntityCommands::new(entity, self);
+        entity.is_ordered = true;
         entity
     }
 
@@ -61,6 +131,23 @@ impl<'w, 's, T: Prototypical, C: Config<T>> ProtoCommands<'w, 's, T, C> {
         self.add(ProtoRemoveCommand::<T, C>::new(id.into(), None));
     }
 
+    /// Despawn all root entities spawned from the prototype with the given [ID],
+    /// along with their descendants.
+    ///
+    /// The prototype is [removed] from each entity before it is despawned,
+    /// so that any removal hooks are run.
+    ///
+    /// Only entities spawned (or inserted) by this crate are affected,
+    /// and entities spawned as children of another prototype are not considered roots.
+    /// Note that this queries all [`ProtoInstance`] entities, so it should be avoided
+    /// in performance-sensitive code.
+    ///
+    /// [ID]: Prototypical::id
+    /// [removed]: ProtoEntityCommands::remove
+    pub fn despawn_all<I: Into<T::Id>>(&mut self, id: I) {
+        self.add(ProtoDespawnAllCommand::<T, C>::new(id.into()));
+    }
+
     /// Get the [`ProtoEntityCommands`] for the given entity.
     ///
     /// This internally calls [`Commands::entity`].
@@ -99,6 +186,20 @@ impl<'w, 's, T: Prototypical, C: Config<T>> ProtoCommands<'w, 's, T, C> {
     /// spawning a new one if it doesn't exist.
     ///
     /// This internally calls [`Commands::get_or_spawn`].
+    ///
+    /// This can be used to spawn a prototype onto a specific, pre-reserved entity,
+    /// such as one assigned by a server when synchronizing entities over the network.
+    /// Inserting a prototype onto the returned entity roots the prototype's hierarchy
+    /// at that exact entity, while its children are spawned as usual.
+    ///
+    /// ```ignore
+    /// fn on_server_spawn(mut commands: ProtoCommands, entity: Entity) {
+    ///   commands.get_or_spawn(entity).insert("Player");
+    /// }
+    /// ```
+    ///
+    /// Note that if an entity with the same index but a different generation is alive,
+    /// no entity will be spawned, so the prototype cannot be inserted onto it.
     pub fn get_or_spawn(&mut self, entity: Entity) -> ProtoEntityCommands<'w, 's, '_, T, C> {
         ProtoEntityCommands::new(self.commands.get_or_spawn(entity).id(), self)
     }
@@ -119,6 +220,10 @@ impl<'w, 's, T: Prototypical, C: Config<T>> ProtoCommands<'w, 's, T, C> {
 pub struct ProtoEntityCommands<'w, 's, 'a, T: Prototypical, C: Config<T>> {
     entity: Entity,
     proto_commands: &'a mut ProtoCommands<'w, 's, T, C>,
+    /// Whether an [ordered insertion] was queued through these commands.
+    ///
+    /// [ordered insertion]: Self::insert_ordered
+    is_ordered: bool,
 }
 
 impl<'w, 's, 'a, T: Prototypical, C: Config<T>> ProtoEntityCommands<'w, 's, 'a, T, C> {
@@ -126,6 +231,7 @@ impl<'w, 's, 'a, T: Prototypical, C: Config<T>> ProtoEntityCommands<'w, 's, 'a,
         Self {
             entity,
             proto_commands,
+            is_ordered: false,
         }
     }
 
@@ -139,8 +245,130 @@ impl<'w, 's, 'a, T: Prototypical, C: Config<T>> ProtoEntityCommands<'w, 's, 'a,
     /// [ID]: Prototypical::id
     pub fn insert<I: Into<T::Id>>(&mut self, id: I) -> &mut Self {
         let id = id.into();
-        self.proto_commands
-            .add(ProtoInsertCommand::<T, C>::new(id, Some(self.entity)));
+        self.add(ProtoInsertCommand::<T, C>::new(id, Some(self.entity)));
+        self
+    }
+
+    /// Inserts the prototype with the given [ID] onto the entity using the given [parameters].
+    ///
+    /// See [`ProtoCommands::spawn_with_params`] for details.
+    ///
+    /// [ID]: Prototypical::id
+    /// [parameters]: ProtoParams
+    pub fn insert_with_params<I: Into<T::Id>>(&mut self, id: I, params: ProtoParams) -> &mut Self {
+        let id = id.into();
+        self.add(ProtoInsertCommand::<T, C>::new(id, Some(self.entity)).with_params(params));
+        self
+    }
+
+    /// Applies the schematics of the template prototype with the given [ID] onto the entity.
+    ///
+    /// This allows templates to be used as reusable mixins at runtime,
+    /// composing behavior onto an existing entity.
+    ///
+    /// Unlike [spawning] a prototype, no new entity is created.
+    /// The template's own templates are applied as well,
+    /// but children are only spawned if the template itself defines them.
+    ///
+    /// Unlike [inserting] a prototype, the template may be [abstract],
+    /// and the entity is not tracked as an instance of the template in its [`ProtoInstance`].
+    /// This means the entity won't be affected by [`ProtoCommands::despawn_all`]
+    /// or returned by [`ProtoWorld::instances_of`] for the template.
+    ///
+    /// [ID]: Prototypical::id
+    /// [spawning]: ProtoCommands::spawn
+    /// [inserting]: Self::insert
+    /// [abstract]: Prototypical::is_abstract
+    /// [`ProtoWorld::instances_of`]: crate::proto::ProtoWorld::instances_of
+    pub fn apply_template<I: Into<T::Id>>(&mut self, id: I) -> &mut Self {
+        let id = id.into();
+        self.add(ProtoInsertCommand::<T, C>::template(id, self.entity));
+        self
+    }
+
+    /// Inserts the prototype with the given [ID] onto the entity if it's ready.
+    ///
+    /// A prototype is considered ready once it has been registered,
+    /// which happens after it and all of its dependencies have finished loading.
+    /// If the prototype is not yet ready, this does nothing and returns `false`.
+    ///
+    /// This is useful for polling-style code that attempts to insert a prototype
+    /// every frame until it becomes available.
+    ///
+    /// Note that readiness is checked when this method is called, not when the command is applied.
+    /// If the prototype is unregistered in between (e.g. due to a hot-reload),
+    /// the insertion will still panic like [`insert`].
+    ///
+    /// [ID]: Prototypical::id
+    /// [`insert`]: Self::insert
+    pub fn insert_if_ready<I: Into<T::Id>>(&mut self, id: I) -> bool {
+        let id = id.into();
+
+        if !self.proto_commands.registry.contains(&id) {
+            trace!(
+                "skipping insertion of prototype {:?} on entity {:?}: not ready",
+                id,
+                self.entity
+            );
+            return false;
+        }
+
+        self.insert(id);
+        true
+    }
+
+    /// Inserts the prototype with the given [ID] onto the entity,
+    /// applying it according to the given `order`.
+    ///
+    /// Normally, prototypes are applied in the order their commands are flushed,
+    /// which can make references between prototypes spawned in the same frame
+    /// (e.g. a weapon that references the unit wielding it) depend on system ordering.
+    /// Ordered insertions are instead collected and applied together during [`ProtoSet::Apply`],
+    /// once all other pending commands have been flushed.
+    ///
+    /// Prototypes with a lower `order` are applied first.
+    /// Ties are resolved by the order in which the commands were flushed,
+    /// which, within a single system, is the order in which they were queued.
+    ///
+    /// Note that ordered insertions queued by systems running after [`ProtoSet::Apply`]
+    /// are applied during the next frame's [`ProtoSet::Apply`].
+    ///
+    /// Any commands chained after this one (such as [`on_applied`] or [`insert_reflected`])
+    /// are deferred as well, and run immediately after the prototype has been applied.
+    /// This does not apply to commands queued through [`entity_commands`]
+    /// or through a separate [`ProtoEntityCommands`] for the same entity.
+    ///
+    /// [ID]: Prototypical::id
+    /// [`ProtoSet::Apply`]: crate::ProtoSet::Apply
+    /// [`on_applied`]: Self::on_applied
+    /// [`insert_reflected`]: Self::insert_reflected
+    /// [`entity_commands`]: Self::entity_commands
+    pub fn insert_ordered<I: Into<T::Id>>(&mut self, id: I, order: i32) -> &mut Self {
+        let id = id.into();
+        self.is_ordered = true;
+        self.proto_commands.add(ProtoOrderedInsertCommand::new(
+            ProtoInsertCommand::<T, C>::new(id, Some(self.entity)),
+            order,
+        ));
+        self
+    }
+
+    /// Inserts the prototype with the given [ID] onto the entity,
+    /// spreading its application across multiple frames.
+    ///
+    /// This is useful for large hierarchies that would otherwise cause a frame spike.
+    /// Each frame, at most [`Config::apply_budget`] entities have their schematics applied.
+    ///
+    /// The entity itself is available immediately, but note that the hierarchy will be
+    /// incomplete until the prototype has been fully applied: while all descendant entities
+    /// are spawned right away, their schematics are applied progressively.
+    /// If the entity is despawned or the prototype is unloaded mid-construction,
+    /// the remaining work is discarded.
+    ///
+    /// [ID]: Prototypical::id
+    pub fn insert_budgeted<I: Into<T::Id>>(&mut self, id: I) -> &mut Self {
+        let id = id.into();
+        self.add(ProtoBudgetedInsertCommand::<T, C>::new(id, self.entity));
         self
     }
 
@@ -149,11 +377,103 @@ impl<'w, 's, 'a, T: Prototypical, C: Config<T>> ProtoEntityCommands<'w, 's, 'a,
     /// [ID]: Prototypical::id
     pub fn remove<I: Into<T::Id>>(&mut self, id: I) -> &mut Self {
         let id = id.into();
-        self.proto_commands
-            .add(ProtoRemoveCommand::<T, C>::new(id, Some(self.entity)));
+        self.add(ProtoRemoveCommand::<T, C>::new(id, Some(self.entity)));
+        self
+    }
+
+    /// Replaces the prototype with the given `from` [ID] with the one with the given `to` ID.
+    ///
+    /// The schematics of `from` are removed and the schematics of `to` are then applied,
+    /// all within a single command.
+    /// This guarantees that no other command can run in between the two operations.
+    ///
+    /// This is useful for state transitions, such as swapping an enemy's "Calm"
+    /// prototype for an "Enraged" one.
+    ///
+    /// The entity does not need to have had `from` applied to it.
+    /// In that case, the removal of each schematic still runs but typically does nothing
+    /// (e.g. removing a component the entity doesn't have),
+    /// so this behaves like a regular [insertion] of `to`.
+    /// Note that any components shared by both prototypes will be removed and then re-inserted.
+    ///
+    /// [ID]: Prototypical::id
+    /// [insertion]: Self::insert
+    pub fn swap<I: Into<T::Id>>(&mut self, from: I, to: I) -> &mut Self {
+        self.add(ProtoSwapCommand::<T, C>::new(
+            from.into(),
+            to.into(),
+            self.entity,
+        ));
+        self
+    }
+
+    /// Inserts the given reflected component onto the entity.
+    ///
+    /// This allows procedurally-generated data to be added alongside a prototype
+    /// without needing to define a schematic for it.
+    /// Like other prototype commands, this is applied in order with the rest of
+    /// the entity's queued commands.
+    ///
+    /// The value's type must be registered with [`ReflectComponent`],
+    /// otherwise the command will panic.
+    ///
+    /// [`ReflectComponent`]: bevy::ecs::reflect::ReflectComponent
+    pub fn insert_reflected(&mut self, value: Box<dyn Reflect>) -> &mut Self {
+        self.add(ProtoInsertReflectedCommand::new(self.entity, value));
+        self
+    }
+
+    /// Queues a callback to be run with the entity once all previously queued
+    /// prototype commands for it have been applied.
+    ///
+    /// Since commands are applied in order, this can be used to access the components
+    /// inserted by a prototype immediately after it has been spawned.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// commands.spawn("Player").on_applied(|world, entity| {
+    ///   let health = world.get::<Health>(entity).unwrap();
+    ///   info!("Player spawned with {:?} health", health);
+    /// });
+    /// ```
+    pub fn on_applied<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: FnOnce(&mut World, Entity) + Send + 'static,
+    {
+        let entity = self.entity;
+        self.add(move |world: &mut World| callback(world, entity));
         self
     }
 
+    /// Returns a [`ProtoCompletion`] that resolves once all previously queued
+    /// prototype commands for the entity have been fully applied.
+    ///
+    /// Unlike [`on_applied`], this also waits for [budgeted] insertions to finish
+    /// and for any scenes within the entity's hierarchy to finish spawning.
+    /// This makes it useful for orchestrating loading sequences.
+    ///
+    /// If the entity is despawned first, the completion resolves to `None`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let completion = commands.spawn("Level").completion();
+    /// AsyncComputeTaskPool::get().spawn(async move {
+    ///   if let Some(entity) = completion.await {
+    ///     info!("Level {:?} is fully loaded", entity);
+    ///   }
+    /// }).detach();
+    /// ```
+    ///
+    /// [`on_applied`]: Self::on_applied
+    /// [budgeted]: Self::insert_budgeted
+    pub fn completion(&mut self) -> ProtoCompletion {
+        let (completion, sender) = ProtoCompletion::new();
+        self.add(ProtoCompletionCommand::<T, C>::new(self.entity, sender));
+        completion
+    }
+
     /// Returns the underlying [`ProtoCommands`].
     pub fn commands(&mut self) -> &mut ProtoCommands<'w, 's, T, C> {
         self.proto_commands
@@ -163,6 +483,21 @@ impl<'w, 's, 'a, T: Prototypical, C: Config<T>> ProtoEntityCommands<'w, 's, 'a,
     pub fn entity_commands(&'a mut self) -> EntityCommands<'w, 's, 'a> {
         self.proto_commands.commands.entity(self.entity)
     }
+
+    /// Queues a command for the entity.
+    ///
+    /// If an [ordered insertion] was queued through these commands,
+    /// the command is deferred until that insertion has been applied.
+    ///
+    /// [ordered insertion]: Self::insert_ordered
+    fn add<Cmd: Command>(&mut self, command: Cmd) {
+        if self.is_ordered {
+            self.proto_commands
+                .add(ProtoChainedCommand::new(self.entity, command));
+        } else {
+            self.proto_commands.add(command);
+        }
+    }
 }
 
 /// A [command] to insert a [prototype] on an entity.
@@ -171,6 +506,12 @@ impl<'w, 's, 'a, T: Prototypical, C: Config<T>> ProtoEntityCommands<'w, 's, 'a,
 /// [prototype]: Prototypical
 pub struct ProtoInsertCommand<T: Prototypical, C: Config<T>> {
     data: ProtoCommandData<T, C>,
+    /// Whether the entity was spawned specifically for this prototype.
+    is_spawn: bool,
+    /// Whether the prototype is being applied as a template.
+    ///
+    /// Templates may be abstract and are not tracked in the entity's [`ProtoInstance`].
+    is_template: bool,
 }
 
 impl<T: Prototypical, C: Config<T>> ProtoInsertCommand<T, C> {
@@ -179,20 +520,106 @@ impl<T: Prototypical, C: Config<T>> ProtoInsertCommand<T, C> {
             data: ProtoCommandData {
                 id,
                 entity,
+                params: None,
                 _phantom: PhantomData,
             },
+            is_spawn: false,
+            is_template: false,
+        }
+    }
+
+    /// Create a command that applies the prototype onto the given entity as a template.
+    ///
+    /// See [`ProtoEntityCommands::apply_template`] for details.
+    pub fn template(id: T::Id, entity: Entity) -> Self {
+        Self {
+            is_template: true,
+            ..Self::new(id, Some(entity))
+        }
+    }
+
+    /// Create a command that inserts the prototype onto a freshly spawned entity.
+    ///
+    /// If the prototype does not actually need an entity,
+    /// the entity will be marked with [`NeedlessEntity`] and despawned later.
+    pub fn spawned(id: T::Id, entity: Entity) -> Self {
+        Self {
+            is_spawn: true,
+            ..Self::new(id, Some(entity))
         }
     }
+
+    /// Sets the [parameters] available to the prototype's schematics.
+    ///
+    /// [parameters]: ProtoParams
+    pub fn with_params(mut self, params: ProtoParams) -> Self {
+        self.data.params = Some(params);
+        self
+    }
+
+    /// Applies the prototype's schematics without tracking it in the entity's [`ProtoInstance`].
+    fn apply_schematics(&self, world: &mut World) {
+        #[cfg(feature = "analysis")]
+        world
+            .resource_mut::<ProtoRegistry<T, C>>()
+            .mark_applied(&self.data.id);
+
+        let skip_failed = world.resource::<C>().skip_failed_schematics();
+
+        self.data
+            .for_each_schematic(world, true, |schematic, id, context| {
+                if let Err(err) = schematic.apply(id, context) {
+                    on_schematic_error(&self.data.id, schematic, err, skip_failed);
+                }
+            });
+    }
 }
 
 impl<T: Prototypical, C: Config<T>> Command for ProtoInsertCommand<T, C> {
     fn apply(self, world: &mut World) {
         self.data.assert_is_registered(world);
 
-        self.data
-            .for_each_schematic(world, true, |schematic, id, context| {
-                schematic.apply(id, context).unwrap();
-            });
+        if !self.is_template {
+            self.data.assert_is_not_abstract(world);
+            self.data.track_instance(world);
+        }
+
+        self.apply_schematics(world);
+
+        if let Some(entity) = self.data.entity.filter(|_| self.is_spawn) {
+            if !self.data.needs_entity(world) {
+                warn!(
+                    "prototype {:?} was spawned but does not require an entity: despawning {:?} (consider using `ProtoCommands::apply` instead)",
+                    &self.data.id,
+                    entity
+                );
+                // Despawned later so that commands chained onto the entity can still run
+                world.entity_mut(entity).insert(NeedlessEntity);
+            }
+        }
+    }
+}
+
+/// Marker component for entities that were spawned for a [prototype] that doesn't need one.
+///
+/// These entities are despawned by [`despawn_needless_entities`].
+///
+/// [prototype]: Prototypical
+#[derive(Component)]
+pub(crate) struct NeedlessEntity;
+
+/// Despawns all entities marked with [`NeedlessEntity`].
+///
+/// This runs after all prototypes have been applied, so that any commands chained onto
+/// those entities have had a chance to run.
+pub(crate) fn despawn_needless_entities(world: &mut World) {
+    let entities = world
+        .query_filtered::<Entity, With<NeedlessEntity>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+
+    for entity in entities {
+        world.despawn(entity);
     }
 }
 
@@ -210,30 +637,224 @@ impl<T: Prototypical, C: Config<T>> ProtoRemoveCommand<T, C> {
             data: ProtoCommandData {
                 id,
                 entity,
+                params: None,
                 _phantom: PhantomData,
             },
         }
     }
+
+    /// Removes the prototype's schematics without untracking it from the entity's [`ProtoInstance`].
+    fn remove_schematics(&self, world: &mut World) {
+        self.data
+            .for_each_schematic(world, false, |schematic, id, context| {
+                schematic.remove(id, context).unwrap();
+            });
+    }
 }
 
 impl<T: Prototypical, C: Config<T>> Command for ProtoRemoveCommand<T, C> {
     fn apply(self, world: &mut World) {
         self.data.assert_is_registered(world);
 
-        self.data
-            .for_each_schematic(world, false, |schematic, id, context| {
-                schematic.remove(id, context).unwrap();
+        self.remove_schematics(world);
+        self.data.untrack_instance(world);
+    }
+}
+
+/// A [command] to despawn all root entities spawned from a [prototype].
+///
+/// See [`ProtoCommands::despawn_all`] for details.
+///
+/// [command]: Command
+/// [prototype]: Prototypical
+pub struct ProtoDespawnAllCommand<T: Prototypical, C: Config<T>> {
+    data: ProtoCommandData<T, C>,
+}
+
+impl<T: Prototypical, C: Config<T>> ProtoDespawnAllCommand<T, C> {
+    pub fn new(id: T::Id) -> Self {
+        Self {
+            data: ProtoCommandData {
+                id,
+                entity: None,
+                params: None,
+                _phantom: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T: Prototypical, C: Config<T>> Command for ProtoDespawnAllCommand<T, C> {
+    fn apply(self, world: &mut World) {
+        self.data.assert_is_registered(world);
+
+        let handle = self.data.handle(world).unwrap();
+
+        for entity in find_root_instances(world, handle) {
+            ProtoRemoveCommand::<T, C>::new(self.data.id.clone(), Some(entity)).apply(world);
+            world.entity_mut(entity).despawn_recursive();
+        }
+    }
+}
+
+/// Returns all root entities that the prototype with the given handle is currently applied to.
+///
+/// An instance is considered a root if it has no parent that is itself a prototype instance.
+/// The entities are returned in the order they were spawned.
+pub(crate) fn find_root_instances(world: &mut World, handle: HandleId) -> Vec<Entity> {
+    let mut query = world.query::<(Entity, &ProtoInstance, Option<&Parent>)>();
+    let mut instances = query
+        .iter(world)
+        .filter(|(_, instance, _)| instance.contains(handle))
+        .filter(|(_, _, parent)| {
+            parent.map_or(true, |parent| {
+                world.get::<ProtoInstance>(parent.get()).is_none()
+            })
+        })
+        .map(|(entity, instance, _)| (instance.spawn_index(), entity))
+        .collect::<Vec<_>>();
+
+    instances.sort_unstable();
+    instances.into_iter().map(|(_, entity)| entity).collect()
+}
+
+/// A [command] to replace one [prototype] with another on an entity.
+///
+/// See [`ProtoEntityCommands::swap`] for details.
+///
+/// [command]: Command
+/// [prototype]: Prototypical
+pub struct ProtoSwapCommand<T: Prototypical, C: Config<T>> {
+    remove: ProtoRemoveCommand<T, C>,
+    insert: ProtoInsertCommand<T, C>,
+}
+
+impl<T: Prototypical, C: Config<T>> ProtoSwapCommand<T, C> {
+    pub fn new(from: T::Id, to: T::Id, entity: Entity) -> Self {
+        Self {
+            remove: ProtoRemoveCommand::new(from, Some(entity)),
+            insert: ProtoInsertCommand::new(to, Some(entity)),
+        }
+    }
+}
+
+impl<T: Prototypical, C: Config<T>> Command for ProtoSwapCommand<T, C> {
+    fn apply(self, world: &mut World) {
+        // Check both prototypes up front so that a missing `to` doesn't leave the entity stripped
+        self.remove.data.assert_is_registered(world);
+        self.insert.data.assert_is_registered(world);
+        self.insert.data.assert_is_not_abstract(world);
+
+        self.remove.remove_schematics(world);
+
+        // Replace `from` in place so that the entity is no longer considered an instance of it
+        let from = self.remove.data.handle(world).unwrap();
+        let to = self.insert.data.handle(world).unwrap();
+        match self
+            .insert
+            .data
+            .entity
+            .and_then(|entity| world.get_mut::<ProtoInstance>(entity))
+        {
+            Some(mut instance) => instance.replace(from, to),
+            None => self.insert.data.track_instance(world),
+        }
+
+        self.insert.apply_schematics(world);
+    }
+}
+
+/// A [command] to insert a reflected component onto an entity.
+///
+/// See [`ProtoEntityCommands::insert_reflected`] for details.
+///
+/// [command]: Command
+pub struct ProtoInsertReflectedCommand {
+    entity: Entity,
+    value: Box<dyn Reflect>,
+}
+
+impl ProtoInsertReflectedCommand {
+    pub fn new(entity: Entity, value: Box<dyn Reflect>) -> Self {
+        Self { entity, value }
+    }
+}
+
+impl Command for ProtoInsertReflectedCommand {
+    fn apply(self, world: &mut World) {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        let type_name = self.value.type_name();
+        let reflect_component = registry
+            .get_with_name(type_name)
+            .and_then(|registration| registration.data::<ReflectComponent>())
+            .unwrap_or_else(|| {
+                panic!(
+                    "could not insert reflected value of type `{}`: type is not registered as a component (make sure it is registered with `#[reflect(Component)]`)",
+                    type_name
+                )
             });
+
+        let mut entity = world.get_entity_mut(self.entity).unwrap_or_else(|| {
+            panic!(
+                "could not insert reflected value of type `{}`: entity {:?} does not exist",
+                type_name, self.entity
+            )
+        });
+
+        reflect_component.insert(&mut entity, &*self.value);
     }
 }
 
 struct ProtoCommandData<T: Prototypical, C: Config<T>> {
     id: T::Id,
     entity: Option<Entity>,
+    /// The parameters made available to schematics via [`SchematicContext::params`].
+    params: Option<ProtoParams>,
     _phantom: PhantomData<C>,
 }
 
 impl<T: Prototypical, C: Config<T>> ProtoCommandData<T, C> {
+    /// Returns the [`HandleId`] of the prototype, if it's registered.
+    fn handle(&self, world: &World) -> Option<HandleId> {
+        world
+            .resource::<ProtoRegistry<T, C>>()
+            .get_tree_by_id(&self.id)
+            .map(|tree| tree.handle())
+    }
+
+    /// Records the prototype in the entity's [`ProtoInstance`], inserting one if needed.
+    fn track_instance(&self, world: &mut World) {
+        let (Some(entity), Some(handle)) = (self.entity, self.handle(world)) else {
+            return;
+        };
+
+        if let Some(mut instance) = world.get_mut::<ProtoInstance>(entity) {
+            instance.track(handle);
+        } else if world.get_entity(entity).is_some() {
+            let instance = ProtoInstance::new(handle, 0, world);
+            world.entity_mut(entity).insert(instance);
+        }
+    }
+
+    /// Removes the prototype from the entity's [`ProtoInstance`],
+    /// removing the component entirely once no prototypes remain.
+    fn untrack_instance(&self, world: &mut World) {
+        let (Some(entity), Some(handle)) = (self.entity, self.handle(world)) else {
+            return;
+        };
+
+        let Some(mut instance) = world.get_mut::<ProtoInstance>(entity) else {
+            return;
+        };
+
+        let is_empty = instance.untrack(handle);
+        if is_empty {
+            world.entity_mut(entity).remove::<ProtoInstance>();
+        }
+    }
+
     /// Asserts that the given prototype is registered, panicking if it isn't.
     fn assert_is_registered(&self, world: &World) {
         let registry = world.resource::<ProtoRegistry<T, C>>();
@@ -254,93 +875,1055 @@ impl<T: Prototypical, C: Config<T>> ProtoCommandData<T, C> {
         }
     }
 
-    fn for_each_entity<F>(&self, world: &mut World, is_apply: bool, callback: F)
-    where
-        F: Fn(&EntityTreeNode, &mut SchematicContext, &Assets<T>, &mut C),
-    {
-        world.resource_scope(|world: &mut World, registry: Mut<ProtoRegistry<T, C>>| {
-            world.resource_scope(|world: &mut World, mut config: Mut<C>| {
-                world.resource_scope(|world, prototypes: Mut<Assets<T>>| {
-                    let entity_tree = registry
-                        .get_tree_by_id(&self.id)
-                        .unwrap()
-                        .to_entity_tree(self.entity, world);
-
-                    for node in entity_tree.iter() {
-                        entity_tree.set_current(node);
-
-                        let mut context = SchematicContext::new(world, &entity_tree);
-
-                        #[cfg(feature = "auto_name")]
-                        if let Some(mut entity) = context.entity_mut() {
-                            if is_apply && !entity.contains::<bevy::core::Name>() {
-                                entity.insert(bevy::core::Name::new(format!(
-                                    "{} (Prototype)",
-                                    node.id()
-                                )));
-                            }
-                        }
+    /// Panics if the given [prototype] is [abstract].
+    ///
+    /// [prototype]: Prototypical
+    /// [abstract]: Prototypical::is_abstract
+    fn assert_is_not_abstract(&self, world: &World) {
+        let registry = world.resource::<ProtoRegistry<T, C>>();
+        let is_abstract = registry
+            .get_tree_by_id(&self.id)
+            .map(|tree| tree.is_abstract())
+            .unwrap_or_default();
 
-                        callback(node, &mut context, &prototypes, &mut config);
-                    }
-                })
-            })
-        });
+        if is_abstract {
+            panic!(
+                "could not apply prototype {:?}: prototype is abstract; use it as a template",
+                &self.id
+            );
+        }
     }
 
-    /// Helper function to loop over the [schematics] for the given [prototype] and entity.
+    /// Returns false if the given [prototype] has no children and only contains
+    /// schematics that don't [require an entity].
     ///
-    /// [schematics]: DynamicSchematic
     /// [prototype]: Prototypical
-    fn for_each_schematic<F>(&self, world: &mut World, is_apply: bool, callback: F)
-    where
+    /// [require an entity]: crate::schematics::Schematic::requires_entity
+    fn needs_entity(&self, world: &World) -> bool {
+        let registry = world.resource::<ProtoRegistry<T, C>>();
+        let prototypes = world.resource::<Assets<T>>();
+        let mixins = world.resource::<ProtoMixins>();
+
+        let Some(tree) = registry.get_tree_by_id(&self.id) else {
+            return true;
+        };
+
+        if !tree.children().is_empty() {
+            return true;
+        }
+
+        let mut schematics = tree
+            .prototypes()
+            .iter()
+            .filter_map(|handle_id| {
+                let proto = prototypes.get(&prototypes.get_handle(*handle_id))?;
+                Some((handle_id, proto))
+            })
+            .flat_map(|(handle_id, proto)| {
+                registry
+                    .iter_mixin_schematics(*handle_id, mixins)
+                    .chain(proto.schematics().iter())
+            })
+            .peekable();
+
+        // Empty prototypes may be spawned intentionally (e.g. as placeholders)
+        if schematics.peek().is_none() {
+            return true;
+        }
+
+        schematics.any(|(_, schematic)| schematic.requires_entity())
+    }
+
+    /// Helper function to loop over the entities for the given [prototype].
+    ///
+    /// The first `skip` nodes are skipped and at most `limit` nodes will be processed.
+    /// Returns the number of nodes processed.
+    ///
+    /// [prototype]: Prototypical
+    fn for_each_entity<F>(
+        &self,
+        world: &mut World,
+        is_apply: bool,
+        skip: usize,
+        limit: usize,
+        callback: F,
+    ) -> usize
+    where
+        F: Fn(
+            &EntityTreeNode,
+            &mut SchematicContext,
+            &Assets<T>,
+            &ProtoRegistry<T, C>,
+            &ProtoMixins,
+            &mut C,
+        ),
+    {
+        world.resource_scope(|world: &mut World, registry: Mut<ProtoRegistry<T, C>>| {
+            world.resource_scope(|world: &mut World, mut config: Mut<C>| {
+                world.resource_scope(|world, prototypes: Mut<Assets<T>>| {
+                    world.resource_scope(|world, mixins: Mut<ProtoMixins>| {
+                        let entity_tree = registry
+                            .get_tree_by_id(&self.id)
+                            .unwrap()
+                            .to_entity_tree(self.entity, world);
+
+                        let mut processed = 0;
+                        let order = config.apply_order();
+                        for node in entity_tree.iter(order).skip(skip).take(limit) {
+                            processed += 1;
+                            entity_tree.set_current(node);
+
+                            let mut context = SchematicContext::new(world, &entity_tree)
+                                .with_params(self.params.as_ref())
+                                .with_weak_lazy_assets(config.weak_lazy_assets());
+
+                            #[cfg(feature = "auto_name")]
+                            if let Some(mut entity) = context.entity_mut() {
+                                if is_apply && !entity.contains::<bevy::core::Name>() {
+                                    entity
+                                        .insert(bevy::core::Name::new(config.auto_name(node.id())));
+                                }
+                            }
+
+                            callback(
+                                node,
+                                &mut context,
+                                &prototypes,
+                                &registry,
+                                &mixins,
+                                &mut config,
+                            );
+                        }
+
+                        processed
+                    })
+                })
+            })
+        })
+    }
+
+    /// Helper function to loop over the [schematics] for the given [prototype] and entity.
+    ///
+    /// [schematics]: DynamicSchematic
+    /// [prototype]: Prototypical
+    fn for_each_schematic<F>(&self, world: &mut World, is_apply: bool, callback: F)
+    where
         F: Fn(&DynamicSchematic, SchematicId, &mut SchematicContext),
     {
-        self.for_each_entity(world, is_apply, |node, context, prototypes, config| {
-            let on_before_prototype = if is_apply {
-                Config::<T>::on_before_apply_prototype
-            } else {
-                Config::<T>::on_before_remove_prototype
-            };
-            let on_after_prototype = if is_apply {
-                Config::<T>::on_after_apply_prototype
-            } else {
-                Config::<T>::on_after_remove_prototype
-            };
-            let on_before_schematic = if is_apply {
-                Config::<T>::on_before_apply_schematic
-            } else {
-                Config::<T>::on_before_remove_schematic
+        self.for_each_schematic_in(world, is_apply, 0, usize::MAX, callback);
+    }
+
+    /// Same as [`for_each_schematic`], but only processes the schematics of at most `limit` entities,
+    /// after skipping the first `skip`.
+    ///
+    /// Returns the number of entities processed.
+    ///
+    /// [`for_each_schematic`]: Self::for_each_schematic
+    fn for_each_schematic_in<F>(
+        &self,
+        world: &mut World,
+        is_apply: bool,
+        skip: usize,
+        limit: usize,
+        callback: F,
+    ) -> usize
+    where
+        F: Fn(&DynamicSchematic, SchematicId, &mut SchematicContext),
+    {
+        self.for_each_entity(
+            world,
+            is_apply,
+            skip,
+            limit,
+            |node, context, prototypes, registry, mixins, config| {
+                let on_before_prototype = if is_apply {
+                    Config::<T>::on_before_apply_prototype
+                } else {
+                    Config::<T>::on_before_remove_prototype
+                };
+                let on_after_prototype = if is_apply {
+                    Config::<T>::on_after_apply_prototype
+                } else {
+                    Config::<T>::on_after_remove_prototype
+                };
+                let on_before_schematic = if is_apply {
+                    Config::<T>::on_before_apply_schematic
+                } else {
+                    Config::<T>::on_before_remove_schematic
+                };
+                let on_after_schematic = if is_apply {
+                    Config::<T>::on_after_apply_schematic
+                } else {
+                    Config::<T>::on_after_remove_schematic
+                };
+
+                for handle_id in node.prototypes() {
+                    let handle = prototypes.get_handle(*handle_id);
+                    let proto = prototypes.get(&handle).unwrap();
+
+                    if proto.requires_entity() && context.entity().is_none() {
+                        panic!(
+                            "could not apply command for prototype {:?}: requires entity",
+                            proto.id()
+                        );
+                    }
+
+                    let _proto_span = info_span!("apply_prototype", id = ?proto.id()).entered();
+
+                    on_before_prototype(config, proto, context);
+
+                    // Mixins are applied before the prototype's own schematics
+                    // so that the latter take precedence
+                    let mixin_schematics = registry.iter_mixin_schematics(*handle_id, mixins);
+
+                    for (_, schematic) in mixin_schematics.chain(proto.schematics().iter()) {
+                        let id = SchematicId::new(*handle_id, schematic.type_info().type_id());
+
+                        if schematic.insert_mode() == InsertMode::KeepExisting
+                            && !claim_kept_schematic(schematic, &id, is_apply, context)
+                        {
+                            continue;
+                        }
+
+                        let _schematic_span = info_span!(
+                            "apply_schematic",
+                            schematic = schematic.type_info().type_name()
+                        )
+                        .entered();
+
+                        on_before_schematic(config, schematic, id.clone(), context);
+
+                        let transformed = if is_apply {
+                            config.transform_schematic(schematic, id.clone(), context)
+                        } else {
+                            None
+                        };
+                        let schematic = transformed.as_ref().unwrap_or(schematic);
+
+                        callback(schematic, id.clone(), context);
+                        on_after_schematic(config, schematic, id.clone(), context);
+                    }
+
+                    on_after_prototype(config, proto, context);
+                }
+            },
+        )
+    }
+}
+
+/// Determines whether an [`InsertMode::KeepExisting`] schematic should be processed
+/// for the current entity, updating its [`ProtoInstance`] accordingly.
+///
+/// When applying, the schematic is skipped if the entity already contains its component,
+/// unless that component was inserted by this same schematic (e.g. when re-inserting).
+/// When removing, the schematic is only processed if it was actually inserted,
+/// so that components which existed beforehand are left untouched.
+///
+/// Schematics without a tracked entity (e.g. resource-only prototypes) are always processed.
+fn claim_kept_schematic(
+    schematic: &DynamicSchematic,
+    id: &SchematicId,
+    is_apply: bool,
+    context: &mut SchematicContext,
+) -> bool {
+    let Some(entity) = context.entity().map(|entity| entity.id()) else {
+        return true;
+    };
+
+    if is_apply {
+        let is_owned = context
+            .world()
+            .get::<ProtoInstance>(entity)
+            .map(|instance| instance.owns(id))
+            .unwrap_or_default();
+
+        if schematic.is_present(context) && !is_owned {
+            return false;
+        }
+
+        if let Some(mut instance) = context.world_mut().get_mut::<ProtoInstance>(entity) {
+            instance.own(id.clone());
+        }
+
+        true
+    } else {
+        context
+            .world_mut()
+            .get_mut::<ProtoInstance>(entity)
+            .map(|mut instance| instance.release(id))
+            .unwrap_or(true)
+    }
+}
+
+/// A [command] to insert a [prototype] on an entity over the course of multiple frames.
+///
+/// Rather than being applied immediately, the prototype is pushed onto a [`ProtoApplyQueue`]
+/// which applies at most [`Config::apply_budget`] entities' worth of schematics per frame.
+///
+/// [command]: Command
+/// [prototype]: Prototypical
+pub struct ProtoBudgetedInsertCommand<T: Prototypical, C: Config<T>> {
+    data: ProtoCommandData<T, C>,
+}
+
+impl<T: Prototypical, C: Config<T>> ProtoBudgetedInsertCommand<T, C> {
+    pub fn new(id: T::Id, entity: Entity) -> Self {
+        Self {
+            data: ProtoCommandData {
+                id,
+                entity: Some(entity),
+                params: None,
+                _phantom: PhantomData,
+            },
+        }
+    }
+}
+
+impl<T: Prototypical, C: Config<T>> Command for ProtoBudgetedInsertCommand<T, C> {
+    fn apply(self, world: &mut World) {
+        self.data.assert_is_registered(world);
+        self.data.assert_is_not_abstract(world);
+
+        self.data.track_instance(world);
+
+        world
+            .resource_mut::<ProtoApplyQueue<T, C>>()
+            .queue
+            .push_back(QueuedInsert {
+                data: self.data,
+                processed: 0,
+            });
+    }
+}
+
+/// A [command] to insert a [prototype] on an entity according to a given order.
+///
+/// Rather than being applied immediately, the prototype is pushed onto a [`ProtoOrderQueue`]
+/// which applies all queued prototypes in order during [`ProtoSet::Apply`].
+///
+/// [command]: Command
+/// [prototype]: Prototypical
+/// [`ProtoSet::Apply`]: crate::ProtoSet::Apply
+pub struct ProtoOrderedInsertCommand<T: Prototypical, C: Config<T>> {
+    insert: ProtoInsertCommand<T, C>,
+    order: i32,
+}
+
+impl<T: Prototypical, C: Config<T>> ProtoOrderedInsertCommand<T, C> {
+    pub fn new(insert: ProtoInsertCommand<T, C>, order: i32) -> Self {
+        Self { insert, order }
+    }
+}
+
+impl<T: Prototypical, C: Config<T>> Command for ProtoOrderedInsertCommand<T, C> {
+    fn apply(self, world: &mut World) {
+        world
+            .resource_mut::<ProtoOrderQueue<T, C>>()
+            .queue
+            .push(QueuedOrderedInsert {
+                order: self.order,
+                insert: self.insert,
+                chained: CommandQueue::default(),
+            });
+    }
+}
+
+/// A [command] chained after a [`ProtoOrderedInsertCommand`] for the same entity.
+///
+/// The command is deferred until the ordered insertion has been applied.
+/// If no ordered insertion is queued for the entity, it's applied immediately.
+///
+/// [command]: Command
+struct ProtoChainedCommand<T: Prototypical, C: Config<T>, Cmd: Command> {
+    entity: Entity,
+    command: Cmd,
+    _phantom: PhantomData<(T, C)>,
+}
+
+impl<T: Prototypical, C: Config<T>, Cmd: Command> ProtoChainedCommand<T, C, Cmd> {
+    fn new(entity: Entity, command: Cmd) -> Self {
+        Self {
+            entity,
+            command,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Prototypical, C: Config<T>, Cmd: Command> Command for ProtoChainedCommand<T, C, Cmd> {
+    fn apply(self, world: &mut World) {
+        let mut queue = world.resource_mut::<ProtoOrderQueue<T, C>>();
+        if let Some(queued) = queue
+            .queue
+            .iter_mut()
+            .rev()
+            .find(|queued| queued.insert.data.entity == Some(self.entity))
+        {
+            queued.chained.push(self.command);
+            return;
+        }
+
+        self.command.apply(world);
+    }
+}
+
+/// Resource containing the prototypes queued to be applied in order.
+///
+/// See [`ProtoEntityCommands::insert_ordered`] for details.
+#[derive(Resource)]
+pub(crate) struct ProtoOrderQueue<T: Prototypical, C: Config<T>> {
+    queue: Vec<QueuedOrderedInsert<T, C>>,
+}
+
+/// A prototype in the [`ProtoOrderQueue`] along with the commands chained after it.
+struct QueuedOrderedInsert<T: Prototypical, C: Config<T>> {
+    order: i32,
+    insert: ProtoInsertCommand<T, C>,
+    /// The commands to run once the prototype has been applied.
+    chained: CommandQueue,
+}
+
+impl<T: Prototypical, C: Config<T>> Default for ProtoOrderQueue<T, C> {
+    fn default() -> Self {
+        Self { queue: Vec::new() }
+    }
+}
+
+/// Applies the prototypes in the [`ProtoOrderQueue`] from lowest to highest order.
+pub(crate) fn apply_ordered_prototypes<T: Prototypical, C: Config<T>>(world: &mut World) {
+    let mut queue = std::mem::take(&mut world.resource_mut::<ProtoOrderQueue<T, C>>().queue);
+
+    // Stable sort so that ties keep the order in which they were queued
+    queue.sort_by_key(|queued| queued.order);
+
+    for mut queued in queue {
+        let is_alive = queued
+            .insert
+            .data
+            .entity
+            .map(|entity| world.get_entity(entity).is_some())
+            .unwrap_or_default();
+
+        if is_alive {
+            queued.insert.apply(world);
+            queued.chained.apply(world);
+        }
+    }
+}
+
+/// Resource containing the prototypes queued to be applied across multiple frames.
+///
+/// See [`ProtoEntityCommands::insert_budgeted`] for details.
+#[derive(Resource)]
+pub(crate) struct ProtoApplyQueue<T: Prototypical, C: Config<T>> {
+    queue: VecDeque<QueuedInsert<T, C>>,
+}
+
+impl<T: Prototypical, C: Config<T>> ProtoApplyQueue<T, C> {
+    /// Returns true if a prototype is still queued to be applied to the given entity.
+    pub fn is_queued(&self, entity: Entity) -> bool {
+        self.queue
+            .iter()
+            .any(|item| item.data.entity == Some(entity))
+    }
+}
+
+impl<T: Prototypical, C: Config<T>> Default for ProtoApplyQueue<T, C> {
+    fn default() -> Self {
+        Self {
+            queue: VecDeque::new(),
+        }
+    }
+}
+
+/// A prototype in the [`ProtoApplyQueue`] along with its progress.
+struct QueuedInsert<T: Prototypical, C: Config<T>> {
+    data: ProtoCommandData<T, C>,
+    /// The number of entity tree nodes that have already been processed.
+    processed: usize,
+}
+
+/// Applies the prototypes in the [`ProtoApplyQueue`], respecting the [`Config::apply_budget`].
+pub(crate) fn apply_budgeted_prototypes<T: Prototypical, C: Config<T>>(world: &mut World) {
+    world.resource_scope(|world: &mut World, mut queue: Mut<ProtoApplyQueue<T, C>>| {
+        let mut budget = world.resource::<C>().apply_budget().max(1);
+
+        while budget > 0 {
+            let Some(mut item) = queue.queue.pop_front() else {
+                break;
             };
-            let on_after_schematic = if is_apply {
-                Config::<T>::on_after_apply_schematic
+
+            // The entity may have been despawned or the prototype unloaded mid-construction
+            let is_valid = item
+                .data
+                .entity
+                .map(|entity| world.get_entity(entity).is_some())
+                .unwrap_or_default()
+                && world
+                    .resource::<ProtoRegistry<T, C>>()
+                    .contains(&item.data.id);
+
+            if !is_valid {
+                continue;
+            }
+
+            #[cfg(feature = "analysis")]
+            if item.processed == 0 {
+                world
+                    .resource_mut::<ProtoRegistry<T, C>>()
+                    .mark_applied(&item.data.id);
+            }
+
+            let skip_failed = world.resource::<C>().skip_failed_schematics();
+            let processed = item.data.for_each_schematic_in(
+                world,
+                true,
+                item.processed,
+                budget,
+                |schematic, id, context| {
+                    if let Err(err) = schematic.apply(id, context) {
+                        on_schematic_error(&item.data.id, schematic, err, skip_failed);
+                    }
+                },
+            );
+
+            item.processed += processed;
+
+            if processed < budget {
+                // Finished processing this prototype
+                budget -= processed;
             } else {
-                Config::<T>::on_after_remove_schematic
-            };
+                // Budget exhausted -> continue next frame
+                queue.queue.push_front(item);
+                break;
+            }
+        }
+    });
+}
 
-            for handle_id in node.prototypes() {
-                let handle = prototypes.get_handle(*handle_id);
-                let proto = prototypes.get(&handle).unwrap();
+/// Handles a schematic that failed to apply, either by logging the error or panicking
+/// depending on [`Config::skip_failed_schematics`].
+fn on_schematic_error<I: std::fmt::Debug>(
+    proto_id: &I,
+    schematic: &DynamicSchematic,
+    error: SchematicError,
+    skip_failed: bool,
+) {
+    let schematic_name = schematic.type_info().type_name();
+    if skip_failed {
+        error!(
+            "could not apply schematic `{}` of prototype {:?}: {} (skipping)",
+            schematic_name, proto_id, error
+        );
+    } else {
+        panic!(
+            "could not apply schematic `{}` of prototype {:?}: {}",
+            schematic_name, proto_id, error
+        );
+    }
+}
 
-                if proto.requires_entity() && context.entity().is_none() {
-                    panic!(
-                        "could not apply command for prototype {:?}: requires entity",
-                        proto.id()
-                    );
-                }
+#[cfg(test)]
+mod tests {
+    use bevy::app::App;
+    use bevy::hierarchy::BuildWorldChildren;
+    use bevy::prelude::Component;
+    use bevy::reflect::{Reflect, TypeUuid};
+    use bevy_proto_derive::Schematic;
 
-                on_before_prototype(config, proto, context);
+    use crate::proto::ProtoWorld;
+    use crate::schematics::TryFromSchematicInput;
+    use crate::test_utils::{self, TestConfig, TestProto};
 
-                for (_, schematic) in proto.schematics().iter() {
-                    let id = SchematicId::new(*handle_id, schematic.type_info().type_id());
+    use super::*;
 
-                    on_before_schematic(config, schematic, id.clone(), context);
-                    callback(schematic, id.clone(), context);
-                    on_after_schematic(config, schematic, id.clone(), context);
-                }
+    fn spawn_instance(world: &mut World, handle: HandleId) -> Entity {
+        let instance = ProtoInstance::new(handle, 0, world);
+        world.spawn(instance).id()
+    }
+
+    /// Returns the prototypes tracked by the entity's [`ProtoInstance`], if it has one.
+    fn tracked(app: &App, entity: Entity) -> Option<Vec<HandleId>> {
+        app.world
+            .get::<ProtoInstance>(entity)
+            .map(|instance| instance.handles().to_vec())
+    }
+
+    #[test]
+    fn should_find_root_instances() {
+        let mut world = World::new();
+        let handle = HandleId::new(TestProto::TYPE_UUID, 0);
+        let other = HandleId::new(TestProto::TYPE_UUID, 1);
+
+        let root_a = spawn_instance(&mut world, handle);
+        let child = spawn_instance(&mut world, handle);
+        world.entity_mut(root_a).push_children(&[child]);
+
+        let root_b = spawn_instance(&mut world, handle);
+        spawn_instance(&mut world, other);
+
+        // Parented under an entity that isn't a prototype instance
+        let parent = world.spawn_empty().id();
+        let root_c = spawn_instance(&mut world, handle);
+        world.entity_mut(parent).push_children(&[root_c]);
+
+        let mut roots = find_root_instances(&mut world, handle);
+        roots.sort();
+        assert_eq!(vec![root_a, root_b, root_c], roots);
+    }
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn should_find_root_instances_in_spawn_order() {
+        let mut world = World::new();
+        let handle = HandleId::new(TestProto::TYPE_UUID, 0);
+
+        let first = spawn_instance(&mut world, handle);
+        let second = spawn_instance(&mut world, handle);
+        let third = spawn_instance(&mut world, handle);
+        let despawned = spawn_instance(&mut world, handle);
+        world.despawn(despawned);
+
+        // Move the first instance to a later archetype so query order differs from spawn order
+        world.entity_mut(first).insert(Marker);
+
+        assert_eq!(
+            vec![first, second, third],
+            find_root_instances(&mut world, handle)
+        );
+    }
+
+    #[test]
+    fn should_count_spawn_indices_per_world() {
+        let handle = HandleId::new(TestProto::TYPE_UUID, 0);
+        let mut world_a = World::new();
+        let mut world_b = World::new();
+
+        assert_eq!(0, ProtoInstance::new(handle, 0, &mut world_a).spawn_index());
+        assert_eq!(1, ProtoInstance::new(handle, 0, &mut world_a).spawn_index());
+        assert_eq!(0, ProtoInstance::new(handle, 0, &mut world_b).spawn_index());
+    }
+
+    #[test]
+    fn should_track_applied_prototypes() {
+        let mut app = test_utils::app();
+        let a = test_utils::add(&mut app, TestProto::new("A"));
+        let b = test_utils::add(&mut app, TestProto::new("B"));
+        let c = test_utils::add(&mut app, TestProto::new("C"));
+        test_utils::register(&mut app);
+
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("A").id());
+        assert_eq!(Some(vec![a.id()]), tracked(&app, entity));
+
+        test_utils::run_commands(&mut app, |commands| {
+            commands.entity(entity).insert("B");
+        });
+        assert_eq!(Some(vec![a.id(), b.id()]), tracked(&app, entity));
+
+        // Swapping replaces the prototype in place
+        test_utils::run_commands(&mut app, |commands| {
+            commands.entity(entity).swap("A", "C");
+        });
+        assert_eq!(Some(vec![c.id(), b.id()]), tracked(&app, entity));
+
+        test_utils::run_commands(&mut app, |commands| {
+            commands.entity(entity).remove("C").remove("B");
+        });
+        assert_eq!(None, tracked(&app, entity));
+    }
+
+    #[test]
+    fn should_not_track_prototype_on_remove() {
+        let mut app = test_utils::app();
+        test_utils::add(&mut app, TestProto::new("A"));
+        test_utils::register(&mut app);
+
+        let entity = app.world.spawn_empty().id();
+        test_utils::run_commands(&mut app, |commands| {
+            commands.entity(entity).remove("A");
+        });
+        assert_eq!(None, tracked(&app, entity));
+    }
+
+    #[test]
+    fn should_only_despawn_current_instances() {
+        let mut app = test_utils::app();
+        test_utils::add(&mut app, TestProto::new("A"));
+        test_utils::add(&mut app, TestProto::new("B"));
+        test_utils::register(&mut app);
+
+        let (swapped, kept) = test_utils::run_commands(&mut app, |commands| {
+            let swapped = commands.spawn("A").swap("A", "B").id();
+            let kept = commands.spawn("A").id();
+            (swapped, kept)
+        });
+
+        test_utils::run_commands(&mut app, |commands| commands.despawn_all("A"));
+        assert!(app.world.get_entity(swapped).is_some());
+        assert!(app.world.get_entity(kept).is_none());
+
+        test_utils::run_commands(&mut app, |commands| commands.despawn_all("B"));
+        assert!(app.world.get_entity(swapped).is_none());
+    }
+
+    #[test]
+    fn should_find_instances_of_each_inserted_prototype() {
+        let mut app = test_utils::app();
+        test_utils::add(&mut app, TestProto::new("A"));
+        test_utils::add(&mut app, TestProto::new("B"));
+        test_utils::register(&mut app);
+
+        let (single, both) = test_utils::run_commands(&mut app, |commands| {
+            let single = commands.spawn("A").id();
+            let both = commands.spawn("B").insert("A").id();
+            (single, both)
+        });
+
+        let mut world = ProtoWorld::<TestProto, TestConfig>::new(&mut app.world);
+        assert_eq!(vec![single, both], world.instances_of("A"));
+        assert_eq!(vec![both], world.instances_of("B"));
+
+        world.remove_from(both, "A");
+        assert_eq!(vec![single], world.instances_of("A"));
+    }
+
+    #[test]
+    fn should_order_instances_by_spawn_and_exclude_despawned() {
+        let mut app = test_utils::app();
+        test_utils::add(&mut app, TestProto::new("A"));
+        test_utils::register(&mut app);
+
+        let first = test_utils::run_commands(&mut app, |commands| commands.spawn("A").id());
+        let second = test_utils::run_commands(&mut app, |commands| commands.spawn("A").id());
+        let third = test_utils::run_commands(&mut app, |commands| commands.spawn("A").id());
+
+        let mut world = ProtoWorld::<TestProto, TestConfig>::new(&mut app.world);
+        assert_eq!(vec![first, second, third], world.instances_of("A"));
+
+        app.world.despawn(second);
+
+        let mut world = ProtoWorld::<TestProto, TestConfig>::new(&mut app.world);
+        assert_eq!(vec![first, third], world.instances_of("A"));
+        assert_eq!(Some(third), world.entity_from_instance("A", 1));
+        assert_eq!(None, world.entity_from_instance("A", 2));
+    }
+
+    #[derive(Component, Reflect, Schematic, Default, Debug, PartialEq)]
+    struct Movement {
+        speed: f32,
+        jump_height: f32,
+    }
+
+    #[derive(Component, Reflect, Schematic)]
+    struct Player {
+        name: String,
+        #[schematic(flatten(speed: f32))]
+        movement: Movement,
+    }
+
+    #[test]
+    fn should_spawn_flattened_schematic() {
+        let mut app = test_utils::app();
+        test_utils::add(
+            &mut app,
+            TestProto::new("Player").with_schematic::<Player>(PlayerInput {
+                name: String::from("Bob"),
+                speed: 1.5,
+            }),
+        );
+        test_utils::register(&mut app);
+
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("Player").id());
+
+        let player = app.world.get::<Player>(entity).unwrap();
+        assert_eq!("Bob", player.name);
+        // Unlisted fields fall back to their defaults
+        assert_eq!(
+            &Movement {
+                speed: 1.5,
+                jump_height: 0.0,
+            },
+            &player.movement
+        );
+    }
+
+    #[derive(Component, Reflect, Schematic, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    /// Creates a prototype that inserts `Health` with [`InsertMode::KeepExisting`].
+    fn keep_existing(id: &str, health: u32) -> TestProto {
+        let mut prototype = TestProto::new(id).with_schematic::<Health>(Health(health));
+        prototype
+            .schematics
+            .get_mut::<Health>()
+            .unwrap()
+            .set_insert_mode(InsertMode::KeepExisting);
+        prototype
+    }
+
+    #[test]
+    fn should_not_remove_existing_components() {
+        let mut app = test_utils::app();
+        app.register_type::<Health>();
+        test_utils::add(&mut app, keep_existing("A", 10));
+        test_utils::register(&mut app);
+
+        let entity = app.world.spawn(Health(1)).id();
+
+        test_utils::run_commands(&mut app, |commands| {
+            commands.entity(entity).insert("A");
+        });
+        assert_eq!(Some(&Health(1)), app.world.get::<Health>(entity));
+
+        test_utils::run_commands(&mut app, |commands| {
+            commands.entity(entity).remove("A");
+        });
+        assert_eq!(Some(&Health(1)), app.world.get::<Health>(entity));
+    }
 
-                on_after_prototype(config, proto, context);
+    #[test]
+    fn should_remove_kept_components_inserted_by_prototype() {
+        let mut app = test_utils::app();
+        app.register_type::<Health>();
+        test_utils::add(&mut app, keep_existing("A", 10));
+        test_utils::register(&mut app);
+
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("A").id());
+        assert_eq!(Some(&Health(10)), app.world.get::<Health>(entity));
+
+        // Re-inserting should not mistake the prototype's own component for an existing one
+        test_utils::run_commands(&mut app, |commands| {
+            commands.entity(entity).insert("A");
+        });
+        test_utils::run_commands(&mut app, |commands| {
+            commands.entity(entity).remove("A");
+        });
+        assert_eq!(None, app.world.get::<Health>(entity));
+    }
+
+    #[derive(Resource, Reflect, Schematic)]
+    #[schematic(kind = "resource")]
+    struct Score(u32);
+
+    /// Records whether the `Health` inserted by a chained command was present
+    /// when the chained callback ran.
+    #[derive(Resource)]
+    struct ChainedHealth(bool);
+
+    #[test]
+    fn should_run_chained_commands_before_despawning_needless_entity() {
+        let mut app = test_utils::app();
+        app.register_type::<Health>().register_type::<Score>();
+        test_utils::add(
+            &mut app,
+            TestProto::new("Scoreboard").with_schematic::<Score>(Score(0)),
+        );
+        test_utils::register(&mut app);
+
+        let entity = test_utils::run_commands(&mut app, |commands| {
+            let mut entity = commands.spawn("Scoreboard");
+            entity
+                .insert_reflected(Box::new(Health(1)))
+                .on_applied(|world, entity| {
+                    let has_health = world.get::<Health>(entity).is_some();
+                    world.insert_resource(ChainedHealth(has_health));
+                });
+            entity.id()
+        });
+        assert!(app.world.resource::<ChainedHealth>().0);
+        assert!(app.world.contains_resource::<Score>());
+
+        app.update();
+        assert!(app.world.get_entity(entity).is_none());
+    }
+
+    /// Records the `Health` of the entity when the chained callback ran.
+    #[derive(Resource)]
+    struct AppliedHealth(Option<u32>);
+
+    #[test]
+    fn should_run_chained_commands_after_ordered_insert() {
+        let mut app = test_utils::app();
+        app.register_type::<Health>();
+        test_utils::add(
+            &mut app,
+            TestProto::new("A").with_schematic::<Health>(Health(10)),
+        );
+        test_utils::register(&mut app);
+
+        let entity = test_utils::run_commands(&mut app, |commands| {
+            let mut entity = commands.spawn_ordered("A", 0);
+            entity
+                .insert_reflected(Box::new(Health(1)))
+                .on_applied(|world, entity| {
+                    let health = world.get::<Health>(entity).map(|health| health.0);
+                    world.insert_resource(AppliedHealth(health));
+                });
+            entity.id()
+        });
+
+        // Chained commands wait for the ordered insertion
+        assert_eq!(None, app.world.get::<Health>(entity));
+        assert!(!app.world.contains_resource::<AppliedHealth>());
+
+        app.update();
+        assert_eq!(Some(&Health(1)), app.world.get::<Health>(entity));
+        assert_eq!(Some(1), app.world.resource::<AppliedHealth>().0);
+    }
+
+    /// A percentage that rejects values over 100.
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Percent(u32);
+
+    impl TryFromSchematicInput<u32> for Percent {
+        fn try_from_input(
+            input: u32,
+            _id: SchematicId,
+            _context: &mut SchematicContext,
+        ) -> Result<Self, SchematicError> {
+            if input > 100 {
+                return Err(SchematicError::InvalidInput {
+                    schematic: "Volume",
+                    reason: format!("{input} is over 100%"),
+                });
             }
+
+            Ok(Self(input))
+        }
+    }
+
+    #[derive(Component, Reflect, Schematic, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Volume {
+        #[schematic(try_from = u32)]
+        level: Percent,
+    }
+
+    /// Spawns a prototype with an invalid `Volume` followed by a valid `Health`.
+    fn spawn_invalid_volume(config: TestConfig) -> (App, Entity) {
+        let mut app = test_utils::app_with_config(config);
+        app.register_type::<Volume>().register_type::<Health>();
+        test_utils::add(
+            &mut app,
+            TestProto::new("Speaker")
+                .with_schematic::<Volume>(VolumeInput { level: 150 })
+                .with_schematic::<Health>(Health(10)),
+        );
+        test_utils::register(&mut app);
+
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("Speaker").id());
+        (app, entity)
+    }
+
+    #[test]
+    fn should_apply_fallible_schematic() {
+        let mut app = test_utils::app();
+        app.register_type::<Volume>();
+        test_utils::add(
+            &mut app,
+            TestProto::new("Speaker").with_schematic::<Volume>(VolumeInput { level: 50 }),
+        );
+        test_utils::register(&mut app);
+
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("Speaker").id());
+        assert_eq!(
+            Some(&Volume { level: Percent(50) }),
+            app.world.get::<Volume>(entity)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "could not apply schematic")]
+    fn should_panic_on_failed_schematic() {
+        spawn_invalid_volume(TestConfig::default());
+    }
+
+    #[test]
+    fn should_skip_failed_schematics() {
+        let (app, entity) = spawn_invalid_volume(TestConfig {
+            skip_failed_schematics: true,
+            ..Default::default()
+        });
+
+        assert_eq!(None, app.world.get::<Volume>(entity));
+        assert_eq!(Some(&Health(10)), app.world.get::<Health>(entity));
+    }
+
+    /// Registers an abstract `Base` prototype and a `Derived` prototype inheriting from it.
+    fn abstract_app() -> App {
+        let mut app = test_utils::app();
+        app.register_type::<Health>();
+        let base = test_utils::add(
+            &mut app,
+            TestProto {
+                is_abstract: true,
+                ..TestProto::new("Base").with_schematic::<Health>(Health(10))
+            },
+        );
+        test_utils::add(
+            &mut app,
+            TestProto::new("Derived").with_template("Base", &base),
+        );
+        test_utils::register(&mut app);
+        app
+    }
+
+    #[test]
+    #[should_panic(expected = "prototype is abstract; use it as a template")]
+    fn should_not_spawn_abstract_prototype() {
+        let mut app = abstract_app();
+        test_utils::run_commands(&mut app, |commands| {
+            commands.spawn("Base");
+        });
+    }
+
+    #[test]
+    fn should_inherit_from_abstract_prototype() {
+        let mut app = abstract_app();
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("Derived").id());
+        assert_eq!(Some(&Health(10)), app.world.get::<Health>(entity));
+    }
+
+    #[test]
+    fn should_apply_abstract_prototype_as_template() {
+        let mut app = abstract_app();
+        let entity = test_utils::run_commands(&mut app, |commands| {
+            let mut entity_commands = commands.spawn_empty();
+            entity_commands.apply_template("Base");
+            entity_commands.id()
         });
+        assert_eq!(Some(&Health(10)), app.world.get::<Health>(entity));
+    }
+
+    #[test]
+    fn should_apply_abstract_template_without_tracking_instance() {
+        let mut app = abstract_app();
+        let entity = app.world.spawn_empty().id();
+        let entity_count = app.world.entities().len();
+
+        test_utils::run_commands(&mut app, |commands| {
+            commands.entity(entity).apply_template("Base");
+        });
+
+        assert_eq!(Some(&Health(10)), app.world.get::<Health>(entity));
+        assert_eq!(entity_count, app.world.entities().len());
+        assert_eq!(None, tracked(&app, entity));
+    }
+
+    #[test]
+    fn should_not_track_template_on_existing_instance() {
+        let mut app = abstract_app();
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("Derived").id());
+        let before = tracked(&app, entity);
+
+        test_utils::run_commands(&mut app, |commands| {
+            commands.entity(entity).apply_template("Base");
+        });
+
+        assert!(before.is_some());
+        assert_eq!(before, tracked(&app, entity));
     }
 }
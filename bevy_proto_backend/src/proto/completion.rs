@@ -0,0 +1,330 @@
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+
+use bevy::ecs::system::Command;
+use bevy::prelude::{Entity, Resource, World};
+use parking_lot::Mutex;
+
+use crate::proto::{Config, ProtoApplyQueue, Prototypical};
+
+/// A [future] that resolves once a [prototype] has been fully applied to an entity.
+///
+/// This is returned by [`ProtoEntityCommands::completion`].
+///
+/// A prototype is considered fully applied once:
+/// 1. All of its schematics have been applied to the entity and its descendants
+///    (including [budgeted] insertions, which may span multiple frames)
+/// 2. All scenes spawned within its hierarchy have finished spawning
+///
+/// The future outputs `Some(entity)` on completion.
+/// If the entity is despawned before completion, it outputs `None` instead.
+///
+/// For non-async code, [`ProtoCompletion::is_complete`] can be polled instead.
+///
+/// Dropping this value cancels tracking so that no state is leaked.
+///
+/// [future]: Future
+/// [prototype]: Prototypical
+/// [`ProtoEntityCommands::completion`]: crate::proto::ProtoEntityCommands::completion
+/// [budgeted]: crate::proto::ProtoEntityCommands::insert_budgeted
+#[derive(Debug)]
+pub struct ProtoCompletion {
+    state: Arc<Mutex<CompletionState>>,
+}
+
+impl ProtoCompletion {
+    pub(crate) fn new() -> (Self, CompletionSender) {
+        let state = Arc::new(Mutex::new(CompletionState::Pending(None)));
+        (
+            Self {
+                state: state.clone(),
+            },
+            CompletionSender { state },
+        )
+    }
+
+    /// Returns true if the prototype has been fully applied.
+    pub fn is_complete(&self) -> bool {
+        matches!(*self.state.lock(), CompletionState::Completed(_))
+    }
+
+    /// Returns true if the entity was despawned before the prototype was fully applied.
+    pub fn is_cancelled(&self) -> bool {
+        matches!(*self.state.lock(), CompletionState::Cancelled)
+    }
+}
+
+impl Future for ProtoCompletion {
+    type Output = Option<Entity>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock();
+        match &mut *state {
+            CompletionState::Pending(waker) => {
+                *waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+            CompletionState::Completed(entity) => Poll::Ready(Some(*entity)),
+            CompletionState::Cancelled => Poll::Ready(None),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum CompletionState {
+    Pending(Option<Waker>),
+    Completed(Entity),
+    Cancelled,
+}
+
+/// The sending half of a [`ProtoCompletion`].
+pub(crate) struct CompletionSender {
+    state: Arc<Mutex<CompletionState>>,
+}
+
+impl CompletionSender {
+    /// Returns true if the corresponding [`ProtoCompletion`] has been dropped.
+    fn is_orphaned(&self) -> bool {
+        Arc::strong_count(&self.state) == 1
+    }
+
+    fn resolve(self, entity: Option<Entity>) {
+        let mut state = self.state.lock();
+        let next = match entity {
+            Some(entity) => CompletionState::Completed(entity),
+            None => CompletionState::Cancelled,
+        };
+
+        if let CompletionState::Pending(Some(waker)) = std::mem::replace(&mut *state, next) {
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for CompletionSender {
+    fn drop(&mut self) {
+        // Ensure the receiver is never left waiting on a sender that no longer exists
+        let mut state = self.state.lock();
+        if let CompletionState::Pending(waker) = &mut *state {
+            let waker = waker.take();
+            *state = CompletionState::Cancelled;
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// Resource containing the [`ProtoCompletion`]s waiting to be resolved.
+#[derive(Resource)]
+pub(crate) struct ProtoCompletions<T: Prototypical, C: Config<T>> {
+    pending: Vec<(Entity, CompletionSender)>,
+    _phantom: PhantomData<(T, C)>,
+}
+
+impl<T: Prototypical, C: Config<T>> Default for ProtoCompletions<T, C> {
+    fn default() -> Self {
+        Self {
+            pending: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// A [command] to start tracking the completion of a prototype on an entity.
+///
+/// This must be queued after the command that inserts the prototype.
+///
+/// [command]: Command
+pub(crate) struct ProtoCompletionCommand<T: Prototypical, C: Config<T>> {
+    entity: Entity,
+    sender: CompletionSender,
+    _phantom: PhantomData<(T, C)>,
+}
+
+impl<T: Prototypical, C: Config<T>> ProtoCompletionCommand<T, C> {
+    pub fn new(entity: Entity, sender: CompletionSender) -> Self {
+        Self {
+            entity,
+            sender,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Prototypical, C: Config<T>> Command for ProtoCompletionCommand<T, C> {
+    fn apply(self, world: &mut World) {
+        world
+            .resource_mut::<ProtoCompletions<T, C>>()
+            .pending
+            .push((self.entity, self.sender));
+    }
+}
+
+/// Resolves any [`ProtoCompletion`]s whose prototypes have been fully applied.
+pub(crate) fn resolve_proto_completions<T: Prototypical, C: Config<T>>(world: &mut World) {
+    let pending = std::mem::take(&mut world.resource_mut::<ProtoCompletions<T, C>>().pending);
+
+    let mut still_pending = Vec::with_capacity(pending.len());
+    for (entity, sender) in pending {
+        if sender.is_orphaned() {
+            continue;
+        }
+
+        if world.get_entity(entity).is_none() {
+            sender.resolve(None);
+            continue;
+        }
+
+        let is_queued = world.resource::<ProtoApplyQueue<T, C>>().is_queued(entity);
+
+        if is_queued || !is_hierarchy_spawned(world, entity) {
+            still_pending.push((entity, sender));
+        } else {
+            sender.resolve(Some(entity));
+        }
+    }
+
+    world
+        .resource_mut::<ProtoCompletions<T, C>>()
+        .pending
+        .append(&mut still_pending);
+}
+
+/// Returns true if all scenes within the hierarchy of the given entity have finished spawning.
+#[cfg(feature = "bevy_scene")]
+fn is_hierarchy_spawned(world: &World, entity: Entity) -> bool {
+    use bevy::prelude::{Children, Handle, Scene};
+    use bevy::scene::{SceneInstance, SceneSpawner};
+
+    let Some(entity_ref) = world.get_entity(entity) else {
+        return true;
+    };
+
+    let is_spawned = match entity_ref.get::<SceneInstance>() {
+        Some(instance) => world
+            .get_resource::<SceneSpawner>()
+            .map(|spawner| spawner.instance_is_ready(**instance))
+            .unwrap_or(true),
+        // The scene has not been picked up by the `SceneSpawner` yet
+        None => !entity_ref.contains::<Handle<Scene>>(),
+    };
+
+    is_spawned
+        && entity_ref
+            .get::<Children>()
+            .map(|children| {
+                children
+                    .iter()
+                    .all(|child| is_hierarchy_spawned(world, *child))
+            })
+            .unwrap_or(true)
+}
+
+/// Returns true if all scenes within the hierarchy of the given entity have finished spawning.
+#[cfg(not(feature = "bevy_scene"))]
+fn is_hierarchy_spawned(_world: &World, _entity: Entity) -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::tasks::block_on;
+
+    use crate::test_utils::{self, TestConfig, TestProto};
+
+    use super::*;
+
+    #[test]
+    fn should_resolve_once_applied() {
+        let mut app = test_utils::app();
+        test_utils::add(&mut app, TestProto::new("A"));
+        test_utils::register(&mut app);
+
+        let (entity, completion) = test_utils::run_commands(&mut app, |commands| {
+            let mut entity = commands.spawn("A");
+            (entity.id(), entity.completion())
+        });
+        assert!(!completion.is_complete());
+
+        app.update();
+        assert!(completion.is_complete());
+        assert_eq!(Some(entity), block_on(completion));
+    }
+
+    #[test]
+    fn should_wait_for_budgeted_application() {
+        let mut app = test_utils::app_with_config(TestConfig {
+            apply_budget: Some(1),
+            ..Default::default()
+        });
+        let child_a = test_utils::add(&mut app, TestProto::new("ChildA"));
+        let child_b = test_utils::add(&mut app, TestProto::new("ChildB"));
+        test_utils::add(
+            &mut app,
+            TestProto::new("Parent")
+                .with_child(&child_a)
+                .with_child(&child_b),
+        );
+        test_utils::register(&mut app);
+
+        let completion = test_utils::run_commands(&mut app, |commands| {
+            commands.spawn_budgeted("Parent").completion()
+        });
+
+        app.update();
+        assert!(!completion.is_complete());
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!(completion.is_complete());
+    }
+
+    #[test]
+    fn should_cancel_on_despawn() {
+        let mut app = test_utils::app();
+        test_utils::add(&mut app, TestProto::new("A"));
+        test_utils::register(&mut app);
+
+        let completion = test_utils::run_commands(&mut app, |commands| {
+            let mut entity = commands.spawn("A");
+            let completion = entity.completion();
+            let entity = entity.id();
+            commands.commands().entity(entity).despawn();
+            completion
+        });
+
+        app.update();
+        assert!(completion.is_cancelled());
+        assert_eq!(None, block_on(completion));
+    }
+
+    #[test]
+    fn should_discard_dropped_completions() {
+        let mut app = test_utils::app();
+        test_utils::add(&mut app, TestProto::new("A"));
+        test_utils::register(&mut app);
+
+        test_utils::run_commands(&mut app, |commands| {
+            drop(commands.spawn("A").completion());
+        });
+        assert_eq!(
+            1,
+            app.world
+                .resource::<ProtoCompletions<TestProto, TestConfig>>()
+                .pending
+                .len()
+        );
+
+        app.update();
+        assert!(app
+            .world
+            .resource::<ProtoCompletions<TestProto, TestConfig>>()
+            .pending
+            .is_empty());
+    }
+}
@@ -1,22 +1,184 @@
+use std::hash::{Hash, Hasher};
+
 use bevy::asset::HandleId;
-use bevy::prelude::Component;
+use bevy::prelude::{Component, Resource, World};
+use bevy::utils::HashSet;
+
+use crate::schematics::SchematicId;
 
 /// A component used to track existing entities spawned via a [prototype].
 ///
 /// [prototype]: crate::proto::Prototypical
-#[derive(Component, Clone, Hash, PartialEq, Eq)]
+#[derive(Component)]
 pub struct ProtoInstance {
-    /// Used to identify the prototype.
-    handle: HandleId,
+    /// Used to identify the prototypes currently applied to the entity,
+    /// in the order they were applied.
+    ///
+    /// This is never empty: the component is removed along with the last prototype.
+    handles: Vec<HandleId>,
     /// Used to indicate the child index within the parent.
     child_index: usize,
+    /// Used to order instances by when they were spawned.
+    spawn_index: u64,
+    /// Used to identify the [`InsertMode::KeepExisting`] schematics that were actually
+    /// inserted by the applied prototypes, rather than skipped due to an existing component.
+    ///
+    /// Only these schematics are removed along with their prototype,
+    /// leaving any pre-existing components untouched.
+    ///
+    /// [`InsertMode::KeepExisting`]: crate::schematics::InsertMode::KeepExisting
+    owned: HashSet<SchematicId>,
 }
 
 impl ProtoInstance {
-    pub(crate) fn new(handle: HandleId, child_index: usize) -> Self {
+    pub(crate) fn new(handle: HandleId, child_index: usize, world: &mut World) -> Self {
         Self {
-            handle,
+            handles: vec![handle],
             child_index,
+            spawn_index: ProtoSpawnCounter::next(world),
+            owned: HashSet::new(),
+        }
+    }
+
+    /// The [`HandleId`] of the prototype that produced this entity.
+    ///
+    /// For child entities, this is the child's own prototype.
+    /// For entities with multiple prototypes applied, this is the earliest one
+    /// that is still applied (see [`handles`]).
+    ///
+    /// [`handles`]: Self::handles
+    pub fn handle(&self) -> HandleId {
+        self.handles[0]
+    }
+
+    /// The [`HandleId`]s of all prototypes currently applied to this entity,
+    /// in the order they were applied.
+    pub fn handles(&self) -> &[HandleId] {
+        &self.handles
+    }
+
+    /// Returns true if the prototype with the given handle is currently applied to this entity.
+    pub fn contains(&self, handle: HandleId) -> bool {
+        self.handles.contains(&handle)
+    }
+
+    /// The index of this entity among its prototype siblings.
+    ///
+    /// This is always `0` for root entities.
+    pub fn child_index(&self) -> usize {
+        self.child_index
+    }
+
+    /// A number that increases with each spawned instance in the world.
+    ///
+    /// This can be used to order instances by when they were spawned.
+    /// It is not an index into any collection and is not contiguous.
+    pub fn spawn_index(&self) -> u64 {
+        self.spawn_index
+    }
+
+    /// Records that the prototype with the given handle has been applied to this entity.
+    pub(crate) fn track(&mut self, handle: HandleId) {
+        if !self.contains(handle) {
+            self.handles.push(handle);
+        }
+    }
+
+    /// Records that the prototype with the given handle has been removed from this entity.
+    ///
+    /// Returns true if no prototypes remain, in which case the component should be removed.
+    pub(crate) fn untrack(&mut self, handle: HandleId) -> bool {
+        self.handles.retain(|tracked| *tracked != handle);
+        self.handles.is_empty()
+    }
+
+    /// Returns true if the [`InsertMode::KeepExisting`] schematic with the given ID
+    /// was inserted by one of the prototypes applied to this entity.
+    ///
+    /// [`InsertMode::KeepExisting`]: crate::schematics::InsertMode::KeepExisting
+    pub(crate) fn owns(&self, id: &SchematicId) -> bool {
+        self.owned.contains(id)
+    }
+
+    /// Records that the [`InsertMode::KeepExisting`] schematic with the given ID
+    /// was inserted by one of the prototypes applied to this entity.
+    ///
+    /// [`InsertMode::KeepExisting`]: crate::schematics::InsertMode::KeepExisting
+    pub(crate) fn own(&mut self, id: SchematicId) {
+        self.owned.insert(id);
+    }
+
+    /// Forgets the [`InsertMode::KeepExisting`] schematic with the given ID.
+    ///
+    /// Returns true if it was inserted by one of the prototypes applied to this entity,
+    /// in which case it should be removed.
+    ///
+    /// [`InsertMode::KeepExisting`]: crate::schematics::InsertMode::KeepExisting
+    pub(crate) fn release(&mut self, id: &SchematicId) -> bool {
+        self.owned.remove(id)
+    }
+
+    /// Replaces the prototype with the `from` handle with the one with the `to` handle,
+    /// keeping its position.
+    ///
+    /// If `from` is not applied to this entity, `to` is simply [tracked].
+    ///
+    /// [tracked]: Self::track
+    pub(crate) fn replace(&mut self, from: HandleId, to: HandleId) {
+        if self.contains(to) {
+            self.untrack(from);
+            return;
+        }
+
+        match self.handles.iter_mut().find(|tracked| **tracked == from) {
+            Some(tracked) => *tracked = to,
+            None => self.handles.push(to),
+        }
+    }
+}
+
+impl Clone for ProtoInstance {
+    fn clone(&self) -> Self {
+        Self {
+            handles: self.handles.clone(),
+            child_index: self.child_index,
+            spawn_index: self.spawn_index,
+            owned: self.owned.iter().map(SchematicId::clone).collect(),
         }
     }
 }
+
+// The spawn index and owned schematics are intentionally ignored so that instances can be matched
+// against existing entities, regardless of when either was spawned.
+impl PartialEq for ProtoInstance {
+    fn eq(&self, other: &Self) -> bool {
+        self.handles == other.handles && self.child_index == other.child_index
+    }
+}
+
+impl Eq for ProtoInstance {}
+
+impl Hash for ProtoInstance {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.handles.hash(state);
+        self.child_index.hash(state);
+    }
+}
+
+/// Resource used to assign each [`ProtoInstance`] in a world its [spawn index].
+///
+/// This is inserted on demand, so that each world keeps its own count.
+///
+/// [spawn index]: ProtoInstance::spawn_index
+#[derive(Resource, Default)]
+pub(crate) struct ProtoSpawnCounter(u64);
+
+impl ProtoSpawnCounter {
+    /// Returns the next spawn index for the given world.
+    pub fn next(world: &mut World) -> u64 {
+        let mut counter = world.get_resource_or_insert_with(Self::default);
+        let index = counter.0;
+        counter.0 += 1;
+        index
+    }
+}
@@ -1,9 +1,14 @@
 use bevy::asset::Handle;
-use bevy::prelude::{FromWorld, Resource};
+use bevy::prelude::{FromWorld, Resource, World};
+use bevy::utils::{HashMap, HashSet};
 
 use crate::cycles::{Cycle, CycleResponse};
 use crate::proto::Prototypical;
 use crate::schematics::{DynamicSchematic, SchematicContext, SchematicId};
+use crate::tree::EntityTreeOrder;
+
+/// The default value for [`Config::apply_budget`].
+pub const DEFAULT_APPLY_BUDGET: usize = 64;
 
 /// Configuration for a [prototype].
 ///
@@ -41,6 +46,26 @@ pub trait Config<T: Prototypical>: Resource + FromWorld {
     /// [prototype]: Prototypical
     fn on_unregister_prototype(&mut self, id: &T::Id, handle: Handle<T>) {}
 
+    /// Callback method that's triggered when a [prototype] has been registered or reloaded,
+    /// with access to the [`World`].
+    ///
+    /// This is the data-side counterpart to the spawn-time callbacks:
+    /// it fires once each time a prototype is loaded or reloaded (and not each time it is spawned),
+    /// making it a good place to index prototypes (e.g. building an item database)
+    /// or to validate them against external data.
+    ///
+    /// Unlike [`on_register_prototype`] and [`on_reload_prototype`],
+    /// this is called from an exclusive system that runs after registration,
+    /// in the same [`ProtoSet::Register`] set.
+    /// While it runs, both this config resource and the `Assets<T>` resource are
+    /// temporarily removed from the world.
+    ///
+    /// [prototype]: Prototypical
+    /// [`on_register_prototype`]: Config::on_register_prototype
+    /// [`on_reload_prototype`]: Config::on_reload_prototype
+    /// [`ProtoSet::Register`]: crate::ProtoSet::Register
+    fn on_prototype_ready(&mut self, prototype: &T, world: &mut World) {}
+
     /// Callback method that's triggered _before_ a [prototype] is applied to an entity.
     ///
     /// This is only called when using [`ProtoCommands`].
@@ -107,6 +132,34 @@ pub trait Config<T: Prototypical>: Resource + FromWorld {
     ) {
     }
 
+    /// Callback method that allows a [schematic] to be transformed right before it's applied to an entity.
+    ///
+    /// Unlike [`on_before_apply_schematic`], this can be used to modify the schematic's value.
+    /// If this returns `Some`, the returned schematic will be applied in place of the original
+    /// (and passed to [`on_after_apply_schematic`]).
+    /// A modified schematic can be created with [`DynamicSchematic::try_clone`].
+    ///
+    /// Note that this only affects the instance being applied.
+    /// The schematic stored in the prototype is left unchanged.
+    ///
+    /// This is only called when using [`ProtoCommands`].
+    /// Applying a prototype manually won't automatically trigger this callback.
+    ///
+    /// By default, this returns `None`.
+    ///
+    /// [schematic]: crate::schematics::Schematic
+    /// [`on_before_apply_schematic`]: Config::on_before_apply_schematic
+    /// [`on_after_apply_schematic`]: Config::on_after_apply_schematic
+    /// [`ProtoCommands`]: crate::proto::ProtoCommands
+    fn transform_schematic(
+        &mut self,
+        schematic: &DynamicSchematic,
+        id: SchematicId,
+        context: &mut SchematicContext,
+    ) -> Option<DynamicSchematic> {
+        None
+    }
+
     /// Callback method that's triggered _before_ a [schematic] is removed from an entity.
     ///
     /// This is only called when using [`ProtoCommands`].
@@ -137,6 +190,179 @@ pub trait Config<T: Prototypical>: Resource + FromWorld {
     ) {
     }
 
+    /// Controls the order in which the entities of a [prototype] are processed
+    /// when it is applied or removed.
+    ///
+    /// By default, this is [`EntityTreeOrder::BreadthFirst`], meaning parents are always
+    /// processed before their children.
+    ///
+    /// [prototype]: Prototypical
+    fn apply_order(&self) -> EntityTreeOrder {
+        EntityTreeOrder::BreadthFirst
+    }
+
+    /// Controls the [`Name`] automatically given to entities spawned by a [prototype].
+    ///
+    /// The given `id` is the stringified ID of the prototype.
+    ///
+    /// By default, this returns `"{id} (Prototype)"`.
+    ///
+    /// [`Name`]: bevy::core::Name
+    /// [prototype]: Prototypical
+    #[cfg(feature = "auto_name")]
+    fn auto_name(&self, id: &str) -> String {
+        format!("{} (Prototype)", id)
+    }
+
+    /// The maximum number of entities whose schematics are applied per frame
+    /// for prototypes inserted with [`ProtoEntityCommands::insert_budgeted`].
+    ///
+    /// By default, this is `64`.
+    ///
+    /// [`ProtoEntityCommands::insert_budgeted`]: crate::proto::ProtoEntityCommands::insert_budgeted
+    fn apply_budget(&self) -> usize {
+        DEFAULT_APPLY_BUDGET
+    }
+
+    /// Controls whether a [prototype]'s lazy assets must be loaded for it to be considered ready.
+    ///
+    /// Lazy assets are those that are not preloaded, such as an asset path
+    /// within a schematic that isn't marked with `#[schematic(asset(preload))]`.
+    /// Normally, these aren't loaded until the schematic is first applied,
+    /// which can cause a hitch on the first spawn.
+    ///
+    /// When enabled, all lazy assets are loaded as soon as the prototype is registered
+    /// and [`Prototypes::is_ready`] will only return `true` once they have all finished loading.
+    /// This gives an accurate "fully loaded" signal (e.g. for loading screens)
+    /// at the cost of loading more assets upfront, including ones that may never be used.
+    ///
+    /// By default, this is `false`.
+    ///
+    /// [prototype]: Prototypical
+    /// [`Prototypes::is_ready`]: crate::proto::Prototypes::is_ready
+    fn strict_readiness(&self) -> bool {
+        false
+    }
+
+    /// Controls whether a [prototype] may reference templates that aren't loaded.
+    ///
+    /// By default, this is `false`, meaning a prototype with an unresolved template
+    /// fails to register.
+    ///
+    /// When enabled, unresolved templates are skipped with a warning
+    /// and the prototype is built using whatever templates are available.
+    /// This is useful for modular content where some templates are optional,
+    /// such as when content is split across packs that may not all be present.
+    ///
+    /// Note that a prototype is not rebuilt if a missing template is loaded later on.
+    /// It must be reloaded for the template to take effect.
+    ///
+    /// [prototype]: Prototypical
+    fn allow_missing_templates(&self) -> bool {
+        false
+    }
+
+    /// Controls whether a schematic that fails to apply should be skipped.
+    ///
+    /// A schematic may fail to apply if, for example, one of its fields
+    /// rejects its input via [`TryFromSchematicInput`].
+    ///
+    /// By default, this is `false`, meaning such a failure will panic.
+    ///
+    /// When enabled, the error is logged and the remaining schematics
+    /// are applied as normal.
+    ///
+    /// [`TryFromSchematicInput`]: crate::schematics::TryFromSchematicInput
+    fn skip_failed_schematics(&self) -> bool {
+        false
+    }
+
+    /// Controls whether lazy assets are given weak handles.
+    ///
+    /// Lazy assets are those that are not preloaded, such as an asset path
+    /// within a schematic that isn't marked with `#[schematic(asset(preload))]`.
+    /// By default, this is `false`, meaning such assets are given strong handles
+    /// and will stay loaded for as long as the resulting component (or resource) exists.
+    ///
+    /// When enabled, these assets are given weak handles instead,
+    /// as long as they are already loaded when the schematic is applied.
+    /// This can be useful for pooled or transient entities that shouldn't keep their
+    /// assets alive on their own.
+    /// However, it means that something else must hold a strong handle to the asset.
+    /// Otherwise, the asset may be unloaded as soon as the schematic has been applied,
+    /// leaving the weak handle dangling.
+    ///
+    /// Assets that are still loading are always given strong handles,
+    /// since dropping the only strong handle would cancel the load.
+    ///
+    /// This does not affect preloaded assets (which are kept alive by the prototype itself)
+    /// or inline assets.
+    fn weak_lazy_assets(&self) -> bool {
+        false
+    }
+
+    /// Controls whether the templates of a [prototype] cascade down to its children.
+    ///
+    /// When enabled, every child in a hierarchy inherits the templates of its parent
+    /// (including those the parent itself inherited), applied before the child's own templates.
+    /// This allows "theme" templates applied once at the root to affect all descendants.
+    ///
+    /// By default, this is `false`, meaning children only use the templates they define themselves.
+    ///
+    /// [prototype]: Prototypical
+    fn cascade_templates(&self) -> bool {
+        false
+    }
+
+    /// The IDs of templates implicitly inherited by every [prototype].
+    ///
+    /// Default templates are inherited after a prototype's own templates,
+    /// giving them the lowest priority.
+    /// Prototypes may opt out via [`Prototypical::uses_default_templates`].
+    /// The default templates themselves never inherit them.
+    ///
+    /// Default templates are looked up by ID when a prototype is registered.
+    /// If a default template has not been registered yet, it is skipped and
+    /// the prototype is rebuilt once the template is registered,
+    /// so the order in which they are loaded does not matter.
+    ///
+    /// By default, this is empty.
+    ///
+    /// [prototype]: Prototypical
+    fn default_templates(&self) -> &[T::Id] {
+        &[]
+    }
+
+    /// The set of active flags used to resolve conditional sections when loading a [prototype].
+    ///
+    /// How these flags are used is up to the [`Loader`].
+    /// They are read once when the [`ProtoBackendPlugin`] is built,
+    /// so changing them afterwards has no effect.
+    ///
+    /// By default, no flags are active.
+    ///
+    /// [prototype]: Prototypical
+    /// [`Loader`]: crate::load::Loader
+    fn active_flags(&self) -> HashSet<String> {
+        HashSet::new()
+    }
+
+    /// A map of deprecated type paths to the type paths that should be used in their place
+    /// when loading a [prototype].
+    ///
+    /// This allows types to be renamed or moved without breaking existing prototype files.
+    /// How these aliases are used is up to the [`Loader`].
+    /// They are read once when the [`ProtoBackendPlugin`] is built,
+    /// so changing them afterwards has no effect.
+    ///
+    /// By default, this is empty.
+    ///
+    /// [prototype]: Prototypical
+    /// [`Loader`]: crate::load::Loader
+    fn type_path_aliases(&self) -> HashMap<String, String> {
+        HashMap::new()
+    }
+
     /// Controls how [cycles] should be handled.
     ///
     /// When `#[cfg(debug_assertions)]` is enabled, the default behavior will be to panic.
@@ -0,0 +1,253 @@
+use bevy::asset::Assets;
+use bevy::reflect::{Reflect, ReflectRef, VariantType};
+use indexmap::IndexMap;
+use serde::Serialize;
+
+use crate::proto::Prototypical;
+use crate::schematics::DynamicSchematic;
+use crate::tree::ProtoTree;
+
+/// The difference between the schematics of two [prototypes].
+///
+/// This is generated by [`Prototypes::diff`].
+///
+/// Each prototype is first flattened by applying its templates in order,
+/// such that a schematic defined by a prototype overrides the same schematic
+/// defined by any of its templates.
+/// The resulting schematic sets are then compared by type and, using reflection,
+/// by the value of each field.
+///
+/// Only the root prototypes are compared— their children are not.
+///
+/// [prototypes]: Prototypical
+/// [`Prototypes::diff`]: crate::proto::Prototypes::diff
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct PrototypeDiff {
+    /// The type paths of schematics only found in the first prototype.
+    pub removed: Vec<String>,
+    /// The type paths of schematics only found in the second prototype.
+    pub added: Vec<String>,
+    /// The schematics found in both prototypes whose values differ.
+    pub changed: Vec<SchematicDiff>,
+}
+
+impl PrototypeDiff {
+    pub(crate) fn new<T: Prototypical>(
+        a: &ProtoTree<T>,
+        b: &ProtoTree<T>,
+        assets: &Assets<T>,
+    ) -> Self {
+        let a = flatten(a, assets);
+        let b = flatten(b, assets);
+
+        let mut diff = Self::default();
+
+        for (name, a_schematic) in &a {
+            match b.get(name) {
+                Some(b_schematic) => {
+                    let mut fields = Vec::new();
+                    diff_values(
+                        String::new(),
+                        a_schematic.input(),
+                        b_schematic.input(),
+                        &mut fields,
+                    );
+
+                    if !fields.is_empty() {
+                        diff.changed.push(SchematicDiff {
+                            schematic: name.to_string(),
+                            fields,
+                        });
+                    }
+                }
+                None => diff.removed.push(name.to_string()),
+            }
+        }
+
+        for name in b.keys() {
+            if !a.contains_key(name) {
+                diff.added.push(name.to_string());
+            }
+        }
+
+        diff
+    }
+
+    /// Returns true if both prototypes resulted in the same schematics.
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// The difference between the values of a schematic found in both prototypes of a [`PrototypeDiff`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchematicDiff {
+    /// The type path of the schematic.
+    pub schematic: String,
+    /// The fields whose values differ.
+    pub fields: Vec<FieldDiff>,
+}
+
+/// A single differing value within a [`SchematicDiff`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldDiff {
+    /// The path to the value within the schematic's input (e.g. `color.r` or `items[2]`).
+    ///
+    /// This is empty if the input itself differs.
+    pub path: String,
+    /// The debug representation of the value in the first prototype, if any.
+    pub a: Option<String>,
+    /// The debug representation of the value in the second prototype, if any.
+    pub b: Option<String>,
+}
+
+/// Collect the schematics of the given tree's prototype and templates,
+/// in application order, with later schematics overriding earlier ones.
+fn flatten<'a, T: Prototypical>(
+    tree: &ProtoTree<T>,
+    assets: &'a Assets<T>,
+) -> IndexMap<&'a str, &'a DynamicSchematic> {
+    let mut schematics = IndexMap::new();
+
+    for handle_id in tree.prototypes().iter().rev() {
+        let Some(prototype) = assets.get(&assets.get_handle(*handle_id)) else {
+            continue;
+        };
+
+        for (name, schematic) in prototype.schematics().iter() {
+            schematics.insert(name.as_ref(), schematic);
+        }
+    }
+
+    schematics
+}
+
+fn diff_values(path: String, a: &dyn Reflect, b: &dyn Reflect, diffs: &mut Vec<FieldDiff>) {
+    if a.type_name() != b.type_name() {
+        return push_diff(path, Some(a), Some(b), diffs);
+    }
+
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => {
+            for (index, a_field) in a.iter_fields().enumerate() {
+                let name = a.name_at(index).unwrap_or_default();
+                diff_values(
+                    join_field(&path, name),
+                    a_field,
+                    b.field(name).unwrap_or(a_field),
+                    diffs,
+                );
+            }
+        }
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => {
+            for (index, (a_field, b_field)) in a.iter_fields().zip(b.iter_fields()).enumerate() {
+                diff_values(join_field(&path, index), a_field, b_field, diffs);
+            }
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => {
+            for (index, (a_field, b_field)) in a.iter_fields().zip(b.iter_fields()).enumerate() {
+                diff_values(join_field(&path, index), a_field, b_field, diffs);
+            }
+        }
+        (ReflectRef::List(a), ReflectRef::List(b)) => {
+            for index in 0..a.len().max(b.len()) {
+                diff_elements(join_index(&path, index), a.get(index), b.get(index), diffs);
+            }
+        }
+        (ReflectRef::Array(a), ReflectRef::Array(b)) => {
+            for index in 0..a.len().max(b.len()) {
+                diff_elements(join_index(&path, index), a.get(index), b.get(index), diffs);
+            }
+        }
+        (ReflectRef::Enum(a_enum), ReflectRef::Enum(b_enum)) => {
+            if a_enum.variant_name() != b_enum.variant_name() {
+                return push_diff(path, Some(a), Some(b), diffs);
+            }
+
+            match a_enum.variant_type() {
+                VariantType::Struct => {
+                    for (index, a_field) in a_enum.iter_fields().enumerate() {
+                        let name = a_enum.name_at(index).unwrap_or_default();
+                        diff_values(
+                            join_field(&path, name),
+                            a_field.value(),
+                            b_enum.field(name).unwrap_or(a_field.value()),
+                            diffs,
+                        );
+                    }
+                }
+                VariantType::Tuple => {
+                    for (index, (a_field, b_field)) in
+                        a_enum.iter_fields().zip(b_enum.iter_fields()).enumerate()
+                    {
+                        diff_values(
+                            join_field(&path, index),
+                            a_field.value(),
+                            b_field.value(),
+                            diffs,
+                        );
+                    }
+                }
+                VariantType::Unit => {}
+            }
+        }
+        _ => {
+            if !a
+                .reflect_partial_eq(b)
+                .unwrap_or_else(|| debug(a) == debug(b))
+            {
+                push_diff(path, Some(a), Some(b), diffs);
+            }
+        }
+    }
+}
+
+/// Returns true if the two values are structurally equal.
+///
+/// This uses the same comparison as [`PrototypeDiff`].
+pub(crate) fn values_eq(a: &dyn Reflect, b: &dyn Reflect) -> bool {
+    let mut diffs = Vec::new();
+    diff_values(String::new(), a, b, &mut diffs);
+    diffs.is_empty()
+}
+
+fn diff_elements(
+    path: String,
+    a: Option<&dyn Reflect>,
+    b: Option<&dyn Reflect>,
+    diffs: &mut Vec<FieldDiff>,
+) {
+    match (a, b) {
+        (Some(a), Some(b)) => diff_values(path, a, b, diffs),
+        (a, b) => push_diff(path, a, b, diffs),
+    }
+}
+
+fn push_diff(
+    path: String,
+    a: Option<&dyn Reflect>,
+    b: Option<&dyn Reflect>,
+    diffs: &mut Vec<FieldDiff>,
+) {
+    diffs.push(FieldDiff {
+        path,
+        a: a.map(debug),
+        b: b.map(debug),
+    });
+}
+
+fn join_field(path: &str, field: impl std::fmt::Display) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+fn join_index(path: &str, index: usize) -> String {
+    format!("{}[{}]", path, index)
+}
+
+fn debug(value: &dyn Reflect) -> String {
+    format!("{:?}", value)
+}
@@ -36,4 +36,9 @@ pub enum ProtoError {
     /// This includes attempting to register children on an entity-less prototype.
     #[error("expected prototype with ID {id:?} to require an entity")]
     RequiresEntity { id: String },
+    /// Indicates that multiple entities within a prototype's tree share the same [local ID].
+    ///
+    /// [local ID]: crate::children::PrototypicalChild::local_id
+    #[error("prototype with ID {id:?} contains multiple children with the local ID {local_id:?}")]
+    DuplicateLocalId { id: String, local_id: String },
 }
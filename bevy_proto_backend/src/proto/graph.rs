@@ -0,0 +1,196 @@
+use std::fmt::{Display, Formatter};
+
+use bevy::asset::{Assets, HandleId};
+use serde::Serialize;
+
+use crate::children::PrototypicalChild;
+use crate::proto::{Config, Prototypical};
+use crate::registration::ProtoRegistry;
+
+/// The relationships between all registered [prototypes].
+///
+/// This is generated by [`Prototypes::dependency_graph`] and can be serialized
+/// or converted to the [DOT] format (via [`ProtoGraph::to_dot`]) for visualization.
+///
+/// Only direct relationships are captured.
+/// For example, if `A` inherits `B` and `B` inherits `C`,
+/// the graph will contain the edges `A -> B` and `B -> C`, but not `A -> C`.
+///
+/// [prototypes]: Prototypical
+/// [`Prototypes::dependency_graph`]: crate::proto::Prototypes::dependency_graph
+/// [DOT]: https://graphviz.org/doc/info/lang.html
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct ProtoGraph {
+    /// The IDs of all registered prototypes, sorted.
+    pub nodes: Vec<String>,
+    /// The relationships between prototypes, sorted.
+    pub edges: Vec<ProtoGraphEdge>,
+}
+
+/// A single relationship within a [`ProtoGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct ProtoGraphEdge {
+    /// The ID of the dependent prototype.
+    pub from: String,
+    /// The ID of the prototype being depended on.
+    pub to: String,
+    /// How `from` depends on `to`.
+    pub kind: ProtoGraphEdgeKind,
+}
+
+/// The kind of a [`ProtoGraphEdge`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum ProtoGraphEdgeKind {
+    /// The prototype inherits the other as a template.
+    Inherits,
+    /// The prototype contains the other as a child.
+    Contains,
+}
+
+impl ProtoGraph {
+    pub(crate) fn new<T: Prototypical, C: Config<T>>(
+        registry: &ProtoRegistry<T, C>,
+        assets: &Assets<T>,
+    ) -> Self {
+        let mut graph = Self::default();
+
+        let get_id = |handle: HandleId| registry.get_id(handle).map(ToString::to_string);
+
+        for (handle_id, id) in registry.iter() {
+            graph.nodes.push(id.to_string());
+
+            let Some(prototype) = assets.get(&assets.get_handle(*handle_id)) else {
+                continue;
+            };
+
+            if let Some(templates) = prototype.templates() {
+                for (_, handle) in templates.iter() {
+                    if let Some(to) = get_id(handle.id()) {
+                        graph.edges.push(ProtoGraphEdge {
+                            from: id.to_string(),
+                            to,
+                            kind: ProtoGraphEdgeKind::Inherits,
+                        });
+                    }
+                }
+            }
+
+            if let Some(children) = prototype.children() {
+                for child in children.iter() {
+                    if let Some(to) = get_id(child.handle().id()) {
+                        graph.edges.push(ProtoGraphEdge {
+                            from: id.to_string(),
+                            to,
+                            kind: ProtoGraphEdgeKind::Contains,
+                        });
+                    }
+                }
+            }
+        }
+
+        graph.nodes.sort();
+        graph.edges.sort();
+        graph
+    }
+
+    /// Returns this graph in the [DOT] format.
+    ///
+    /// Template edges are drawn dashed, while child edges are drawn solid.
+    ///
+    /// [DOT]: https://graphviz.org/doc/info/lang.html
+    pub fn to_dot(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for ProtoGraph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "digraph prototypes {{")?;
+
+        for node in &self.nodes {
+            writeln!(f, "  {:?};", node)?;
+        }
+
+        for edge in &self.edges {
+            let attrs = match edge.kind {
+                ProtoGraphEdgeKind::Inherits => "label=\"inherits\", style=dashed",
+                ProtoGraphEdgeKind::Contains => "label=\"contains\"",
+            };
+
+            writeln!(f, "  {:?} -> {:?} [{}];", edge.from, edge.to, attrs)?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::SystemState;
+
+    use crate::proto::Prototypes;
+    use crate::test_utils::{self, TestConfig, TestProto};
+
+    use super::*;
+
+    fn edge(from: &str, to: &str, kind: ProtoGraphEdgeKind) -> ProtoGraphEdge {
+        ProtoGraphEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn should_capture_direct_relationships() {
+        let mut app = test_utils::app();
+        let base = test_utils::add(&mut app, TestProto::new("Base"));
+        let parent = test_utils::add(
+            &mut app,
+            TestProto::new("Parent").with_template("Base", &base),
+        );
+        let child = test_utils::add(
+            &mut app,
+            TestProto::new("Child").with_template("Parent", &parent),
+        );
+        test_utils::add(&mut app, TestProto::new("Root").with_child(&child));
+        test_utils::register(&mut app);
+
+        let graph = SystemState::<Prototypes<TestProto, TestConfig>>::new(&mut app.world)
+            .get(&app.world)
+            .dependency_graph();
+
+        assert_eq!(vec!["Base", "Child", "Parent", "Root"], graph.nodes);
+        // `Child` only indirectly inherits `Base`, so that edge is omitted
+        assert_eq!(
+            vec![
+                edge("Child", "Parent", ProtoGraphEdgeKind::Inherits),
+                edge("Parent", "Base", ProtoGraphEdgeKind::Inherits),
+                edge("Root", "Child", ProtoGraphEdgeKind::Contains),
+            ],
+            graph.edges
+        );
+    }
+
+    #[test]
+    fn should_convert_to_dot() {
+        let graph = ProtoGraph {
+            nodes: vec![String::from("A"), String::from("B"), String::from("C")],
+            edges: vec![
+                edge("A", "B", ProtoGraphEdgeKind::Inherits),
+                edge("A", "C", ProtoGraphEdgeKind::Contains),
+            ],
+        };
+
+        assert_eq!(
+            r#"digraph prototypes {
+  "A";
+  "B";
+  "C";
+  "A" -> "B" [label="inherits", style=dashed];
+  "A" -> "C" [label="contains"];
+}"#,
+            graph.to_dot()
+        );
+    }
+}
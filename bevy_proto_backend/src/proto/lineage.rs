@@ -0,0 +1,58 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::proto::Prototypical;
+
+/// The lineage of an entity spawned from a [prototype].
+///
+/// This contains the [ID] of the prototype that produced the entity,
+/// along with the IDs of all the templates it inherits from (flattened).
+///
+/// This can be retrieved using [`Prototypes::lineage`].
+///
+/// [prototype]: Prototypical
+/// [ID]: Prototypical::id
+/// [`Prototypes::lineage`]: crate::proto::Prototypes::lineage
+pub struct ProtoLineage<'a, T: Prototypical> {
+    id: &'a T::Id,
+    templates: Vec<&'a T::Id>,
+}
+
+impl<'a, T: Prototypical> ProtoLineage<'a, T> {
+    pub(crate) fn new(id: &'a T::Id, templates: Vec<&'a T::Id>) -> Self {
+        Self { id, templates }
+    }
+
+    /// The [ID] of the prototype that produced the entity.
+    ///
+    /// [ID]: Prototypical::id
+    pub fn id(&self) -> &'a T::Id {
+        self.id
+    }
+
+    /// The [IDs] of the templates inherited by the prototype.
+    ///
+    /// These are in reverse-application order, meaning the templates that take
+    /// precedence come first.
+    ///
+    /// [IDs]: Prototypical::id
+    pub fn templates(&self) -> &[&'a T::Id] {
+        &self.templates
+    }
+
+    /// Returns an iterator over the entire lineage, starting with the prototype itself
+    /// and followed by its [templates].
+    ///
+    /// [templates]: Self::templates
+    pub fn iter(&self) -> impl Iterator<Item = &'a T::Id> + '_ {
+        std::iter::once(self.id).chain(self.templates.iter().copied())
+    }
+}
+
+impl<'a, T: Prototypical> Debug for ProtoLineage<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProtoLineage")
+            .field("id", self.id)
+            .field("templates", &self.templates)
+            .finish()
+    }
+}
@@ -3,19 +3,34 @@
 #[cfg(feature = "bevy_render")]
 pub use color::*;
 pub use commands::*;
+pub use completion::*;
 pub use component::*;
 pub use config::*;
+pub(crate) use diff::values_eq;
+pub use diff::*;
 pub use error::*;
+pub use graph::*;
+pub use lineage::*;
 pub use prototypes::*;
 pub use prototypical::*;
 pub(crate) use storage::*;
+pub use validation::*;
+pub use world::*;
 
 #[cfg(feature = "bevy_render")]
 mod color;
 mod commands;
+mod completion;
 mod component;
 mod config;
+mod diff;
 mod error;
+mod graph;
+mod lineage;
 mod prototypes;
 mod prototypical;
+#[cfg(feature = "bevy_scene")]
+mod scene;
 mod storage;
+mod validation;
+mod world;
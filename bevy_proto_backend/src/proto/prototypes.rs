@@ -1,14 +1,22 @@
 use std::borrow::Borrow;
 
-use bevy::asset::{AssetServerError, Handle, HandleId, HandleUntyped, LoadState};
+use bevy::asset::{
+    AssetPath, AssetServerError, Assets, Handle, HandleId, HandleUntyped, LoadState,
+};
 use bevy::ecs::system::SystemParam;
-use bevy::prelude::{AssetServer, Res, ResMut};
+use bevy::prelude::{AppTypeRegistry, AssetServer, Res, ResMut, Resource};
+use indexmap::IndexSet;
 use std::hash::Hash;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
-use crate::proto::{Config, ProtoStorage, Prototypical};
+use crate::proto::{
+    Config, ProtoGraph, ProtoInstance, ProtoLineage, ProtoStorage, ProtoValidationError,
+    ProtoValidationReport, PrototypeDiff, Prototypical,
+};
 use crate::registration::ProtoRegistry;
+use crate::schematics::SchematicDescription;
+use crate::tree::ProtoTree;
 
 #[derive(Debug, Error)]
 pub enum ProtoLoadError {
@@ -28,6 +36,8 @@ pub struct Prototypes<'w, T: Prototypical, C: Config<T>> {
     config: Res<'w, C>,
     asset_server: Res<'w, AssetServer>,
     storage: Res<'w, ProtoStorage<T>>,
+    prototypes: Res<'w, Assets<T>>,
+    type_registry: Res<'w, AppTypeRegistry>,
 }
 
 /// A helper [`SystemParam`] for managing [prototypes].
@@ -41,6 +51,9 @@ pub struct PrototypesMut<'w, T: Prototypical, C: Config<T>> {
     config: ResMut<'w, C>,
     asset_server: Res<'w, AssetServer>,
     storage: ResMut<'w, ProtoStorage<T>>,
+    prototypes: Res<'w, Assets<T>>,
+    register_queue: ResMut<'w, ProtoRegisterQueue<T>>,
+    type_registry: Res<'w, AppTypeRegistry>,
 }
 
 impl<'w, T: Prototypical, C: Config<T>> PrototypesMut<'w, T, C> {
@@ -61,7 +74,14 @@ impl<'w, T: Prototypical, C: Config<T>> PrototypesMut<'w, T, C> {
     ///
     /// This will also store strong handles to the prototypes in order to keep them loaded.
     ///
+    /// Only the directory listing happens on the calling thread.
+    /// Reading and deserializing each file is handled by the [`AssetServer`] on the IO task pool,
+    /// and the results are registered by a system as each one finishes loading
+    /// (use [`Prototypes::is_ready`] to check when a prototype becomes available).
+    ///
     /// To load without automatically storing the handles, try using [`AssetServer::load_folder`].
+    ///
+    /// [`Prototypes::is_ready`]: crate::proto::Prototypes::is_ready
     pub fn load_folder<P: Into<PathBuf>>(
         &mut self,
         path: P,
@@ -83,6 +103,62 @@ impl<'w, T: Prototypical, C: Config<T>> PrototypesMut<'w, T, C> {
         Ok(handles)
     }
 
+    /// Register an already-constructed prototype.
+    ///
+    /// The prototype is queued to be added as an asset during [`ProtoSet::Register`],
+    /// after which it will be registered just as if it had been loaded from a file:
+    /// its templates and children are validated (including checks for cycles)
+    /// and a [`ProtoAssetEvent::Created`] event is sent once registration completes.
+    /// Like [`load`], this stores a strong handle under the prototype's [path]
+    /// in order to keep it loaded.
+    ///
+    /// Since the asset events for the new asset are only sent at the end of that frame,
+    /// registration happens the next time prototypes are processed after that
+    /// (use [`Prototypes::is_ready`] to check when the prototype becomes available).
+    /// Any templates or children it references must already be loaded by then.
+    ///
+    /// Prototype IDs must be unique.
+    /// If a prototype with the same ID is later loaded from a file, that file will fail to register
+    /// (and vice versa if the file was registered first).
+    /// Similarly, calling [`load`] with the same path will replace the stored handle,
+    /// allowing this prototype to be unloaded.
+    ///
+    /// # Replacing Prototypes
+    ///
+    /// If a prototype with the same ID is already registered, it is replaced in-place
+    /// and the existing handle is returned.
+    /// This is treated just like a hot-reload: the prototype is validated again,
+    /// every prototype that depends on it (as a template or as a child) is reloaded,
+    /// and a [`ProtoAssetEvent::Modified`] event is sent instead of `Created`.
+    ///
+    /// This allows in-memory prototypes, which can't be watched for changes like files,
+    /// to be reloaded manually (e.g. when receiving updated content over the network).
+    /// Note that replacing a prototype that was loaded from a file will be undone
+    /// if that file is later modified.
+    ///
+    /// [`ProtoAssetEvent::Created`]: crate::assets::ProtoAssetEvent::Created
+    /// [`ProtoAssetEvent::Modified`]: crate::assets::ProtoAssetEvent::Modified
+    /// [`load`]: Self::load
+    /// [path]: Prototypical::path
+    /// [`ProtoSet::Register`]: crate::ProtoSet::Register
+    /// [`Prototypes::is_ready`]: crate::proto::Prototypes::is_ready
+    pub fn register(&mut self, prototype: T) -> Handle<T> {
+        let path = prototype.path().path().to_path_buf();
+        let handle_id = self
+            .registry
+            .get_tree_by_id(prototype.id())
+            .map(|tree| tree.handle())
+            .or_else(|| self.register_queue.get_handle_id(prototype.id()))
+            .unwrap_or_else(HandleId::random::<T>);
+
+        // Replacing an existing asset sends a `Modified` event, triggering a reload
+        let handle = self.prototypes.get_handle(handle_id);
+        self.register_queue.queue.push((handle_id, prototype));
+
+        self.storage.insert(path, handle.clone());
+        handle
+    }
+
     /// Remove the stored handle for the given prototype path.
     ///
     /// This allows the asset to be unloaded if the handle is dropped and no other
@@ -106,6 +182,43 @@ impl<'w, T: Prototypical, C: Config<T>> PrototypesMut<'w, T, C> {
     }
 }
 
+/// Resource containing the prototypes queued by [`PrototypesMut::register`].
+///
+/// These are added to their [`Assets`] by a system in [`ProtoSet::Register`]
+/// so that [`PrototypesMut`] only needs immutable access to the assets.
+///
+/// [`ProtoSet::Register`]: crate::ProtoSet::Register
+#[derive(Resource)]
+pub(crate) struct ProtoRegisterQueue<T: Prototypical> {
+    queue: Vec<(HandleId, T)>,
+}
+
+impl<T: Prototypical> ProtoRegisterQueue<T> {
+    /// Returns the [`HandleId`] of the queued prototype with the given ID, if any.
+    fn get_handle_id(&self, id: &T::Id) -> Option<HandleId> {
+        self.queue
+            .iter()
+            .find(|(_, prototype)| prototype.id() == id)
+            .map(|(handle_id, _)| *handle_id)
+    }
+}
+
+impl<T: Prototypical> Default for ProtoRegisterQueue<T> {
+    fn default() -> Self {
+        Self { queue: Vec::new() }
+    }
+}
+
+/// Adds the prototypes in the [`ProtoRegisterQueue`] to their [`Assets`].
+pub(crate) fn add_registered_prototypes<T: Prototypical>(
+    mut queue: ResMut<ProtoRegisterQueue<T>>,
+    mut prototypes: ResMut<Assets<T>>,
+) {
+    for (handle_id, prototype) in queue.queue.drain(..) {
+        prototypes.set_untracked(handle_id, prototype);
+    }
+}
+
 macro_rules! impl_prototypes {
     ($ident: ident) => {
         impl<'w, T: Prototypical, C: Config<T>> $ident<'w, T, C> {
@@ -141,12 +254,20 @@ macro_rules! impl_prototypes {
             /// This method is preferred over [`AssetServer::get_load_state`] as it better
             /// accounts for prototype dependencies and registration.
             ///
+            /// If [`Config::strict_readiness`] is enabled, this also requires that all
+            /// lazy assets of the prototype have finished loading.
+            ///
             /// [ID]: Prototypical::id
             pub fn is_ready<I: Hash + Eq + ?Sized>(&self, id: &I) -> bool
             where
                 T::Id: Borrow<I>,
             {
-                self.registry.contains(id)
+                if !self.registry.contains(id) {
+                    return false;
+                }
+
+                !self.config.strict_readiness()
+                    || self.registry.is_lazy_loaded(id, &self.asset_server)
             }
 
             /// Returns true if the prototype with the given handle is ready to be spawned.
@@ -154,7 +275,211 @@ macro_rules! impl_prototypes {
             /// This method is preferred over [`AssetServer::get_load_state`] as it better
             /// accounts for prototype dependencies and registration.
             pub fn is_ready_handle<H: Into<HandleId>>(&self, handle: H) -> bool {
-                self.registry.contains_handle(handle)
+                let handle = handle.into();
+                if !self.registry.contains_handle(handle) {
+                    return false;
+                }
+
+                !self.config.strict_readiness()
+                    || self
+                        .registry
+                        .is_lazy_loaded_handle(handle, &self.asset_server)
+            }
+
+            /// Returns the path of the file the registered prototype with the given [ID]
+            /// was loaded from.
+            ///
+            /// Returns `None` if the prototype is not registered or if it was not loaded
+            /// from a file, such as when added directly to its [`Assets`] collection.
+            ///
+            /// [ID]: Prototypical::id
+            /// [`Assets`]: bevy::asset::Assets
+            pub fn source_path<I: Hash + Eq + ?Sized>(&self, id: &I) -> Option<&Path>
+            where
+                T::Id: Borrow<I>,
+            {
+                self.registry.get_source_path(id)
+            }
+
+            /// Returns the [lineage] of an entity spawned from a prototype,
+            /// using its [`ProtoInstance`] component.
+            ///
+            /// This works for child entities spawned as part of a hierarchy as well,
+            /// in which case the lineage will be that of the child's own prototype.
+            /// If multiple prototypes have been inserted onto the entity,
+            /// the lineage will be that of the earliest one that is still applied.
+            ///
+            /// Returns `None` if the prototype is not currently registered.
+            ///
+            /// [lineage]: ProtoLineage
+            pub fn lineage(&self, instance: &ProtoInstance) -> Option<ProtoLineage<'_, T>> {
+                let id = self.registry.get_id(instance.handle())?;
+                let tree = self.registry.get_tree(instance.handle())?;
+                let templates = tree
+                    .prototypes()
+                    .iter()
+                    .skip(1)
+                    .filter_map(|handle| self.registry.get_id(*handle))
+                    .collect();
+
+                Some(ProtoLineage::new(id, templates))
+            }
+
+            /// Compares the schematics of the registered prototypes with the given [IDs].
+            ///
+            /// Each prototype is flattened along with its templates before being compared.
+            /// See [`PrototypeDiff`] for details.
+            ///
+            /// Returns `None` if either prototype is not currently registered.
+            ///
+            /// # Example
+            ///
+            /// ```ignore
+            /// let diff = prototypes.diff("Player", "PoweredUpPlayer").unwrap();
+            /// assert_eq!(diff.added, vec!["my_game::Invincible"]);
+            /// ```
+            ///
+            /// [IDs]: Prototypical::id
+            pub fn diff<I: Hash + Eq + ?Sized>(&self, a: &I, b: &I) -> Option<PrototypeDiff>
+            where
+                T::Id: Borrow<I>,
+            {
+                let a = self.registry.get_tree_by_key(a)?;
+                let b = self.registry.get_tree_by_key(b)?;
+                Some(PrototypeDiff::new(a, b, &self.prototypes))
+            }
+
+            /// Returns the graph of template and child relationships between all registered prototypes.
+            ///
+            /// The result can be serialized or converted to the DOT format for visualization:
+            ///
+            /// ```ignore
+            /// std::fs::write("prototypes.dot", prototypes.dependency_graph().to_dot()).unwrap();
+            /// ```
+            pub fn dependency_graph(&self) -> ProtoGraph {
+                ProtoGraph::new(&self.registry, &self.prototypes)
+            }
+
+            /// Returns a description of the authorable shape of the [schematic]
+            /// with the given type name.
+            ///
+            /// This lists the fields of the schematic's input type, along with their types,
+            /// defaults, and whether they reference assets or entities.
+            /// The result can be serialized or converted to markdown to generate authoring docs:
+            ///
+            /// ```ignore
+            /// let description = prototypes.describe_schematic("my_game::Player").unwrap();
+            /// std::fs::write("Player.md", description.to_markdown()).unwrap();
+            /// ```
+            ///
+            /// This reads purely from the type registry, so the schematic does not need to be
+            /// used by any prototype.
+            /// Returns `None` if the type is not registered with [`ReflectSchematic`].
+            ///
+            /// [schematic]: crate::schematics::Schematic
+            /// [`ReflectSchematic`]: crate::schematics::ReflectSchematic
+            pub fn describe_schematic(&self, type_name: &str) -> Option<SchematicDescription> {
+                SchematicDescription::new(&self.type_registry.read(), type_name)
+            }
+
+            /// Returns the registered prototype with the given [ID] for inspection.
+            ///
+            /// This can be used to read a prototype's metadata, such as its templates
+            /// or the types of its schematics, without spawning it.
+            ///
+            /// Returns `None` if the prototype is not currently registered.
+            ///
+            /// [ID]: Prototypical::id
+            pub fn get_prototype<I: Hash + Eq + ?Sized>(&self, id: &I) -> Option<&T>
+            where
+                T::Id: Borrow<I>,
+            {
+                let handle = self.registry.get_tree_by_key(id)?.handle();
+                self.prototypes.get(&self.prototypes.get_handle(handle))
+            }
+
+            /// Returns the asset paths preloaded by the registered prototype with the given [ID].
+            ///
+            /// This includes the preload dependencies of the prototype's templates
+            /// and of its entire hierarchy of children, without duplicates.
+            /// It can be used to verify that assets exist or to drive progress reporting
+            /// (e.g. for a loading screen).
+            ///
+            /// Lazy assets (those that aren't preloaded) are not included.
+            ///
+            /// Returns an empty list if the prototype is not currently registered.
+            ///
+            /// [ID]: Prototypical::id
+            pub fn dependencies<I: Hash + Eq + ?Sized>(&self, id: &I) -> Vec<AssetPath<'static>>
+            where
+                T::Id: Borrow<I>,
+            {
+                let mut paths = IndexSet::new();
+                if let Some(tree) = self.registry.get_tree_by_key(id) {
+                    collect_dependencies(tree, &self.prototypes, &mut paths);
+                }
+
+                paths.into_iter().collect()
+            }
+
+            /// Returns true if any stored prototype is still being loaded or registered.
+            ///
+            /// Stored prototypes are those loaded via [`PrototypesMut::load`],
+            /// [`PrototypesMut::load_folder`], or [`PrototypesMut::register`].
+            pub fn is_loading(&self) -> bool {
+                self.storage.iter().any(|(_, handle)| {
+                    matches!(
+                        self.get_load_state(handle),
+                        LoadState::NotLoaded | LoadState::Loading
+                    )
+                })
+            }
+
+            /// Returns true if no prototypes are stored.
+            pub fn is_empty(&self) -> bool {
+                self.storage.iter().next().is_none()
+            }
+
+            /// Checks that every stored prototype was successfully loaded and registered.
+            ///
+            /// All problems are gathered into a single [`ProtoValidationReport`],
+            /// making this useful for catching content errors early,
+            /// such as in CI or at startup.
+            ///
+            /// Prototypes that are still loading are reported as errors as well,
+            /// so this should generally be called once [`is_loading`] returns `false`.
+            ///
+            /// [`is_loading`]: Self::is_loading
+            pub fn validate_all(&self) -> Result<(), ProtoValidationReport> {
+                let mut errors = self
+                    .storage
+                    .iter()
+                    .filter_map(|(path, handle)| {
+                        let reason = match self.get_load_state(handle) {
+                            LoadState::Loaded => return None,
+                            LoadState::NotLoaded | LoadState::Loading => {
+                                String::from("has not finished loading")
+                            }
+                            LoadState::Failed => match self.registry.get_failure(handle) {
+                                Some(reason) => format!("failed to register: {}", reason),
+                                None => String::from("failed to load (see logs for details)"),
+                            },
+                            LoadState::Unloaded => String::from("was unloaded"),
+                        };
+
+                        Some(ProtoValidationError {
+                            path: path.clone(),
+                            reason,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                if errors.is_empty() {
+                    return Ok(());
+                }
+
+                errors.sort_by(|a, b| a.path.cmp(&b.path));
+                Err(ProtoValidationReport { errors })
             }
 
             /// Returns true if a prototype with the given path is currently stored.
@@ -179,5 +504,100 @@ macro_rules! impl_prototypes {
     };
 }
 
+/// Recursively collects the preload dependency paths of the given tree.
+fn collect_dependencies<T: Prototypical>(
+    tree: &ProtoTree<T>,
+    prototypes: &Assets<T>,
+    paths: &mut IndexSet<AssetPath<'static>>,
+) {
+    for handle_id in tree.prototypes() {
+        if let Some(prototype) = prototypes.get(&prototypes.get_handle(*handle_id)) {
+            paths.extend(
+                prototype
+                    .dependencies()
+                    .iter()
+                    .map(|(path, _)| path.clone()),
+            );
+        }
+    }
+
+    for child in tree.children() {
+        collect_dependencies(child, prototypes, paths);
+    }
+}
+
 impl_prototypes!(Prototypes);
 impl_prototypes!(PrototypesMut);
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::SystemState;
+    use bevy::prelude::{App, Component, Reflect};
+
+    use crate::test_utils::{self, TestConfig, TestProto};
+    use bevy_proto_derive::Schematic;
+
+    use super::*;
+
+    #[derive(Component, Reflect, Schematic, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    fn register(app: &mut App, prototype: TestProto) -> Handle<TestProto> {
+        let mut state = SystemState::<PrototypesMut<TestProto, TestConfig>>::new(&mut app.world);
+        let handle = state.get_mut(&mut app.world).register(prototype);
+        state.apply(&mut app.world);
+        handle
+    }
+
+    #[test]
+    fn should_register_alongside_prototype_assets() {
+        let mut app = test_utils::app();
+
+        // Would panic on creation if `PrototypesMut` required mutable access to the assets
+        let mut state =
+            SystemState::<(PrototypesMut<TestProto, TestConfig>, Res<Assets<TestProto>>)>::new(
+                &mut app.world,
+            );
+
+        let (mut prototypes, assets) = state.get_mut(&mut app.world);
+        let handle = prototypes.register(TestProto::new("A"));
+        assert!(assets.get(&handle).is_none());
+
+        test_utils::register(&mut app);
+
+        let registry = app.world.resource::<ProtoRegistry<TestProto, TestConfig>>();
+        assert_eq!(
+            Some(handle.id()),
+            registry
+                .get_tree_by_id(String::from("A"))
+                .map(|tree| tree.handle())
+        );
+    }
+
+    #[test]
+    fn should_reload_dependents_of_replaced_prototype() {
+        let mut app = test_utils::app();
+        app.register_type::<Health>();
+
+        let base = register(
+            &mut app,
+            TestProto::new("Base").with_schematic::<Health>(Health(1)),
+        );
+        register(
+            &mut app,
+            TestProto::new("Child").with_template("Base", &base),
+        );
+        test_utils::register(&mut app);
+
+        let replaced = register(
+            &mut app,
+            TestProto::new("Base").with_schematic::<Health>(Health(2)),
+        );
+        assert_eq!(base.id(), replaced.id());
+        test_utils::register(&mut app);
+
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("Child").id());
+        assert_eq!(Some(&Health(2)), app.world.get::<Health>(entity));
+    }
+}
@@ -6,8 +6,8 @@ use bevy::asset::Asset;
 use crate::children::{Children, PrototypicalChild};
 use crate::deps::Dependencies;
 use crate::path::ProtoPath;
-use crate::schematics::Schematics;
-use crate::templates::Templates;
+use crate::schematics::{DynamicSchematic, Schematics};
+use crate::templates::{Mixins, Templates};
 
 /// The trait used to define a prototype.
 ///
@@ -52,14 +52,46 @@ pub trait Prototypical: Asset + Sized {
     fn requires_entity(&self) -> bool {
         true
     }
+    /// Whether or not this prototype is abstract.
+    ///
+    /// Abstract prototypes may only be used as templates.
+    /// Attempting to spawn or apply one directly will panic.
+    ///
+    /// Defaults to `false`.
+    fn is_abstract(&self) -> bool {
+        false
+    }
+    /// Whether or not this prototype inherits the [default templates] defined by the config.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [default templates]: crate::proto::Config::default_templates
+    fn uses_default_templates(&self) -> bool {
+        true
+    }
     /// An immutable reference to the collection of [`Schematics`] contained in this prototype.
     fn schematics(&self) -> &Schematics;
     /// A mutable reference to the collection of [`Schematics`] contained in this prototype.
     fn schematics_mut(&mut self) -> &mut Schematics;
+    /// Returns the type-erased [`DynamicSchematic`] stored under the given type path, if any.
+    ///
+    /// This can be used to inspect a schematic's reflected input at runtime,
+    /// such as for diffing or copying schematics between prototypes.
+    /// Modifications should instead be made via [`Prototypical::schematics_mut`]
+    /// before the prototype is registered.
+    fn get_schematic(&self, type_path: &str) -> Option<&DynamicSchematic> {
+        self.schematics().get_by_name(type_path)
+    }
     /// An immutable reference to the collection of [`Templates`] inherited by this prototype, if any.
     fn templates(&self) -> Option<&Templates>;
     /// A mutable reference to the collection of [`Templates`] inherited by this prototype, if any.
     fn templates_mut(&mut self) -> Option<&mut Templates>;
+    /// An immutable reference to the collection of [`Mixins`] included by this prototype, if any.
+    ///
+    /// Defaults to `None`.
+    fn mixins(&self) -> Option<&Mixins> {
+        None
+    }
     /// An immutable reference to the collection of [`Dependencies`] used by this prototype.
     fn dependencies(&self) -> &Dependencies;
     /// A mutable reference to the collection of [`Dependencies`] used by this prototype.
@@ -0,0 +1,74 @@
+use bevy::ecs::system::Command;
+use bevy::hierarchy::{Children, DespawnRecursiveExt};
+use bevy::prelude::{Entity, World};
+use bevy::scene::{DynamicScene, DynamicSceneBuilder};
+
+use crate::proto::{Config, ProtoInsertCommand, Prototypes, Prototypical};
+
+impl<'w, T: Prototypical, C: Config<T>> Prototypes<'w, T, C> {
+    /// Converts the prototype with the given [ID] into a [`DynamicScene`].
+    ///
+    /// This works by spawning the prototype into the given world, extracting the spawned
+    /// entity (along with all of its descendants) into a scene, and then despawning them.
+    ///
+    /// Only components that are registered in the [`AppTypeRegistry`] and reflect
+    /// `ReflectComponent` will be captured by the scene.
+    ///
+    /// Since this requires exclusive world access, it is an associated function
+    /// rather than a method and should be called from an exclusive system.
+    ///
+    /// # Side Effects
+    ///
+    /// **The prototype is really applied to the given world.**
+    /// Although the spawned entities are despawned again before this returns,
+    /// everything else that applying the prototype does is kept:
+    ///
+    /// - All [`Config`] callbacks (e.g. [`Config::on_after_apply_prototype`]) are triggered
+    ///   for the temporary entities
+    /// - Schematics that don't [require an entity], such as resource schematics,
+    ///   modify the world as usual
+    /// - Assets created by inline asset schematics remain in their [`Assets`] collection
+    /// - Any [removal] logic is skipped, since the entities are simply despawned
+    ///
+    /// To avoid affecting the main world, pass in a separate world that has its own
+    /// [`ProtoBackendPlugin`] set up (see [`ProtoWorld`] for details).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prototype is not yet [ready].
+    ///
+    /// [ID]: Prototypical::id
+    /// [`AppTypeRegistry`]: bevy::ecs::reflect::AppTypeRegistry
+    /// [ready]: Prototypes::is_ready
+    /// [require an entity]: crate::schematics::Schematic::requires_entity
+    /// [`Assets`]: bevy::asset::Assets
+    /// [removal]: crate::schematics::Schematic::remove
+    /// [`ProtoBackendPlugin`]: crate::ProtoBackendPlugin
+    /// [`ProtoWorld`]: crate::proto::ProtoWorld
+    pub fn to_dynamic_scene<I: Into<T::Id>>(world: &mut World, id: I) -> DynamicScene {
+        let root = world.spawn_empty().id();
+        ProtoInsertCommand::<T, C>::new(id.into(), Some(root)).apply(world);
+
+        let mut entities = Vec::new();
+        collect_hierarchy(world, root, &mut entities);
+
+        let mut builder = DynamicSceneBuilder::from_world(world);
+        builder.extract_entities(entities.into_iter());
+        let scene = builder.build();
+
+        world.entity_mut(root).despawn_recursive();
+
+        scene
+    }
+}
+
+/// Collects the given entity and all of its descendants.
+fn collect_hierarchy(world: &World, entity: Entity, entities: &mut Vec<Entity>) {
+    entities.push(entity);
+
+    if let Some(children) = world.get::<Children>(entity) {
+        for child in children.iter() {
+            collect_hierarchy(world, *child, entities);
+        }
+    }
+}
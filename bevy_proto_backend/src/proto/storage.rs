@@ -44,6 +44,11 @@ impl<T: Prototypical> ProtoStorage<T> {
         self.path_to_handle.remove(path.as_ref())
     }
 
+    /// Returns an iterator over the stored paths and their handles.
+    pub fn iter(&self) -> impl Iterator<Item = (&PathBuf, &Handle<T>)> {
+        self.path_to_handle.iter()
+    }
+
     /// Remove all handles.
     pub fn clear(&mut self) {
         self.path_to_handle.clear();
@@ -0,0 +1,55 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+use bevy::prelude::Local;
+use thiserror::Error;
+
+use crate::proto::{Config, Prototypes, Prototypical};
+
+/// A problem found with a single prototype file by [`Prototypes::validate_all`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtoValidationError {
+    /// The path of the prototype file.
+    pub path: PathBuf,
+    /// A description of the problem.
+    pub reason: String,
+}
+
+/// A consolidated report of all problems found by [`Prototypes::validate_all`].
+///
+/// Errors are sorted by path.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Error)]
+pub struct ProtoValidationReport {
+    pub errors: Vec<ProtoValidationError>,
+}
+
+impl Display for ProtoValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "found {} invalid prototype(s):", self.errors.len())?;
+
+        for error in &self.errors {
+            write!(f, "\n  - `{}`: {}", error.path.display(), error.reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Validates all stored prototypes once they have finished loading,
+/// panicking with a [`ProtoValidationReport`] if any are invalid.
+///
+/// This only runs once.
+pub(crate) fn validate_on_startup<T: Prototypical, C: Config<T>>(
+    prototypes: Prototypes<T, C>,
+    mut is_done: Local<bool>,
+) {
+    if *is_done || prototypes.is_empty() || prototypes.is_loading() {
+        return;
+    }
+
+    *is_done = true;
+
+    if let Err(report) = prototypes.validate_all() {
+        panic!("{}", report);
+    }
+}
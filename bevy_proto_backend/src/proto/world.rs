@@ -0,0 +1,186 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::system::Command;
+use bevy::prelude::{Entity, World};
+
+use crate::proto::{
+    find_root_instances, Config, ProtoInsertCommand, ProtoRemoveCommand, Prototypical,
+};
+use crate::registration::ProtoRegistry;
+use crate::schematics::ProtoParams;
+
+/// A wrapper around a [`World`] for immediately spawning, inserting, and removing [prototypes].
+///
+/// Unlike [`ProtoCommands`], which always targets the main app's world,
+/// this can target _any_ world, such as a headless simulation world running
+/// separately from the render world.
+///
+/// All prototype data is taken from the targeted world itself,
+/// meaning it must contain its own:
+/// - [`ProtoRegistry<T, C>`]
+/// - [`Config<T>`] resource (`C`)
+/// - [`Assets<T>`]
+///
+/// The simplest way to set this up is to add the [`ProtoBackendPlugin`] to the [`SubApp`]
+/// (or separate [`App`]) that owns the world.
+/// Prototypes will then be loaded and registered within that world independently of the main app.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut sim = App::new();
+/// sim.add_plugins((MinimalPlugins, AssetPlugin::default()))
+///   .add_plugins(ProtoBackendPlugin::<MyProto, MyConfig>::default());
+///
+/// // ...load prototypes into `sim` and update it until they are registered...
+///
+/// let mut proto_world = ProtoWorld::<MyProto, MyConfig>::new(&mut sim.world);
+/// if proto_world.is_ready("Player") {
+///   let player = proto_world.spawn("Player");
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Operations panic if the targeted prototype is not registered in the given world,
+/// or if the world is missing any of the resources listed above.
+///
+/// [prototypes]: Prototypical
+/// [`ProtoCommands`]: crate::proto::ProtoCommands
+/// [`Assets<T>`]: bevy::asset::Assets
+/// [`ProtoBackendPlugin`]: crate::ProtoBackendPlugin
+/// [`SubApp`]: bevy::app::SubApp
+/// [`App`]: bevy::app::App
+pub struct ProtoWorld<'w, T: Prototypical, C: Config<T>> {
+    world: &'w mut World,
+    _phantom: PhantomData<(T, C)>,
+}
+
+impl<'w, T: Prototypical, C: Config<T>> ProtoWorld<'w, T, C> {
+    /// Wrap the given [`World`].
+    ///
+    /// This does not check that the world contains the required resources,
+    /// so use [`is_ready`] before spawning if the registration state is unknown.
+    ///
+    /// [`is_ready`]: Self::is_ready
+    pub fn new(world: &'w mut World) -> Self {
+        Self {
+            world,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns true if the prototype with the given [ID] is registered in this world.
+    ///
+    /// [ID]: Prototypical::id
+    pub fn is_ready<I: Into<T::Id>>(&self, id: I) -> bool {
+        self.world
+            .get_resource::<ProtoRegistry<T, C>>()
+            .map(|registry| registry.contains(&id.into()))
+            .unwrap_or_default()
+    }
+
+    /// Spawn the prototype with the given [ID], returning the spawned entity.
+    ///
+    /// [ID]: Prototypical::id
+    pub fn spawn<I: Into<T::Id>>(&mut self, id: I) -> Entity {
+        let entity = self.world.spawn_empty().id();
+        self.insert(entity, id);
+        entity
+    }
+
+    /// Spawn the prototype with the given [ID] using the given [parameters],
+    /// returning the spawned entity.
+    ///
+    /// See [`ProtoCommands::spawn_with_params`] for details.
+    ///
+    /// [ID]: Prototypical::id
+    /// [parameters]: ProtoParams
+    /// [`ProtoCommands::spawn_with_params`]: crate::proto::ProtoCommands::spawn_with_params
+    pub fn spawn_with_params<I: Into<T::Id>>(&mut self, id: I, params: ProtoParams) -> Entity {
+        let entity = self.world.spawn_empty().id();
+        ProtoInsertCommand::<T, C>::new(id.into(), Some(entity))
+            .with_params(params)
+            .apply(self.world);
+        entity
+    }
+
+    /// Returns the live root entities spawned from the prototype with the given [ID].
+    ///
+    /// The entities are returned in the order they were spawned (or the order the prototype
+    /// was first inserted onto them), so the `n`th element is the `n`th oldest instance
+    /// that still exists.
+    /// Despawned instances are excluded, as are entities spawned as children of another prototype.
+    ///
+    /// Returns an empty list if the prototype is not registered.
+    /// Note that this queries all [`ProtoInstance`] entities,
+    /// so it should be avoided in performance-sensitive code.
+    ///
+    /// [ID]: Prototypical::id
+    /// [`ProtoInstance`]: crate::proto::ProtoInstance
+    pub fn instances_of<I: Into<T::Id>>(&mut self, id: I) -> Vec<Entity> {
+        let handle = self
+            .world
+            .get_resource::<ProtoRegistry<T, C>>()
+            .and_then(|registry| registry.get_tree_by_id(&id.into()))
+            .map(|tree| tree.handle());
+
+        match handle {
+            Some(handle) => find_root_instances(self.world, handle),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the `index`th live root entity spawned from the prototype with the given [ID].
+    ///
+    /// See [`instances_of`] for details on ordering.
+    ///
+    /// [ID]: Prototypical::id
+    /// [`instances_of`]: Self::instances_of
+    pub fn entity_from_instance<I: Into<T::Id>>(&mut self, id: I, index: usize) -> Option<Entity> {
+        self.instances_of(id).get(index).copied()
+    }
+
+    /// Insert the prototype with the given [ID] onto the given entity.
+    ///
+    /// [ID]: Prototypical::id
+    pub fn insert<I: Into<T::Id>>(&mut self, entity: Entity, id: I) -> &mut Self {
+        ProtoInsertCommand::<T, C>::new(id.into(), Some(entity)).apply(self.world);
+        self
+    }
+
+    /// Remove the prototype with the given [ID] from the given entity.
+    ///
+    /// [ID]: Prototypical::id
+    pub fn remove_from<I: Into<T::Id>>(&mut self, entity: Entity, id: I) -> &mut Self {
+        ProtoRemoveCommand::<T, C>::new(id.into(), Some(entity)).apply(self.world);
+        self
+    }
+
+    /// Apply the prototype with the given [ID] to the world.
+    ///
+    /// This should only be called on prototypes that do not [require an entity].
+    ///
+    /// [ID]: Prototypical::id
+    /// [require an entity]: Prototypical::requires_entity
+    pub fn apply<I: Into<T::Id>>(&mut self, id: I) -> &mut Self {
+        ProtoInsertCommand::<T, C>::new(id.into(), None).apply(self.world);
+        self
+    }
+
+    /// Remove the prototype with the given [ID] from the world.
+    ///
+    /// This should only be called on prototypes that do not [require an entity].
+    ///
+    /// [ID]: Prototypical::id
+    /// [require an entity]: Prototypical::requires_entity
+    pub fn remove<I: Into<T::Id>>(&mut self, id: I) -> &mut Self {
+        ProtoRemoveCommand::<T, C>::new(id.into(), None).apply(self.world);
+        self
+    }
+
+    /// Returns the underlying [`World`].
+    pub fn world(&mut self) -> &mut World {
+        self.world
+    }
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::{warn, EventReader, Local, Res};
+
+use crate::assets::ProtoAssetEvent;
+use crate::proto::{Config, Prototypical};
+use crate::registration::ProtoRegistry;
+
+/// Reports any unused prototypes once all queued prototypes have finished loading.
+///
+/// A new report is made whenever prototypes have been registered since the last one,
+/// even if they finished loading before the load queue could be observed as non-empty.
+///
+/// A prototype is considered unused if it is not used as a template or child by
+/// any other prototype and has not yet been applied.
+/// Prototypes that are only meant to be spawned later on will also be reported,
+/// so this should be treated as a hint rather than an error.
+pub(crate) fn report_unused_prototypes<T: Prototypical, C: Config<T>>(
+    registry: Res<ProtoRegistry<T, C>>,
+    mut events: EventReader<ProtoAssetEvent<T>>,
+    mut has_registered: Local<bool>,
+) {
+    if events
+        .iter()
+        .any(|event| matches!(event, ProtoAssetEvent::Created { .. }))
+    {
+        *has_registered = true;
+    }
+
+    if !*has_registered || !registry.load_queue().read().is_empty() {
+        return;
+    }
+
+    *has_registered = false;
+
+    let mut unused = registry
+        .unused()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    if unused.is_empty() {
+        return;
+    }
+
+    unused.sort();
+
+    warn!(
+        "found {} prototype(s) that are never used as a template or child and have not been spawned: {}",
+        unused.len(),
+        unused.join(", ")
+    );
+}
@@ -1,7 +1,11 @@
+#[cfg(feature = "analysis")]
+pub(crate) use analysis::*;
 pub(crate) use manager::*;
 pub(crate) use registry::*;
 pub(crate) use systems::*;
 
+#[cfg(feature = "analysis")]
+mod analysis;
 mod manager;
 mod params;
 mod registry;
@@ -1,12 +1,18 @@
-use crate::assets::ProtoAssetEvent;
+use crate::assets::{ProtoAssetCollections, ProtoAssetEvent};
 use crate::proto::{Config, ProtoError, Prototypical};
+use crate::templates::ProtoMixins;
 use bevy::asset::{Assets, Handle, HandleId};
 use bevy::ecs::system::SystemParam;
-use bevy::prelude::{EventWriter, Res, ResMut};
+use bevy::prelude::{AppTypeRegistry, AssetServer, EventWriter, Res, ResMut};
+use std::path::{Path, PathBuf};
 
 #[derive(SystemParam)]
 pub(super) struct RegistryParams<'w, T: Prototypical, C: Config<T>> {
     prototypes: Res<'w, Assets<T>>,
+    asset_server: Res<'w, AssetServer>,
+    type_registry: Res<'w, AppTypeRegistry>,
+    mixins: Res<'w, ProtoMixins>,
+    collections: ResMut<'w, ProtoAssetCollections>,
     config: ResMut<'w, C>,
     proto_events: EventWriter<'w, ProtoAssetEvent<T>>,
 }
@@ -16,6 +22,15 @@ impl<'w, T: Prototypical, C: Config<T>> RegistryParams<'w, T, C> {
         Res::clone(&self.prototypes).into_inner()
     }
 
+    pub fn mixins(&self) -> &ProtoMixins {
+        &self.mixins
+    }
+
+    /// Caches the sorted contents of an asset collection folder resolved by the loader.
+    pub fn insert_collection(&mut self, path: &Path, paths: &[PathBuf]) {
+        self.collections.insert(path.to_path_buf(), paths.to_vec());
+    }
+
     pub fn config(&self) -> &C {
         &self.config
     }
@@ -34,6 +49,21 @@ impl<'w, T: Prototypical, C: Config<T>> RegistryParams<'w, T, C> {
         self.prototypes().get_handle(handle)
     }
 
+    pub fn asset_server(&self) -> &AssetServer {
+        &self.asset_server
+    }
+
+    pub fn type_registry(&self) -> &AppTypeRegistry {
+        &self.type_registry
+    }
+
+    /// Returns the path of the file the given prototype was loaded from, if any.
+    pub fn get_source_path<H: Into<HandleId>>(&self, handle: H) -> Option<PathBuf> {
+        self.asset_server
+            .get_handle_path(handle)
+            .map(|path| path.path().to_path_buf())
+    }
+
     pub fn send_event(&mut self, event: ProtoAssetEvent<T>) {
         self.proto_events.send(event);
     }
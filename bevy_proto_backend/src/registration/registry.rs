@@ -1,17 +1,22 @@
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
+use std::collections::VecDeque;
 use std::hash::Hash;
 use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::registration::params::RegistryParams;
-use bevy::asset::{Handle, HandleId};
-use bevy::prelude::Resource;
+use bevy::asset::{AssetServer, Handle, HandleId, HandleUntyped, LoadState};
+use bevy::prelude::{error, Resource};
 
 use crate::assets::ProtoAssetEvent;
 use bevy::utils::{HashMap, HashSet};
 use parking_lot::RwLock;
 
+use crate::assets::collect_lazy_asset_paths;
 use crate::proto::{Config, ProtoError, Prototypical};
+use crate::schematics::DynamicSchematic;
+use crate::templates::ProtoMixins;
 use crate::tree::{ProtoTree, ProtoTreeBuilder};
 
 /// Resource used to track load states, store mappings, and generate cached data.
@@ -20,16 +25,40 @@ pub(crate) struct ProtoRegistry<T: Prototypical, C: Config<T>> {
     ids: HashMap<HandleId, T::Id>,
     handles: HashMap<T::Id, Handle<T>>,
     trees: HashMap<HandleId, ProtoTree<T>>,
+    /// The names of the [mixins] included by each prototype that were found
+    /// when its tree was built, in application order.
+    ///
+    /// [mixins]: crate::templates::ProtoMixins
+    mixins: HashMap<HandleId, Vec<String>>,
+    /// The paths of the files each prototype was loaded from.
+    ///
+    /// Prototypes that were not loaded from a file (i.e. added directly to their `Assets`)
+    /// will not have an entry.
+    source_paths: HashMap<T::Id, PathBuf>,
     /// This contains a mapping of a prototype to a set of prototypes that
     /// depend on it.
     ///
     /// We can use this information to perform selective re-computations
     /// when the prototype is modified.
     dependents: HashMap<HandleId, HashSet<HandleId>>,
+    /// A mapping of [default template] IDs that were not yet registered
+    /// to the prototypes that were built without them.
+    ///
+    /// These prototypes are rebuilt once the default template is registered.
+    ///
+    /// [default template]: Config::default_templates
+    awaiting_default_templates: HashMap<T::Id, HashSet<HandleId>>,
     /// Tracks the prototypes currently being loaded.
     load_queue: Arc<RwLock<LoadQueue<T>>>,
-    /// Set of prototypes that failed to be registered.
-    failed: HashSet<HandleId>,
+    /// The prototypes that failed to be registered, along with the reason why.
+    failed: HashMap<HandleId, String>,
+    /// Strong handles to the lazy assets of each prototype.
+    ///
+    /// This is only populated when [`Config::strict_readiness`] is enabled.
+    lazy_assets: HashMap<HandleId, Vec<HandleUntyped>>,
+    /// Set of prototypes that have been applied at least once.
+    #[cfg(feature = "analysis")]
+    applied: HashSet<HandleId>,
     _phantom: PhantomData<C>,
 }
 
@@ -42,7 +71,16 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
         handle: &Handle<T>,
         params: &mut RegistryParams<'w, T, C>,
     ) -> Result<&'w T, ProtoError> {
-        let prototype = self.register_internal(handle, params, false)?;
+        let prototype = match self.register_internal(handle, params, false) {
+            Ok(prototype) => prototype,
+            Err(err) => {
+                // A handle that is already registered remains valid
+                if !self.ids.contains_key(&handle.id()) {
+                    self.failed.insert(handle.id(), err.to_string());
+                }
+                return Err(err);
+            }
+        };
 
         params
             .config_mut()
@@ -53,9 +91,37 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
             id: prototype.id().clone(),
         });
 
+        self.rebuild_awaiting(prototype.id(), params);
+
         Ok(prototype)
     }
 
+    /// Rebuilds the prototypes that were built before the [default template]
+    /// with the given ID was registered.
+    ///
+    /// [default template]: Config::default_templates
+    fn rebuild_awaiting(&mut self, id: &T::Id, params: &mut RegistryParams<T, C>) {
+        let Some(awaiting) = self.awaiting_default_templates.remove(id) else {
+            return;
+        };
+
+        for handle_id in awaiting {
+            if self.contains_handle(handle_id) {
+                if let Err(err) = self.reload(&Handle::weak(handle_id), params) {
+                    error!(
+                        "could not apply default template {:?} to prototype: {}",
+                        id, err
+                    );
+                }
+            } else {
+                // Not registered (yet), so only its cached tree needs to be rebuilt
+                let dependents = self.invalidate_dependents(handle_id);
+                self.trees.remove(&handle_id);
+                self.reload_dependents(dependents, params);
+            }
+        }
+    }
+
     /// Removes a prototype from the registry.
     ///
     /// Returns the ID of the prototype if it was registered.
@@ -64,7 +130,17 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
         handle: &Handle<T>,
         params: &mut RegistryParams<T, C>,
     ) -> Option<T::Id> {
-        let id = self.unregister_internal(handle, params)?;
+        // Forget the content hash so that the same file can be loaded again later
+        if let Some(path) = self
+            .ids
+            .get(&handle.id())
+            .and_then(|id| self.source_paths.get(id))
+        {
+            self.load_queue.write().remove_content_hash(path);
+        }
+
+        let id = self.unregister_internal(handle)?;
+        let dependents = self.invalidate_dependents(handle.id());
 
         let strong_handle = params.get_strong_handle(handle);
         params
@@ -76,18 +152,35 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
             id: id.clone(),
         });
 
+        self.reload_dependents(dependents, params);
+
         Some(id)
     }
 
     /// Reload a registered prototype.
     ///
+    /// Every prototype that depends on this one (either as a template or as a child),
+    /// directly or transitively, will be reloaded as well.
+    ///
     /// This will return an error if the prototype is not registered.
     pub(super) fn reload<'w>(
         &mut self,
         handle: &Handle<T>,
         params: &mut RegistryParams<'w, T, C>,
     ) -> Result<&'w T, ProtoError> {
-        if self.unregister_internal(handle, params).is_some() {
+        let dependents = self.invalidate_dependents(handle.id());
+        let result = self.reload_single(handle, params);
+        self.reload_dependents(dependents, params);
+        result
+    }
+
+    /// Reload a single registered prototype without reloading its dependents.
+    fn reload_single<'w>(
+        &mut self,
+        handle: &Handle<T>,
+        params: &mut RegistryParams<'w, T, C>,
+    ) -> Result<&'w T, ProtoError> {
+        if self.unregister_internal(handle).is_some() {
             let prototype = self.register_internal(handle, params, true)?;
             let strong_handle = params.get_strong_handle(handle);
             params
@@ -103,6 +196,51 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
         }
     }
 
+    /// Collects every prototype that depends on the given one, directly or transitively,
+    /// and removes their cached trees.
+    ///
+    /// The returned list is ordered such that direct dependents come before
+    /// indirect ones and contains no duplicates.
+    fn invalidate_dependents(&mut self, handle_id: HandleId) -> Vec<HandleId> {
+        let mut visited = HashSet::new();
+        let mut ordered = Vec::new();
+        let mut queue = VecDeque::from([handle_id]);
+
+        visited.insert(handle_id);
+
+        while let Some(current) = queue.pop_front() {
+            // Dependents will re-register themselves when their trees are rebuilt
+            let Some(dependents) = self.dependents.remove(&current) else {
+                continue;
+            };
+
+            for dependent in dependents {
+                if visited.insert(dependent) {
+                    self.trees.remove(&dependent);
+                    ordered.push(dependent);
+                    queue.push_back(dependent);
+                }
+            }
+        }
+
+        ordered
+    }
+
+    /// Reloads the given dependents (as returned by [`invalidate_dependents`]).
+    ///
+    /// [`invalidate_dependents`]: Self::invalidate_dependents
+    fn reload_dependents(&mut self, dependents: Vec<HandleId>, params: &mut RegistryParams<T, C>) {
+        for dependent in dependents {
+            let dependent_handle = Handle::weak(dependent);
+            // This will return an error when a dependent is missing.
+            // We allow it here because there are times when we expect a dependent to be missing.
+            // For example, if a parent prototype is dropped then its child might be dropped as well.
+            // If this happens, the child will unregister after the non-existent parent.
+            // In the future, we can add better diffing strategies to reduce unnecessary unregistrations.
+            self.reload_single(&dependent_handle, params).ok();
+        }
+    }
+
     pub fn contains<I: Hash + Eq + ?Sized>(&self, id: &I) -> bool
     where
         T::Id: Borrow<I>,
@@ -110,12 +248,21 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
         self.handles.contains_key(id)
     }
 
+    pub fn get_id<H: Into<HandleId>>(&self, handle: H) -> Option<&T::Id> {
+        self.ids.get(&handle.into())
+    }
+
     pub fn contains_handle<H: Into<HandleId>>(&self, handle: H) -> bool {
         self.ids.contains_key(&handle.into())
     }
 
     pub fn contains_failed_handle<H: Into<HandleId>>(&self, handle: H) -> bool {
-        self.failed.contains(&handle.into())
+        self.failed.contains_key(&handle.into())
+    }
+
+    /// Returns the reason the prototype with the given handle failed to register, if any.
+    pub fn get_failure<H: Into<HandleId>>(&self, handle: H) -> Option<&str> {
+        self.failed.get(&handle.into()).map(String::as_str)
     }
 
     pub fn add_dependent<H: Into<HandleId>>(&mut self, dependent: H, dependency: H) {
@@ -131,6 +278,47 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
         self.trees.insert(handle.into(), tree)
     }
 
+    pub fn insert_mixins<H: Into<HandleId>>(&mut self, handle: H, mixins: Vec<String>) {
+        self.mixins.insert(handle.into(), mixins);
+    }
+
+    /// Returns the names of the resolved mixins for the given prototype, in application order.
+    pub fn get_mixins<H: Into<HandleId>>(&self, handle: H) -> &[String] {
+        self.mixins
+            .get(&handle.into())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns an iterator over the schematics of the resolved mixins for the given prototype,
+    /// in application order.
+    pub fn iter_mixin_schematics<'a, H: Into<HandleId>>(
+        &'a self,
+        handle: H,
+        mixins: &'a ProtoMixins,
+    ) -> impl Iterator<Item = (&'a Cow<'static, str>, &'a DynamicSchematic)> + 'a {
+        self.get_mixins(handle)
+            .iter()
+            .filter_map(|name| mixins.get(name))
+            .flat_map(|mixin| mixin.iter())
+    }
+
+    /// Returns the handle of the registered prototype with the given ID, if any.
+    pub fn get_handle(&self, id: &T::Id) -> Option<&Handle<T>> {
+        self.handles.get(id)
+    }
+
+    /// Marks the given prototype as having been built without the [default template]
+    /// with the given ID, so that it can be rebuilt once that template is registered.
+    ///
+    /// [default template]: Config::default_templates
+    pub fn await_default_template<H: Into<HandleId>>(&mut self, id: T::Id, dependent: H) {
+        self.awaiting_default_templates
+            .entry(id)
+            .or_default()
+            .insert(dependent.into());
+    }
+
     pub fn get_tree<H: Into<HandleId>>(&self, handle: H) -> Option<&ProtoTree<T>> {
         self.trees.get(&handle.into())
     }
@@ -141,6 +329,102 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
             .and_then(|handle| self.get_tree(handle))
     }
 
+    pub fn get_tree_by_key<I: Hash + Eq + ?Sized>(&self, id: &I) -> Option<&ProtoTree<T>>
+    where
+        T::Id: Borrow<I>,
+    {
+        self.handles
+            .get(id)
+            .and_then(|handle| self.get_tree(handle))
+    }
+
+    pub fn get_source_path<I: Hash + Eq + ?Sized>(&self, id: &I) -> Option<&Path>
+    where
+        T::Id: Borrow<I>,
+    {
+        self.source_paths.get(id).map(PathBuf::as_path)
+    }
+
+    /// Marks the prototype with the given ID as having been applied.
+    #[cfg(feature = "analysis")]
+    pub fn mark_applied<I: Hash + Eq + ?Sized>(&mut self, id: &I)
+    where
+        T::Id: Borrow<I>,
+    {
+        if let Some(handle) = self.handles.get(id) {
+            self.applied.insert(handle.id());
+        }
+    }
+
+    /// Returns the IDs of all registered prototypes that are not used as a template
+    /// or child by any other prototype and have never been applied.
+    #[cfg(feature = "analysis")]
+    pub fn unused(&self) -> impl Iterator<Item = &T::Id> {
+        self.ids.iter().filter_map(|(handle_id, id)| {
+            let is_referenced = self
+                .dependents
+                .get(handle_id)
+                .map(|dependents| !dependents.is_empty())
+                .unwrap_or_default();
+
+            if is_referenced || self.applied.contains(handle_id) {
+                None
+            } else {
+                Some(id)
+            }
+        })
+    }
+
+    /// Returns true if the lazy assets of the prototype with the given ID, along with those of
+    /// all its templates and descendants, have finished loading.
+    ///
+    /// This is always true for registered prototypes if [`Config::strict_readiness`] is disabled.
+    pub fn is_lazy_loaded<I: Hash + Eq + ?Sized>(&self, id: &I, asset_server: &AssetServer) -> bool
+    where
+        T::Id: Borrow<I>,
+    {
+        self.get_tree_by_key(id)
+            .map(|tree| self.is_tree_lazy_loaded(tree, asset_server))
+            .unwrap_or_default()
+    }
+
+    /// Returns true if the lazy assets of the prototype with the given handle, along with those of
+    /// all its templates and descendants, have finished loading.
+    ///
+    /// This is always true for registered prototypes if [`Config::strict_readiness`] is disabled.
+    pub fn is_lazy_loaded_handle<H: Into<HandleId>>(
+        &self,
+        handle: H,
+        asset_server: &AssetServer,
+    ) -> bool {
+        self.get_tree(handle)
+            .map(|tree| self.is_tree_lazy_loaded(tree, asset_server))
+            .unwrap_or_default()
+    }
+
+    fn is_tree_lazy_loaded(&self, tree: &ProtoTree<T>, asset_server: &AssetServer) -> bool {
+        let is_loaded = tree.prototypes().iter().all(|handle_id| {
+            self.lazy_assets
+                .get(handle_id)
+                .map(|handles| {
+                    asset_server.get_group_load_state(handles.iter().map(HandleUntyped::id))
+                        == LoadState::Loaded
+                })
+                .unwrap_or(true)
+        });
+
+        is_loaded
+            && tree
+                .children()
+                .iter()
+                .all(|child| self.is_tree_lazy_loaded(child, asset_server))
+    }
+
+    /// Returns an iterator over the handle IDs and IDs of all registered prototypes.
+    pub fn iter(&self) -> impl Iterator<Item = (&HandleId, &T::Id)> {
+        self.ids.iter()
+    }
+
     pub fn load_queue(&self) -> &Arc<RwLock<LoadQueue<T>>> {
         &self.load_queue
     }
@@ -166,7 +450,6 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
 
             // Check if ID already exists
             if let Some(existing_handle) = self.handles.get(prototype.id()) {
-                self.failed.insert(handle.id());
                 if existing_handle.id() != handle.id() {
                     // Not the same asset!
                     let exiting_prototype = params.get_prototype(&handle)?;
@@ -179,41 +462,52 @@ impl<T: Prototypical, C: Config<T>> ProtoRegistry<T, C> {
             }
         }
 
-        ProtoTreeBuilder::new(self, params.prototypes(), params.config()).build(&handle)?;
+        ProtoTreeBuilder::new(self, params.prototypes(), params.mixins(), params.config())
+            .build(&handle)?;
 
         self.ids.insert(handle.id(), prototype.id().clone());
+        if let Some(path) = params.get_source_path(handle.id()) {
+            self.source_paths.insert(prototype.id().clone(), path);
+        }
         self.handles
             .insert(prototype.id().clone(), handle.clone_weak());
         self.failed.remove(&handle.id());
 
+        // Preloaded collections were already resolved by the loader
+        for (path, paths) in prototype.dependencies().collections() {
+            params.insert_collection(path, paths);
+        }
+
+        if params.config().strict_readiness() {
+            let mut paths = Vec::new();
+            let type_registry = params.type_registry().read();
+            for (_, schematic) in prototype.schematics().iter() {
+                collect_lazy_asset_paths(schematic.input(), &type_registry, &mut paths);
+            }
+
+            let lazy_assets = paths
+                .into_iter()
+                .map(|path| params.asset_server().load_untyped(path))
+                .collect();
+            self.lazy_assets.insert(handle.id(), lazy_assets);
+        }
+
         // Complete load
         self.load_queue().write().deque(prototype.id());
 
         Ok(prototype)
     }
 
-    fn unregister_internal(
-        &mut self,
-        handle: &Handle<T>,
-        params: &mut RegistryParams<T, C>,
-    ) -> Option<T::Id> {
+    fn unregister_internal(&mut self, handle: &Handle<T>) -> Option<T::Id> {
         let handle_id = handle.id();
 
         let id = self.ids.remove(&handle_id)?;
         self.handles.remove(&id);
+        self.source_paths.remove(&id);
         self.failed.remove(&handle_id);
         self.trees.remove(&handle_id);
-        if let Some(dependents) = self.dependents.remove(&handle_id) {
-            for dependent in dependents {
-                let dependent_handle = Handle::weak(dependent);
-                // This will return an error when a dependent is missing.
-                // We allow it here because there are times when we expect a dependent to be missing.
-                // For example, if a parent prototype is dropped then its child might be dropped as well.
-                // If this happens, the child will unregister after the non-existent parent.
-                // In the future, we can add better diffing strategies to reduce unnecessary unregistrations.
-                self.reload(&dependent_handle, params).ok();
-            }
-        }
+        self.mixins.remove(&handle_id);
+        self.lazy_assets.remove(&handle_id);
 
         Some(id)
     }
@@ -225,9 +519,15 @@ impl<T: Prototypical, C: Config<T>> Default for ProtoRegistry<T, C> {
             ids: HashMap::new(),
             handles: HashMap::new(),
             trees: HashMap::new(),
+            mixins: HashMap::new(),
+            source_paths: HashMap::new(),
             dependents: HashMap::new(),
+            awaiting_default_templates: HashMap::new(),
             load_queue: Default::default(),
-            failed: HashSet::new(),
+            failed: HashMap::new(),
+            lazy_assets: HashMap::new(),
+            #[cfg(feature = "analysis")]
+            applied: HashSet::new(),
             _phantom: PhantomData,
         }
     }
@@ -236,6 +536,10 @@ impl<T: Prototypical, C: Config<T>> Default for ProtoRegistry<T, C> {
 pub(crate) struct LoadQueue<T: Prototypical> {
     handles: HashMap<T::Id, Handle<T>>,
     ids: HashMap<HandleId, T::Id>,
+    /// The hash of the contents of each prototype file when it was last loaded.
+    ///
+    /// This is used to skip reloading files that were modified without changing their contents.
+    content_hashes: HashMap<PathBuf, u64>,
 }
 
 impl<T: Prototypical> LoadQueue<T> {
@@ -261,6 +565,23 @@ impl<T: Prototypical> LoadQueue<T> {
     pub fn is_queued_handle<I: Borrow<HandleId>>(&self, id: I) -> bool {
         self.ids.contains_key(id.borrow())
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+
+    /// Returns true if the file at the given path was last loaded with the given content hash.
+    pub fn is_unchanged<P: AsRef<Path>>(&self, path: P, hash: u64) -> bool {
+        self.content_hashes.get(path.as_ref()) == Some(&hash)
+    }
+
+    pub fn set_content_hash<P: Into<PathBuf>>(&mut self, path: P, hash: u64) {
+        self.content_hashes.insert(path.into(), hash);
+    }
+
+    pub fn remove_content_hash<P: AsRef<Path>>(&mut self, path: P) -> Option<u64> {
+        self.content_hashes.remove(path.as_ref())
+    }
 }
 
 impl<T: Prototypical> Clone for LoadQueue<T> {
@@ -268,6 +589,7 @@ impl<T: Prototypical> Clone for LoadQueue<T> {
         Self {
             handles: self.handles.clone(),
             ids: self.ids.clone(),
+            content_hashes: self.content_hashes.clone(),
         }
     }
 }
@@ -277,6 +599,7 @@ impl<T: Prototypical> Default for LoadQueue<T> {
         Self {
             handles: Default::default(),
             ids: Default::default(),
+            content_hashes: Default::default(),
         }
     }
 }
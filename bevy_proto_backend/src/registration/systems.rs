@@ -1,8 +1,14 @@
-use bevy::asset::AssetEvent;
-use bevy::prelude::{error, EventReader};
+use bevy::asset::{AssetEvent, Assets};
+use bevy::ecs::event::ManualEventReader;
+use bevy::prelude::{error, Entity, EventReader, Events, Local, Mut, World};
+use bevy::utils::HashSet;
 
-use crate::proto::{Config, Prototypical};
-use crate::registration::ProtoManager;
+use crate::assets::ProtoAssetEvent;
+use crate::proto::{Config, ProtoInstance, Prototypical};
+use crate::registration::{ProtoManager, ProtoRegistry};
+use crate::schematics::{InsertMode, SchematicContext, SchematicId};
+use crate::templates::ProtoMixins;
+use crate::tree::EntityTree;
 
 /// Handles the registration of loaded, modified, and removed prototypes.
 pub(crate) fn on_proto_asset_event<T: Prototypical, C: Config<T>>(
@@ -27,3 +33,179 @@ pub(crate) fn on_proto_asset_event<T: Prototypical, C: Config<T>>(
         }
     }
 }
+
+/// Calls [`Config::on_prototype_ready`] for every prototype that was registered or reloaded.
+pub(crate) fn on_prototypes_ready<T: Prototypical, C: Config<T>>(
+    world: &mut World,
+    mut reader: Local<ManualEventReader<ProtoAssetEvent<T>>>,
+) {
+    let ready = reader
+        .iter(world.resource::<Events<ProtoAssetEvent<T>>>())
+        .filter_map(|event| match event {
+            ProtoAssetEvent::Created { handle, .. } | ProtoAssetEvent::Modified { handle, .. } => {
+                Some(handle.clone_weak())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if ready.is_empty() {
+        return;
+    }
+
+    world.resource_scope(|world, prototypes: Mut<Assets<T>>| {
+        world.resource_scope(|world, mut config: Mut<C>| {
+            for handle in ready {
+                if let Some(prototype) = prototypes.get(&handle) {
+                    config.on_prototype_ready(prototype, world);
+                }
+            }
+        });
+    });
+}
+
+/// Re-resolves the reloadable assets of entities whose prototype was modified.
+///
+/// Entities whose [`ProtoInstance`] contains the modified prototype are updated,
+/// along with any existing child entities spawned from that prototype's children.
+///
+/// See [`Schematic::reload`] for details.
+///
+/// [`Schematic::reload`]: crate::schematics::Schematic::reload
+pub(crate) fn reload_proto_assets<T: Prototypical, C: Config<T>>(
+    world: &mut World,
+    mut reader: Local<ManualEventReader<ProtoAssetEvent<T>>>,
+) {
+    let modified = reader
+        .iter(world.resource::<Events<ProtoAssetEvent<T>>>())
+        .filter_map(|event| match event {
+            ProtoAssetEvent::Modified { handle, .. } => Some(handle.id()),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    if modified.is_empty() {
+        return;
+    }
+
+    let weak_lazy_assets = world.resource::<C>().weak_lazy_assets();
+    let order = world.resource::<C>().apply_order();
+    let instances = world
+        .query::<(Entity, &ProtoInstance)>()
+        .iter(world)
+        .flat_map(|(entity, instance)| {
+            instance
+                .handles()
+                .iter()
+                .filter(|handle| modified.contains(*handle))
+                .map(move |handle| (entity, *handle))
+        })
+        .collect::<Vec<_>>();
+
+    // Child entities are reloaded along with their parent,
+    // so track which ones were already visited to avoid reloading them twice
+    let mut reloaded = HashSet::new();
+
+    world.resource_scope(|world, registry: Mut<ProtoRegistry<T, C>>| {
+        world.resource_scope(|world, prototypes: Mut<Assets<T>>| {
+            world.resource_scope(|world, mixins: Mut<ProtoMixins>| {
+                for (root, handle) in instances {
+                    let Some(tree) = registry.get_tree(handle) else {
+                        continue;
+                    };
+
+                    let entity_tree = EntityTree::existing(tree, Some(root), world);
+
+                    for node in entity_tree.iter(order) {
+                        let Some(entity) = node.entity() else {
+                            continue;
+                        };
+                        let Some(node_handle) = node.prototypes().last() else {
+                            continue;
+                        };
+                        if !reloaded.insert((entity, *node_handle)) {
+                            continue;
+                        }
+
+                        entity_tree.set_current(node);
+                        let mut context = SchematicContext::new(world, &entity_tree)
+                            .with_weak_lazy_assets(weak_lazy_assets);
+
+                        // Templates first, matching the order in which they were applied
+                        for handle_id in node.prototypes() {
+                            let Some(proto) = prototypes.get(&prototypes.get_handle(*handle_id))
+                            else {
+                                continue;
+                            };
+
+                            let mixin_schematics =
+                                registry.iter_mixin_schematics(*handle_id, &mixins);
+
+                            for (_, schematic) in mixin_schematics.chain(proto.schematics().iter())
+                            {
+                                let id =
+                                    SchematicId::new(*handle_id, schematic.type_info().type_id());
+
+                                // Don't overwrite components that existed before the prototype was applied
+                                if schematic.insert_mode() == InsertMode::KeepExisting
+                                    && !context
+                                        .world()
+                                        .get::<ProtoInstance>(entity)
+                                        .map(|instance| instance.owns(&id))
+                                        .unwrap_or_default()
+                                {
+                                    continue;
+                                }
+
+                                if let Err(err) = schematic.reload(id, &mut context) {
+                                    error!(
+                                        "could not reload schematic for prototype {:?}: {}",
+                                        proto.id(),
+                                        err
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            });
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::Assets;
+
+    use crate::test_utils::{self, TestConfig, TestProto};
+
+    fn ready(app: &bevy::app::App) -> &[String] {
+        &app.world.resource::<TestConfig>().ready
+    }
+
+    #[test]
+    fn should_call_ready_hook_once_per_load_and_reload() {
+        let mut app = test_utils::app();
+        let handle = test_utils::add(&mut app, TestProto::new("A"));
+        test_utils::register(&mut app);
+        assert_eq!(&[String::from("A")], ready(&app));
+
+        // Spawning should not trigger the hook
+        for _ in 0..2 {
+            test_utils::run_commands(&mut app, |commands| {
+                commands.spawn("A");
+            });
+        }
+        app.update();
+        assert_eq!(&[String::from("A")], ready(&app));
+
+        // Modifying the asset triggers a reload
+        app.world
+            .resource_mut::<Assets<TestProto>>()
+            .get_mut(&handle)
+            .unwrap();
+        test_utils::register(&mut app);
+        app.update();
+        assert_eq!(&[String::from("A"), String::from("A")], ready(&app));
+    }
+}
@@ -111,6 +111,19 @@ impl Schematics {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Returns true if both collections contain the same schematics
+    /// with structurally equal values.
+    ///
+    /// See [`DynamicSchematic::semantic_eq`] for details.
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, schematic)| {
+                other
+                    .get_by_name(key)
+                    .is_some_and(|other| schematic.semantic_eq(other))
+            })
+    }
 }
 
 impl Debug for Schematics {
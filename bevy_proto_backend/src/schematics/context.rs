@@ -1,4 +1,6 @@
+use crate::schematics::ProtoParams;
 use crate::tree::{EntityAccess, EntityTree};
+use bevy::asset::{Asset, Assets, Handle};
 use bevy::ecs::world::{EntityMut, EntityRef};
 use bevy::prelude::{Entity, World};
 
@@ -6,11 +8,28 @@ use bevy::prelude::{Entity, World};
 pub struct SchematicContext<'a, 'b> {
     world: &'a mut World,
     tree: &'a EntityTree<'b>,
+    params: Option<&'a ProtoParams>,
+    weak_lazy_assets: bool,
 }
 
 impl<'a, 'b> SchematicContext<'a, 'b> {
     pub(crate) fn new(world: &'a mut World, tree: &'a EntityTree<'b>) -> Self {
-        Self { world, tree }
+        Self {
+            world,
+            tree,
+            params: None,
+            weak_lazy_assets: false,
+        }
+    }
+
+    pub(crate) fn with_params(mut self, params: Option<&'a ProtoParams>) -> Self {
+        self.params = params;
+        self
+    }
+
+    pub(crate) fn with_weak_lazy_assets(mut self, weak_lazy_assets: bool) -> Self {
+        self.weak_lazy_assets = weak_lazy_assets;
+        self
     }
 
     /// Returns a reference to the world.
@@ -44,4 +63,46 @@ impl<'a, 'b> SchematicContext<'a, 'b> {
     pub fn tree(&self) -> &EntityTree {
         self.tree
     }
+
+    /// Returns the [`ProtoParams`] the prototype was spawned with, if any.
+    ///
+    /// These are only available while the prototype is being applied.
+    /// See the [lifetime] section for details.
+    ///
+    /// [lifetime]: ProtoParams#lifetime
+    pub fn params(&self) -> Option<&ProtoParams> {
+        self.params
+    }
+
+    /// Returns true if lazy assets should be given weak handles.
+    ///
+    /// See [`Config::weak_lazy_assets`] for details.
+    ///
+    /// [`Config::weak_lazy_assets`]: crate::proto::Config::weak_lazy_assets
+    pub fn weak_lazy_assets(&self) -> bool {
+        self.weak_lazy_assets
+    }
+
+    /// Returns the handle that should be stored for a lazily-loaded asset,
+    /// given the strong handle returned when loading it.
+    ///
+    /// If [`weak_lazy_assets`] is enabled and the asset is already loaded,
+    /// this returns a weak handle.
+    /// Otherwise, the strong handle is returned as-is,
+    /// since dropping it would cancel the in-progress load.
+    ///
+    /// [`weak_lazy_assets`]: Self::weak_lazy_assets
+    pub fn lazy_asset_handle<T: Asset>(&self, handle: Handle<T>) -> Handle<T> {
+        let is_loaded = self
+            .world
+            .get_resource::<Assets<T>>()
+            .map(|assets| assets.contains(&handle))
+            .unwrap_or_default();
+
+        if self.weak_lazy_assets && is_loaded {
+            handle.clone_weak()
+        } else {
+            handle
+        }
+    }
 }
@@ -0,0 +1,397 @@
+use std::any::TypeId;
+use std::fmt::{Display, Formatter};
+
+use bevy::reflect::std_traits::ReflectDefault;
+use bevy::reflect::{ReflectRef, TypeInfo, TypeRegistry, VariantInfo};
+use serde::Serialize;
+
+use crate::assets::ReflectProtoAsset;
+use crate::schematics::ReflectSchematic;
+use crate::tree::ReflectProtoEntity;
+
+/// A description of the authorable shape of a [schematic].
+///
+/// This describes the fields of the schematic's [input type],
+/// which is what actually gets written in a prototype file.
+///
+/// This is generated by [`Prototypes::describe_schematic`] and can be serialized
+/// or converted to markdown (via [`SchematicDescription::to_markdown`])
+/// to generate authoring documentation.
+///
+/// [schematic]: crate::schematics::Schematic
+/// [input type]: crate::schematics::Schematic::Input
+/// [`Prototypes::describe_schematic`]: crate::proto::Prototypes::describe_schematic
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchematicDescription {
+    /// The type name of the schematic, as used in prototype files.
+    pub type_name: String,
+    /// The type name of the schematic's input type.
+    pub input_type_name: String,
+    /// Whether the schematic operates on an entity.
+    pub requires_entity: bool,
+    /// The shape of the input type.
+    pub shape: SchematicShape,
+}
+
+/// The shape of a [`SchematicDescription`]'s input type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum SchematicShape {
+    /// A struct or tuple struct.
+    ///
+    /// Tuple struct fields are named by their index.
+    Struct(Vec<SchematicFieldDescription>),
+    /// An enum with the given variants.
+    Enum(Vec<SchematicVariantDescription>),
+    /// Any other type, such as a primitive or a list.
+    Value,
+}
+
+/// A single variant of an enum [`SchematicShape`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchematicVariantDescription {
+    /// The name of the variant.
+    pub name: String,
+    /// The fields of the variant.
+    ///
+    /// Tuple variant fields are named by their index.
+    pub fields: Vec<SchematicFieldDescription>,
+}
+
+/// A single field of a [`SchematicDescription`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchematicFieldDescription {
+    /// The name of the field.
+    pub name: String,
+    /// The type name of the field.
+    pub type_name: String,
+    /// The debug representation of the field's default value, if the input type
+    /// is registered with [`ReflectDefault`].
+    ///
+    /// This is always `None` for enum fields.
+    pub default: Option<String>,
+    /// The kind of the field.
+    pub kind: SchematicFieldKind,
+}
+
+/// The kind of a [`SchematicFieldDescription`].
+///
+/// This is derived from the type data of the field's type,
+/// looking through lists, arrays, maps, and options.
+/// Types that aren't registered are always treated as [`SchematicFieldKind::Value`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize)]
+pub enum SchematicFieldKind {
+    /// A plain value.
+    Value,
+    /// A reference to an asset, such as a [`ProtoAsset`] or [`InlinableProtoAsset`].
+    ///
+    /// These are identified by their [`ReflectProtoAsset`] type data.
+    ///
+    /// [`ProtoAsset`]: crate::assets::ProtoAsset
+    /// [`InlinableProtoAsset`]: crate::assets::InlinableProtoAsset
+    Asset,
+    /// A reference to an entity, such as an [`EntityAccess`] or [`ProtoEntity`].
+    ///
+    /// These are identified by their [`ReflectProtoEntity`] type data.
+    ///
+    /// [`EntityAccess`]: crate::tree::EntityAccess
+    /// [`ProtoEntity`]: crate::tree::ProtoEntity
+    Entity,
+}
+
+impl SchematicFieldKind {
+    fn from_type_id(registry: &TypeRegistry, type_id: TypeId) -> Self {
+        let Some(registration) = registry.get(type_id) else {
+            return Self::Value;
+        };
+
+        if registration.data::<ReflectProtoAsset>().is_some() {
+            return Self::Asset;
+        }
+
+        if registration.data::<ReflectProtoEntity>().is_some() {
+            return Self::Entity;
+        }
+
+        match registration.type_info() {
+            TypeInfo::List(info) => Self::from_type_id(registry, info.item_type_id()),
+            TypeInfo::Array(info) => Self::from_type_id(registry, info.item_type_id()),
+            TypeInfo::Map(info) => Self::from_type_id(registry, info.value_type_id()),
+            // Options are detected by shape: a unit `None` and a single-field `Some`
+            TypeInfo::Enum(info) if info.variant_len() == 2 => {
+                match (info.variant("None"), info.variant("Some")) {
+                    (Some(VariantInfo::Unit(_)), Some(VariantInfo::Tuple(some)))
+                        if some.field_len() == 1 =>
+                    {
+                        some.field_at(0)
+                            .map(|field| Self::from_type_id(registry, field.type_id()))
+                            .unwrap_or(Self::Value)
+                    }
+                    _ => Self::Value,
+                }
+            }
+            _ => Self::Value,
+        }
+    }
+}
+
+impl SchematicDescription {
+    /// Describe the schematic with the given type name using the given registry.
+    ///
+    /// Returns `None` if the type is not registered with [`ReflectSchematic`].
+    pub fn new(registry: &TypeRegistry, type_name: &str) -> Option<Self> {
+        let registration = registry.get_with_name(type_name)?;
+        let reflect_schematic = registration.data::<ReflectSchematic>()?;
+
+        let input_registration = reflect_schematic.input_registration();
+        let default = input_registration
+            .data::<ReflectDefault>()
+            .map(|reflect_default| reflect_default.default());
+        let default_field = |name: &str| {
+            let default = default.as_deref()?;
+            let field = match default.reflect_ref() {
+                ReflectRef::Struct(value) => value.field(name),
+                ReflectRef::TupleStruct(value) => value.field(name.parse().ok()?),
+                _ => None,
+            };
+            field.map(|field| format!("{:?}", field))
+        };
+
+        let shape = match input_registration.type_info() {
+            TypeInfo::Struct(info) => SchematicShape::Struct(
+                info.iter()
+                    .map(|field| {
+                        SchematicFieldDescription::new(
+                            registry,
+                            field.name(),
+                            field.type_name(),
+                            field.type_id(),
+                            default_field(field.name()),
+                        )
+                    })
+                    .collect(),
+            ),
+            TypeInfo::TupleStruct(info) => SchematicShape::Struct(
+                info.iter()
+                    .map(|field| {
+                        let name = field.index().to_string();
+                        let default = default_field(&name);
+                        SchematicFieldDescription::new(
+                            registry,
+                            &name,
+                            field.type_name(),
+                            field.type_id(),
+                            default,
+                        )
+                    })
+                    .collect(),
+            ),
+            TypeInfo::Enum(info) => SchematicShape::Enum(
+                info.iter()
+                    .map(|variant| SchematicVariantDescription {
+                        name: variant.name().to_string(),
+                        fields: match variant {
+                            VariantInfo::Struct(info) => info
+                                .iter()
+                                .map(|field| {
+                                    SchematicFieldDescription::new(
+                                        registry,
+                                        field.name(),
+                                        field.type_name(),
+                                        field.type_id(),
+                                        None,
+                                    )
+                                })
+                                .collect(),
+                            VariantInfo::Tuple(info) => info
+                                .iter()
+                                .map(|field| {
+                                    SchematicFieldDescription::new(
+                                        registry,
+                                        &field.index().to_string(),
+                                        field.type_name(),
+                                        field.type_id(),
+                                        None,
+                                    )
+                                })
+                                .collect(),
+                            VariantInfo::Unit(_) => Vec::new(),
+                        },
+                    })
+                    .collect(),
+            ),
+            _ => SchematicShape::Value,
+        };
+
+        Some(Self {
+            type_name: registration.type_name().to_string(),
+            input_type_name: input_registration.type_name().to_string(),
+            requires_entity: reflect_schematic.requires_entity(),
+            shape,
+        })
+    }
+
+    /// Returns this description as markdown.
+    pub fn to_markdown(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl SchematicFieldDescription {
+    fn new(
+        registry: &TypeRegistry,
+        name: &str,
+        type_name: &str,
+        type_id: TypeId,
+        default: Option<String>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+            default,
+            kind: SchematicFieldKind::from_type_id(registry, type_id),
+        }
+    }
+}
+
+impl Display for SchematicDescription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn write_fields(
+            f: &mut Formatter<'_>,
+            fields: &[SchematicFieldDescription],
+        ) -> std::fmt::Result {
+            writeln!(f, "| Field | Type | Default | Kind |")?;
+            writeln!(f, "| ----- | ---- | ------- | ---- |")?;
+            for field in fields {
+                let default = field
+                    .default
+                    .as_ref()
+                    .map(|default| format!("`{}`", default))
+                    .unwrap_or_default();
+                writeln!(
+                    f,
+                    "| `{}` | `{}` | {} | {:?} |",
+                    field.name, field.type_name, default, field.kind
+                )?;
+            }
+            Ok(())
+        }
+
+        writeln!(f, "## `{}`", self.type_name)?;
+        writeln!(f)?;
+        writeln!(f, "Input: `{}`", self.input_type_name)?;
+        if !self.requires_entity {
+            writeln!(f)?;
+            writeln!(f, "Does not require an entity.")?;
+        }
+
+        match &self.shape {
+            SchematicShape::Struct(fields) if !fields.is_empty() => {
+                writeln!(f)?;
+                write_fields(f, fields)?;
+            }
+            SchematicShape::Enum(variants) => {
+                for variant in variants {
+                    writeln!(f)?;
+                    writeln!(f, "### `{}`", variant.name)?;
+                    if !variant.fields.is_empty() {
+                        writeln!(f)?;
+                        write_fields(f, &variant.fields)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::{Component, Entity, Reflect};
+    use bevy::reflect::TypeUuid;
+
+    use crate::assets::ProtoAsset;
+    use crate::schematics::{FromSchematicInput, SchematicContext, SchematicId};
+    use crate::tree::{EntityAccess, ProtoEntityList};
+    use bevy_proto_derive::{AssetSchematic, Schematic};
+
+    use super::*;
+
+    #[derive(AssetSchematic, Reflect, TypeUuid)]
+    #[uuid = "5c1e9a3b-7d2f-4b8e-a6c0-3f9d1e7b5a2c"]
+    struct Texture {
+        size: u32,
+    }
+
+    #[derive(Component, Reflect, Schematic)]
+    #[reflect(Schematic)]
+    #[schematic(from = TargetInput)]
+    struct Target {
+        #[reflect(ignore)]
+        entities: Vec<Entity>,
+    }
+
+    #[derive(Reflect)]
+    struct TargetInput {
+        count: u32,
+        target: EntityAccess,
+        fallback: Option<EntityAccess>,
+        group: ProtoEntityList,
+        texture: ProtoAsset<Texture>,
+        icon: Option<ProtoAsset<Texture>>,
+        textures: Vec<ProtoAsset<Texture>>,
+        unregistered: Vec<EntityAccess>,
+    }
+
+    impl FromSchematicInput<TargetInput> for Target {
+        fn from_input(input: TargetInput, id: SchematicId, context: &mut SchematicContext) -> Self {
+            Self {
+                entities: Vec::from_input(input.group, id, context),
+            }
+        }
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Target>();
+        registry.register::<TargetInput>();
+        registry.register::<EntityAccess>();
+        registry.register::<Option<EntityAccess>>();
+        registry.register::<ProtoEntityList>();
+        registry.register::<ProtoAsset<Texture>>();
+        registry.register::<Option<ProtoAsset<Texture>>>();
+        registry.register::<Vec<ProtoAsset<Texture>>>();
+        registry
+    }
+
+    #[test]
+    fn should_detect_field_kinds_from_type_data() {
+        let registry = registry();
+        let description =
+            SchematicDescription::new(&registry, std::any::type_name::<Target>()).unwrap();
+
+        let SchematicShape::Struct(fields) = description.shape else {
+            panic!("expected struct shape, found {:?}", description.shape);
+        };
+
+        let kinds = fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.kind))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                ("count", SchematicFieldKind::Value),
+                ("target", SchematicFieldKind::Entity),
+                ("fallback", SchematicFieldKind::Entity),
+                ("group", SchematicFieldKind::Entity),
+                ("texture", SchematicFieldKind::Asset),
+                ("icon", SchematicFieldKind::Asset),
+                ("textures", SchematicFieldKind::Asset),
+                ("unregistered", SchematicFieldKind::Value),
+            ],
+            kinds
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use std::fmt::{Debug, Formatter};
 
+use bevy::ecs::reflect::{AppTypeRegistry, ReflectComponent};
 use bevy::prelude::{FromReflect, Reflect};
 use bevy::reflect::{FromType, GetTypeRegistration, TypeInfo, TypeRegistration, Typed};
 
@@ -19,6 +20,7 @@ use crate::schematics::{SchematicContext, SchematicError, SchematicId};
 pub struct DynamicSchematic {
     input: Box<dyn Reflect>,
     reflect_schematic: ReflectSchematic,
+    insert_mode: InsertMode,
 }
 
 impl DynamicSchematic {
@@ -26,9 +28,37 @@ impl DynamicSchematic {
         Self {
             input: Box::new(input),
             reflect_schematic: <ReflectSchematic as FromType<T>>::from_type(),
+            insert_mode: InsertMode::default(),
         }
     }
 
+    /// Returns the [`InsertMode`] of this schematic.
+    pub fn insert_mode(&self) -> InsertMode {
+        self.insert_mode
+    }
+
+    /// Sets the [`InsertMode`] of this schematic.
+    pub fn set_insert_mode(&mut self, insert_mode: InsertMode) {
+        self.insert_mode = insert_mode;
+    }
+
+    /// Returns true if the entity in the given context already contains
+    /// the component of the corresponding [`Schematic`] type.
+    ///
+    /// This requires the schematic type to be registered with [`ReflectComponent`].
+    /// If it isn't, or if there is no entity, this returns `false`.
+    pub fn is_present(&self, context: &SchematicContext) -> bool {
+        let Some(entity) = context.entity() else {
+            return false;
+        };
+
+        let registry = context.world().resource::<AppTypeRegistry>().read();
+        registry
+            .get_type_data::<ReflectComponent>(self.type_info().type_id())
+            .map(|reflect_component| reflect_component.contains(entity))
+            .unwrap_or_default()
+    }
+
     /// Get a reference to the reflected [schematic input] data.
     ///
     /// [schematic input]: Schematic::Input
@@ -61,6 +91,15 @@ impl DynamicSchematic {
         (self.reflect_schematic.remove)(&*self.input, id, context)
     }
 
+    /// Dynamically call the corresponding [`Schematic::reload`] method.
+    pub fn reload(
+        &self,
+        id: SchematicId,
+        context: &mut SchematicContext,
+    ) -> Result<(), SchematicError> {
+        (self.reflect_schematic.reload)(&*self.input, id, context)
+    }
+
     /// Dynamically call the corresponding [`Schematic::preload_dependencies`] method.
     pub fn preload_dependencies(
         &mut self,
@@ -82,11 +121,29 @@ impl DynamicSchematic {
         self.reflect_schematic.input_registration()
     }
 
+    /// Returns whether the corresponding [`Schematic`] operates on an entity.
+    ///
+    /// See [`Schematic::requires_entity`] for details.
+    pub fn requires_entity(&self) -> bool {
+        self.reflect_schematic.requires_entity()
+    }
+
+    /// Returns true if both schematics are of the same type, use the same [`InsertMode`],
+    /// and have structurally equal inputs.
+    ///
+    /// Inputs are compared field-by-field using reflection.
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        self.type_info().type_id() == other.type_info().type_id()
+            && self.insert_mode == other.insert_mode
+            && crate::proto::values_eq(self.input(), other.input())
+    }
+
     /// Attempts to clone this [`DynamicSchematic`].
     pub fn try_clone(&self) -> Result<Self, SchematicError> {
         Ok(Self {
             input: (self.reflect_schematic.clone_input)(&*self.input)?,
             reflect_schematic: self.reflect_schematic.clone(),
+            insert_mode: self.insert_mode,
         })
     }
 }
@@ -96,10 +153,24 @@ impl Debug for DynamicSchematic {
         f.debug_struct("DynamicSchematic")
             .field("type_name", &self.reflect_schematic.type_info.type_name())
             .field("input", &self.input)
+            .field("insert_mode", &self.insert_mode)
             .finish()
     }
 }
 
+/// Controls how a [`DynamicSchematic`] is applied to an entity.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum InsertMode {
+    /// The schematic is always applied, overwriting any existing component.
+    #[default]
+    Overwrite,
+    /// The schematic is skipped if the entity already contains
+    /// the component of the corresponding [`Schematic`] type.
+    ///
+    /// See [`DynamicSchematic::is_present`] for details.
+    KeepExisting,
+}
+
 /// Reflected [type data] for the [`Schematic`] trait.
 ///
 /// [type data]: bevy::reflect::TypeData
@@ -120,12 +191,18 @@ pub struct ReflectSchematic {
         id: SchematicId,
         context: &mut SchematicContext,
     ) -> Result<(), SchematicError>,
+    reload: fn(
+        input: &dyn Reflect,
+        id: SchematicId,
+        context: &mut SchematicContext,
+    ) -> Result<(), SchematicError>,
     preload_dependencies: fn(
         input: &mut dyn Reflect,
         id: SchematicId,
         dependencies: &mut DependenciesBuilder,
     ) -> Result<(), SchematicError>,
     clone_input: fn(input: &dyn Reflect) -> Result<Box<dyn Reflect>, SchematicError>,
+    requires_entity: fn() -> bool,
 }
 
 impl ReflectSchematic {
@@ -151,6 +228,13 @@ impl ReflectSchematic {
     pub fn input_registration(&self) -> TypeRegistration {
         (self.input_registration)()
     }
+
+    /// Returns whether the corresponding [`Schematic`] operates on an entity.
+    ///
+    /// See [`Schematic::requires_entity`] for details.
+    pub fn requires_entity(&self) -> bool {
+        (self.requires_entity)()
+    }
 }
 
 impl<T: Schematic> FromType<T> for ReflectSchematic {
@@ -165,6 +249,7 @@ impl<T: Schematic> FromType<T> for ReflectSchematic {
                 Ok(DynamicSchematic {
                     input: Box::new(input),
                     reflect_schematic: data,
+                    insert_mode: InsertMode::default(),
                 })
             },
             apply: |reflect_input, id, context| {
@@ -174,8 +259,7 @@ impl<T: Schematic> FromType<T> for ReflectSchematic {
                         found: reflect_input.type_name().to_string(),
                     }
                 })?;
-                <T as Schematic>::apply(input, id, context);
-                Ok(())
+                <T as Schematic>::try_apply(input, id, context)
             },
             remove: |reflect_input, id, context| {
                 let input = reflect_input.downcast_ref::<T::Input>().ok_or_else(|| {
@@ -187,6 +271,16 @@ impl<T: Schematic> FromType<T> for ReflectSchematic {
                 <T as Schematic>::remove(input, id, context);
                 Ok(())
             },
+            reload: |reflect_input, id, context| {
+                let input = reflect_input.downcast_ref::<T::Input>().ok_or_else(|| {
+                    SchematicError::TypeMismatch {
+                        expected: std::any::type_name::<T::Input>(),
+                        found: reflect_input.type_name().to_string(),
+                    }
+                })?;
+                <T as Schematic>::reload(input, id, context);
+                Ok(())
+            },
             preload_dependencies: |reflect_input, id, dependencies| {
                 let type_name = reflect_input.type_name().to_string();
                 let input = reflect_input.downcast_mut::<T::Input>().ok_or_else(|| {
@@ -195,14 +289,14 @@ impl<T: Schematic> FromType<T> for ReflectSchematic {
                         found: type_name,
                     }
                 })?;
-                <T as Schematic>::preload_dependencies(input, id, dependencies);
-                Ok(())
+                <T as Schematic>::preload_dependencies(input, id, dependencies)
             },
             clone_input: |reflect_input| {
                 <T::Input as FromReflect>::from_reflect(reflect_input)
                     .map(|input| Box::new(input) as Box<dyn Reflect>)
                     .ok_or(SchematicError::FromReflectFail)
             },
+            requires_entity: <T as Schematic>::requires_entity,
         }
     }
 }
@@ -14,6 +14,44 @@ pub enum SchematicError {
     /// [`FromReflect`]: bevy::reflect::FromReflect
     #[error("a call to a `FromReflect` method failed")]
     FromReflectFail,
+    /// A field's input failed to be converted via [`FromReflect`] while preloading.
+    ///
+    /// This most often happens when a nested type is missing a `FromReflect` implementation
+    /// (i.e. `#[reflect(from_reflect = false)]`) or when it has not been registered.
+    ///
+    /// [`FromReflect`]: bevy::reflect::FromReflect
+    #[error("could not preload field `{field}` of schematic `{schematic}`: the input type `{input}` failed to convert via `FromReflect` (make sure it implements `FromReflect` and that all of its nested types are registered)")]
+    PreloadFromReflectFail {
+        schematic: &'static str,
+        field: String,
+        input: &'static str,
+    },
+    /// A type listed in [`Markers`] is not a valid marker component.
+    ///
+    /// [`Markers`]: crate::schematics::Markers
+    #[error("invalid marker `{name}`: {reason}")]
+    InvalidMarker { name: String, reason: &'static str },
+    /// A schematic's input was rejected while being converted.
+    ///
+    /// This is meant to be returned by [`TryFromSchematicInput`] implementations
+    /// that validate their input.
+    /// It's also returned when loading a prototype whose input fails [`ValidateInput`].
+    ///
+    /// [`TryFromSchematicInput`]: crate::schematics::TryFromSchematicInput
+    /// [`ValidateInput`]: crate::schematics::ValidateInput
+    #[error("invalid input for `{schematic}`: {reason}")]
+    InvalidInput {
+        schematic: &'static str,
+        reason: String,
+    },
+    /// A [`ProtoExpr`] could not be evaluated.
+    ///
+    /// [`ProtoExpr`]: crate::schematics::ProtoExpr
+    #[error("could not evaluate expression {input:?}: {error}")]
+    InvalidExpression {
+        input: crate::schematics::ProtoExpr,
+        error: crate::schematics::ProtoExprError,
+    },
     /// An invalid type was passed.
     #[error("expected type `{expected}` but found `{found}`")]
     TypeMismatch {
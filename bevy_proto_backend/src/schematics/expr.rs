@@ -0,0 +1,292 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use bevy::reflect::{Reflect, ReflectDeserialize, ReflectSerialize};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::schematics::{
+    ProtoParams, SchematicContext, SchematicError, SchematicId, TryFromSchematicInput,
+};
+
+/// A numeric value that may be computed from the [`ProtoParams`] given at spawn time.
+///
+/// In a prototype file, this can either be a plain number or a string containing an expression:
+///
+/// ```text
+/// (
+///   name: "Enemy",
+///   schematics: {
+///     "my_game::Health": (
+///       max: "{level} * 10 + 5",
+///     ),
+///   },
+/// )
+/// ```
+///
+/// Spawning the prototype above with a `level` of `3` results in a `max` of `35`.
+///
+/// This type is meant to be used with the `try_from` field attribute of the [derive macro]:
+///
+/// ```ignore
+/// #[derive(Component, Schematic, Reflect)]
+/// struct Health {
+///   #[schematic(try_from = ProtoExpr)]
+///   max: f32,
+/// }
+/// ```
+///
+/// # Grammar
+///
+/// Expressions are evaluated as `f64` and support the following:
+///
+/// | Syntax              | Description                                    |
+/// | ------------------- | ---------------------------------------------- |
+/// | `1`, `2.5`, `1e3`   | Number literal                                 |
+/// | `{name}`            | The value of the numeric parameter `name`      |
+/// | `a + b`, `a - b`    | Addition and subtraction                       |
+/// | `a * b`, `a / b`    | Multiplication and division                    |
+/// | `a % b`             | Remainder                                      |
+/// | `-a`                | Negation                                       |
+/// | `(a)`               | Grouping                                       |
+///
+/// The usual precedence rules apply: `*`, `/`, and `%` bind tighter than `+` and `-`.
+/// Whitespace is ignored.
+///
+/// When converting to an integer type, the result is truncated towards zero
+/// and saturated to the bounds of that type.
+///
+/// # Errors
+///
+/// An expression that fails to parse, references a parameter that was not given,
+/// or results in a non-finite value (such as when dividing by zero) fails
+/// with [`SchematicError::InvalidExpression`].
+/// Like any other fallible conversion, whether this panics or is logged and skipped
+/// is controlled by [`Config::skip_failed_schematics`].
+///
+/// Note that expressions are only evaluated when the schematic is applied,
+/// so errors are not caught when the prototype is loaded.
+///
+/// [derive macro]: bevy_proto_derive::Schematic
+/// [`Config::skip_failed_schematics`]: crate::proto::Config::skip_failed_schematics
+#[derive(Reflect, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[reflect(PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ProtoExpr {
+    /// A constant value.
+    Value(f64),
+    /// An expression to be evaluated at spawn time.
+    Expr(String),
+}
+
+impl ProtoExpr {
+    /// Evaluate this expression using the given parameters.
+    pub fn eval(&self, params: Option<&ProtoParams>) -> Result<f64, ProtoExprError> {
+        match self {
+            Self::Value(value) => Ok(*value),
+            Self::Expr(expr) => {
+                let mut parser = ExprParser {
+                    chars: expr.char_indices().peekable(),
+                    params,
+                };
+
+                let value = parser.parse_expr()?;
+                if let Some((index, c)) = parser.next_token() {
+                    return Err(ProtoExprError::UnexpectedChar(c, index));
+                }
+
+                if value.is_finite() {
+                    Ok(value)
+                } else {
+                    Err(ProtoExprError::NonFinite)
+                }
+            }
+        }
+    }
+}
+
+impl Default for ProtoExpr {
+    fn default() -> Self {
+        Self::Value(0.0)
+    }
+}
+
+impl From<f64> for ProtoExpr {
+    fn from(value: f64) -> Self {
+        Self::Value(value)
+    }
+}
+
+/// An error that occurs while evaluating a [`ProtoExpr`].
+#[derive(Debug, Error, PartialEq)]
+pub enum ProtoExprError {
+    #[error("unexpected character {0:?} at index {1}")]
+    UnexpectedChar(char, usize),
+    #[error("unexpected end of expression")]
+    UnexpectedEnd,
+    #[error("invalid number {0:?}")]
+    InvalidNumber(String),
+    #[error("unknown parameter {0:?}")]
+    UnknownParam(String),
+    #[error("expression resulted in a non-finite value")]
+    NonFinite,
+}
+
+/// A simple recursive descent parser that evaluates as it goes.
+struct ExprParser<'a> {
+    chars: Peekable<CharIndices<'a>>,
+    params: Option<&'a ProtoParams>,
+}
+
+impl<'a> ExprParser<'a> {
+    /// Skips whitespace and returns the next character without consuming it.
+    fn next_token(&mut self) -> Option<(usize, char)> {
+        while let Some((_, c)) = self.chars.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.chars.next();
+        }
+
+        self.chars.peek().copied()
+    }
+
+    /// `expr := term (('+' | '-') term)*`
+    fn parse_expr(&mut self) -> Result<f64, ProtoExprError> {
+        let mut value = self.parse_term()?;
+
+        while let Some((_, c @ ('+' | '-'))) = self.next_token() {
+            self.chars.next();
+            let rhs = self.parse_term()?;
+            value = if c == '+' { value + rhs } else { value - rhs };
+        }
+
+        Ok(value)
+    }
+
+    /// `term := factor (('*' | '/' | '%') factor)*`
+    fn parse_term(&mut self) -> Result<f64, ProtoExprError> {
+        let mut value = self.parse_factor()?;
+
+        while let Some((_, c @ ('*' | '/' | '%'))) = self.next_token() {
+            self.chars.next();
+            let rhs = self.parse_factor()?;
+            value = match c {
+                '*' => value * rhs,
+                '/' => value / rhs,
+                _ => value % rhs,
+            };
+        }
+
+        Ok(value)
+    }
+
+    /// `factor := '-' factor | '(' expr ')' | '{' name '}' | number`
+    fn parse_factor(&mut self) -> Result<f64, ProtoExprError> {
+        match self.next_token() {
+            Some((_, '-')) => {
+                self.chars.next();
+                Ok(-self.parse_factor()?)
+            }
+            Some((_, '(')) => {
+                self.chars.next();
+                let value = self.parse_expr()?;
+                match self.next_token() {
+                    Some((_, ')')) => {
+                        self.chars.next();
+                        Ok(value)
+                    }
+                    Some((index, c)) => Err(ProtoExprError::UnexpectedChar(c, index)),
+                    None => Err(ProtoExprError::UnexpectedEnd),
+                }
+            }
+            Some((_, '{')) => {
+                self.chars.next();
+                let mut name = String::new();
+                loop {
+                    match self.chars.next() {
+                        Some((_, '}')) => break,
+                        Some((_, c)) => name.push(c),
+                        None => return Err(ProtoExprError::UnexpectedEnd),
+                    }
+                }
+
+                let name = name.trim();
+                self.params
+                    .and_then(|params| params.get_number(name))
+                    .ok_or_else(|| ProtoExprError::UnknownParam(name.to_string()))
+            }
+            Some((_, c)) if c.is_ascii_digit() || c == '.' => {
+                let mut number = String::new();
+                while let Some((_, c)) = self.chars.peek().copied() {
+                    let is_exponent_sign = matches!(c, '+' | '-') && number.ends_with(['e', 'E']);
+                    if c.is_ascii_alphanumeric() || c == '.' || is_exponent_sign {
+                        number.push(c);
+                        self.chars.next();
+                    } else {
+                        break;
+                    }
+                }
+
+                number
+                    .parse()
+                    .map_err(|_| ProtoExprError::InvalidNumber(number))
+            }
+            Some((index, c)) => Err(ProtoExprError::UnexpectedChar(c, index)),
+            None => Err(ProtoExprError::UnexpectedEnd),
+        }
+    }
+}
+
+macro_rules! impl_try_from_expr {
+    ($($ty: ty),*) => {
+        $(
+            impl TryFromSchematicInput<ProtoExpr> for $ty {
+                #[allow(clippy::unnecessary_cast)]
+                fn try_from_input(
+                    input: ProtoExpr,
+                    _id: SchematicId,
+                    context: &mut SchematicContext,
+                ) -> Result<Self, SchematicError> {
+                    input
+                        .eval(context.params())
+                        .map(|value| value as $ty)
+                        .map_err(|error| SchematicError::InvalidExpression { input, error })
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_expr!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(expr: &str) -> Result<f64, ProtoExprError> {
+        let params = ProtoParams::new().with("level", 3_u32).with("bonus", 0.5);
+        ProtoExpr::Expr(expr.to_string()).eval(Some(&params))
+    }
+
+    #[test]
+    fn should_evaluate_expressions() {
+        assert_eq!(Ok(30.0), eval("{level} * 10"));
+        assert_eq!(Ok(35.0), eval("{level} * 10 + 5"));
+        assert_eq!(Ok(45.0), eval("(5 + { level }) * 5 + -(-5)"));
+        assert_eq!(Ok(1.0), eval("{level} % 2"));
+        assert_eq!(Ok(1500.5), eval("1.5e3 + {bonus}"));
+    }
+
+    #[test]
+    fn should_reject_invalid_expressions() {
+        assert_eq!(
+            Err(ProtoExprError::UnknownParam("health".to_string())),
+            eval("{health} * 2")
+        );
+        assert_eq!(Err(ProtoExprError::UnexpectedEnd), eval("({level} * 2"));
+        assert_eq!(Err(ProtoExprError::UnexpectedChar('x', 4)), eval("2 * x"));
+        assert_eq!(Err(ProtoExprError::UnexpectedChar('3', 2)), eval("2 3"));
+        assert_eq!(Err(ProtoExprError::NonFinite), eval("{level} / 0"));
+    }
+}
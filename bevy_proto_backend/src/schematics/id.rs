@@ -37,6 +37,21 @@ impl SchematicId {
         }))
     }
 
+    /// Generate a new [`SchematicId`] from this one and the given field name.
+    ///
+    /// This is a convenience over [`SchematicId::next`] for manual [`FromSchematicInput`]
+    /// implementations, allowing nested IDs to be derived from the field they belong to
+    /// rather than a hand-written UUID.
+    /// Nested fields can be identified by chaining calls, such as `id.field("a").field("b")`.
+    ///
+    /// IDs generated by this method will never collide with those generated by passing
+    /// the same string to [`SchematicId::next`].
+    ///
+    /// [`FromSchematicInput`]: crate::schematics::FromSchematicInput
+    pub fn field(&self, name: &str) -> Self {
+        self.next(FieldSeed(name))
+    }
+
     /// Allows cloning this [`SchematicId`].
     ///
     /// This is used instead of the [`Clone`] trait to prevent consumers of this crate
@@ -51,3 +66,13 @@ impl SchematicId {
         hasher.finish()
     }
 }
+
+/// Seed used by [`SchematicId::field`] to keep field-based IDs distinct from other seeds.
+struct FieldSeed<'a>(&'a str);
+
+impl Hash for FieldSeed<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        TypeId::of::<FieldSeed<'static>>().hash(state);
+        self.0.hash(state);
+    }
+}
@@ -0,0 +1,246 @@
+use bevy::ecs::reflect::{AppTypeRegistry, ReflectComponent};
+use bevy::reflect::std_traits::ReflectDefault;
+use bevy::reflect::{Reflect, TypeInfo, TypeRegistration, TypeRegistryInternal};
+
+use crate::schematics::{Schematic, SchematicContext, SchematicError, SchematicId};
+
+/// A [`Schematic`] that inserts a list of marker components by their type name.
+///
+/// Each marker must be a registered, zero-sized component that has registered
+/// both [`ReflectComponent`] and [`ReflectDefault`].
+/// This can be verified ahead of time using [`Markers::validate`].
+#[derive(Reflect, Debug, Default, Clone, PartialEq, Eq)]
+pub struct Markers(pub Vec<String>);
+
+impl Markers {
+    /// Checks that every marker in this list is a registered zero-sized component.
+    pub fn validate(&self, registry: &TypeRegistryInternal) -> Result<(), SchematicError> {
+        for name in &self.0 {
+            Self::get_registration(name, registry)?;
+        }
+
+        Ok(())
+    }
+
+    fn get_registration<'a>(
+        name: &str,
+        registry: &'a TypeRegistryInternal,
+    ) -> Result<&'a TypeRegistration, SchematicError> {
+        let invalid = |reason: &'static str| SchematicError::InvalidMarker {
+            name: name.to_string(),
+            reason,
+        };
+
+        let registration = registry
+            .get_with_name(name)
+            .ok_or_else(|| invalid("type is not registered"))?;
+
+        let is_zero_sized = match registration.type_info() {
+            TypeInfo::Struct(info) => info.field_len() == 0,
+            TypeInfo::TupleStruct(info) => info.field_len() == 0,
+            _ => false,
+        };
+
+        if !is_zero_sized {
+            return Err(invalid("type is not a zero-sized struct"));
+        }
+
+        if registration.data::<ReflectComponent>().is_none() {
+            return Err(invalid("missing `ReflectComponent` registration"));
+        }
+
+        if registration.data::<ReflectDefault>().is_none() {
+            return Err(invalid("missing `ReflectDefault` registration"));
+        }
+
+        Ok(registration)
+    }
+}
+
+impl Schematic for Markers {
+    type Input = Self;
+
+    fn apply(input: &Self::Input, id: SchematicId, context: &mut SchematicContext) {
+        if let Err(error) = Self::try_apply(input, id, context) {
+            panic!("{}", error);
+        }
+    }
+
+    fn try_apply(
+        input: &Self::Input,
+        _id: SchematicId,
+        context: &mut SchematicContext,
+    ) -> Result<(), SchematicError> {
+        let registry = context.world().resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        // Validate every marker before inserting any of them
+        let registrations = input
+            .0
+            .iter()
+            .map(|name| Self::get_registration(name, &registry))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut entity = context.entity_mut().unwrap_or_else(|| {
+            panic!(
+                "schematic `{}` expected entity",
+                std::any::type_name::<Self>()
+            )
+        });
+
+        for registration in registrations {
+            // Both type data are guaranteed to exist by `get_registration`
+            let marker = registration.data::<ReflectDefault>().unwrap().default();
+            registration
+                .data::<ReflectComponent>()
+                .unwrap()
+                .insert(&mut entity, &*marker);
+        }
+
+        Ok(())
+    }
+
+    fn remove(input: &Self::Input, _id: SchematicId, context: &mut SchematicContext) {
+        let registry = context.world().resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let Some(mut entity) = context.entity_mut() else {
+            return;
+        };
+
+        for name in &input.0 {
+            if let Ok(registration) = Self::get_registration(name, &registry) {
+                registration
+                    .data::<ReflectComponent>()
+                    .unwrap()
+                    .remove(&mut entity);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::App;
+    use bevy::prelude::Component;
+
+    use crate::test_utils::{self, TestConfig, TestProto};
+
+    use super::*;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Enemy;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Flying;
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component, Default)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct NoDefault;
+
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    struct NoComponent;
+
+    fn registry() -> TypeRegistryInternal {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<Enemy>();
+        registry.register::<Health>();
+        registry.register::<NoDefault>();
+        registry.register::<NoComponent>();
+        registry
+    }
+
+    fn markers<T>() -> Markers {
+        Markers(vec![std::any::type_name::<T>().to_string()])
+    }
+
+    #[test]
+    fn should_validate_markers() {
+        let registry = registry();
+
+        assert!(markers::<Enemy>().validate(&registry).is_ok());
+
+        for (invalid, expected) in [
+            (markers::<Flying>(), "type is not registered"),
+            (markers::<Health>(), "type is not a zero-sized struct"),
+            (
+                markers::<NoComponent>(),
+                "missing `ReflectComponent` registration",
+            ),
+            (
+                markers::<NoDefault>(),
+                "missing `ReflectDefault` registration",
+            ),
+        ] {
+            let Err(SchematicError::InvalidMarker { reason, .. }) = invalid.validate(&registry)
+            else {
+                panic!("expected {:?} to be invalid", invalid);
+            };
+            assert_eq!(expected, reason);
+        }
+    }
+
+    #[test]
+    fn should_insert_and_remove_markers() {
+        let mut app = test_utils::app();
+        app.register_type::<Enemy>().register_type::<Flying>();
+        test_utils::add(
+            &mut app,
+            TestProto::new("Bat").with_schematic::<Markers>(Markers(vec![
+                std::any::type_name::<Enemy>().to_string(),
+                std::any::type_name::<Flying>().to_string(),
+            ])),
+        );
+        test_utils::register(&mut app);
+
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("Bat").id());
+        assert!(app.world.get::<Enemy>(entity).is_some());
+        assert!(app.world.get::<Flying>(entity).is_some());
+
+        test_utils::run_commands(&mut app, |commands| {
+            commands.entity(entity).remove("Bat");
+        });
+        assert!(app.world.get::<Enemy>(entity).is_none());
+        assert!(app.world.get::<Flying>(entity).is_none());
+    }
+
+    fn typo_app(config: TestConfig) -> App {
+        let mut app = test_utils::app_with_config(config);
+        app.register_type::<Enemy>();
+        test_utils::add(
+            &mut app,
+            TestProto::new("Typo").with_schematic::<Markers>(Markers(vec![
+                std::any::type_name::<Enemy>().to_string(),
+                "Typo".to_string(),
+            ])),
+        );
+        test_utils::register(&mut app);
+        app
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid marker `Typo`: type is not registered")]
+    fn should_panic_on_invalid_marker() {
+        let mut app = typo_app(TestConfig::default());
+        test_utils::run_commands(&mut app, |commands| {
+            commands.spawn("Typo");
+        });
+    }
+
+    #[test]
+    fn should_skip_invalid_marker() {
+        let mut app = typo_app(TestConfig {
+            skip_failed_schematics: true,
+            ..Default::default()
+        });
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("Typo").id());
+        assert!(app.world.get_entity(entity).is_some());
+        assert!(app.world.get::<Enemy>(entity).is_none());
+    }
+}
@@ -40,14 +40,25 @@
 pub use bevy_proto_derive::Schematic;
 pub use collection::*;
 pub use context::*;
+pub use description::*;
 pub use dynamic::*;
 pub use error::*;
+pub use expr::*;
 pub use id::*;
+pub use markers::*;
+pub use params::*;
 pub use schematic::*;
+pub(crate) use validate::validate_schematic_input;
+pub use validate::{ReflectValidateInput, ValidateInput};
 
 mod collection;
 mod context;
+mod description;
 mod dynamic;
 mod error;
+mod expr;
 mod id;
+mod markers;
+mod params;
 mod schematic;
+mod validate;
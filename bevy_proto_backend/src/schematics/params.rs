@@ -0,0 +1,166 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use bevy::reflect::Reflect;
+use bevy::utils::HashMap;
+
+/// A set of named parameters supplied when spawning a [prototype].
+///
+/// These allow a single prototype to be reused across different contexts.
+/// Schematics can read them during application via [`SchematicContext::params`],
+/// and numeric parameters can be referenced by [`ProtoExpr`] fields.
+///
+/// # Lifetime
+///
+/// Parameters only exist for the duration of the spawn (or insertion) they were given to.
+/// They are available to every schematic in the prototype's hierarchy while it is being applied,
+/// but they are not stored on the entity and are not available when the prototype is later
+/// removed or re-applied (e.g. due to a hot-reload).
+/// Schematics that need a parameter beyond that point should store it themselves.
+///
+/// # Cloning
+///
+/// Values are shared between clones rather than copied,
+/// so cloned parameters keep their concrete types.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_proto_backend::schematics::ProtoParams;
+/// let params = ProtoParams::new()
+///   .with("level", 3_i32)
+///   .with("faction", String::from("pirates"));
+///
+/// assert_eq!(Some(&3), params.get::<i32>("level"));
+/// assert_eq!(Some(3.0), params.get_number("level"));
+/// assert_eq!(Some("pirates"), params.get::<String>("faction").map(String::as_str));
+/// ```
+///
+/// [prototype]: crate::proto::Prototypical
+/// [`SchematicContext::params`]: crate::schematics::SchematicContext::params
+/// [`ProtoExpr`]: crate::schematics::ProtoExpr
+#[derive(Default, Clone)]
+pub struct ProtoParams {
+    values: HashMap<String, Arc<dyn Reflect>>,
+}
+
+impl ProtoParams {
+    /// Create an empty set of parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns this set with the given parameter added.
+    pub fn with<N: Into<String>, V: Reflect>(mut self, name: N, value: V) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Add the given parameter, replacing any previous value with the same name.
+    pub fn insert<N: Into<String>, V: Reflect>(&mut self, name: N, value: V) {
+        self.insert_boxed(name, Box::new(value));
+    }
+
+    /// Add the given reflected parameter, replacing any previous value with the same name.
+    pub fn insert_boxed<N: Into<String>>(&mut self, name: N, value: Box<dyn Reflect>) {
+        self.values.insert(name.into(), Arc::from(value));
+    }
+
+    /// Returns the parameter with the given name if it exists and is of type `T`.
+    pub fn get<T: Reflect>(&self, name: &str) -> Option<&T> {
+        self.get_reflect(name)?.downcast_ref::<T>()
+    }
+
+    /// Returns the reflected parameter with the given name, if any.
+    pub fn get_reflect(&self, name: &str) -> Option<&dyn Reflect> {
+        self.values.get(name).map(|value| value.as_ref())
+    }
+
+    /// Returns the parameter with the given name as an `f64`, if it exists and is a
+    /// primitive number type.
+    #[allow(clippy::unnecessary_cast)]
+    pub fn get_number(&self, name: &str) -> Option<f64> {
+        let value = self.get_reflect(name)?.as_any();
+
+        macro_rules! downcast {
+            ($($ty: ty),*) => {
+                $(
+                    if let Some(value) = value.downcast_ref::<$ty>() {
+                        return Some(*value as f64);
+                    }
+                )*
+            };
+        }
+
+        downcast!(f64, f32, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+        None
+    }
+
+    /// Returns true if a parameter with the given name exists.
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// Returns an iterator over the names and values of all parameters.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &dyn Reflect)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_ref()))
+    }
+
+    /// Returns true if there are no parameters.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl Debug for ProtoParams {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl From<HashMap<String, Box<dyn Reflect>>> for ProtoParams {
+    fn from(values: HashMap<String, Box<dyn Reflect>>) -> Self {
+        Self {
+            values: values
+                .into_iter()
+                .map(|(name, value)| (name, Arc::from(value)))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Spawner {
+        rate: f32,
+        name: String,
+    }
+
+    #[test]
+    fn should_keep_concrete_types_when_cloned() {
+        let params = ProtoParams::new().with("level", 3_i32).with(
+            "spawner",
+            Spawner {
+                rate: 0.5,
+                name: String::from("Goblins"),
+            },
+        );
+
+        let cloned = params.clone();
+        assert_eq!(Some(&3), cloned.get::<i32>("level"));
+        assert_eq!(Some(3.0), cloned.get_number("level"));
+        assert_eq!(
+            Some(&Spawner {
+                rate: 0.5,
+                name: String::from("Goblins"),
+            }),
+            cloned.get::<Spawner>("spawner")
+        );
+    }
+}
@@ -2,7 +2,7 @@ use bevy::prelude::{FromReflect, Reflect};
 use bevy::reflect::{GetTypeRegistration, Typed};
 
 use crate::deps::DependenciesBuilder;
-use crate::schematics::{SchematicContext, SchematicId};
+use crate::schematics::{SchematicContext, SchematicError, SchematicId};
 
 /// Trait used to create a [prototype] schematic for modifying an [entity]
 /// (or the [world] in general).
@@ -49,16 +49,60 @@ pub trait Schematic: Reflect + Typed {
 
     /// Controls how this schematic is applied to the given entity.
     fn apply(input: &Self::Input, id: SchematicId, context: &mut SchematicContext);
+    /// Fallible version of [`apply`](Self::apply).
+    ///
+    /// This is what gets called when a prototype is spawned or inserted,
+    /// allowing conversion errors to be surfaced rather than panicking.
+    /// The derive macro implements this for schematics with `#[schematic(try_from = ...)]` fields.
+    ///
+    /// By default, this calls [`apply`](Self::apply) and returns `Ok(())`.
+    fn try_apply(
+        input: &Self::Input,
+        id: SchematicId,
+        context: &mut SchematicContext,
+    ) -> Result<(), SchematicError> {
+        Self::apply(input, id, context);
+        Ok(())
+    }
     /// Controls how this schematic is removed from the given entity.
     fn remove(input: &Self::Input, id: SchematicId, context: &mut SchematicContext);
 
     /// Allows dependency assets to be loaded when this schematic is loaded.
+    ///
+    /// Any error returned will cause the prototype to fail to load.
     #[allow(unused_variables)]
     fn preload_dependencies(
         input: &mut Self::Input,
         id: SchematicId,
         dependencies: &mut DependenciesBuilder,
-    ) {
+    ) -> Result<(), SchematicError> {
+        // By default, do nothing.
+        Ok(())
+    }
+
+    /// Returns whether this schematic operates on an entity.
+    ///
+    /// Schematics that only modify the [world] (such as those that insert resources)
+    /// should return `false`.
+    /// This is used to detect prototypes that were spawned needlessly.
+    ///
+    /// By default, this returns `true`.
+    ///
+    /// [world]: bevy::ecs::world::World
+    fn requires_entity() -> bool {
+        true
+    }
+
+    /// Controls how an already-applied instance of this schematic is updated
+    /// when its prototype is hot-reloaded.
+    ///
+    /// This is meant to re-resolve assets whose paths may have changed,
+    /// without re-applying the entire schematic.
+    /// The derive macro implements this for fields marked `#[schematic(asset(reload))]`.
+    ///
+    /// By default, this does nothing.
+    #[allow(unused_variables)]
+    fn reload(input: &Self::Input, id: SchematicId, context: &mut SchematicContext) {
         // By default, do nothing.
     }
 }
@@ -84,6 +128,25 @@ impl<S, T: Into<S>> FromSchematicInput<T> for S {
     }
 }
 
+/// A custom [`TryFrom`]-like trait used to convert the [input] of a [schematic]
+/// to itself, allowing the conversion to fail.
+///
+/// This is used by the [derive macro] for fields marked `#[schematic(try_from = ...)]`.
+/// Any error returned will be surfaced by [`Schematic::try_apply`] instead of panicking.
+///
+/// Unlike [`FromSchematicInput`], this trait has no blanket implementation.
+///
+/// [input]: Schematic::Input
+/// [schematic]: Schematic
+/// [derive macro]: bevy_proto_derive::Schematic
+pub trait TryFromSchematicInput<T>: Sized {
+    fn try_from_input(
+        input: T,
+        id: SchematicId,
+        context: &mut SchematicContext,
+    ) -> Result<Self, SchematicError>;
+}
+
 /// A custom [`From`]-like trait used to convert the [input] of a [schematic]
 /// to itself during the _preload_ phase of a schematic.
 ///
@@ -0,0 +1,151 @@
+use bevy::reflect::{FromReflect, FromType, Reflect, ReflectRef, TypeRegistryInternal};
+
+use crate::schematics::SchematicError;
+
+/// Trait used to validate part of a schematic's input when its prototype is loaded.
+///
+/// This allows invalid input to be reported as a load error,
+/// rather than only being caught once the schematic is applied.
+///
+/// Validation is performed for any value within a schematic's input whose type
+/// is registered with the [`ReflectValidateInput`] type data,
+/// which can be done by including `#[reflect(ValidateInput)]` on the type definition.
+pub trait ValidateInput {
+    /// Checks that this value is valid, returning the reason if it isn't.
+    fn validate_input(&self) -> Result<(), String>;
+}
+
+/// [Type data] for types implementing [`ValidateInput`].
+///
+/// [Type data]: bevy::reflect::TypeData
+#[derive(Clone)]
+pub struct ReflectValidateInput {
+    validate: fn(&dyn Reflect) -> Result<(), String>,
+}
+
+impl ReflectValidateInput {
+    /// Validates the given value.
+    ///
+    /// The value may be a dynamic representation of the type (e.g. a `DynamicStruct`).
+    pub fn validate(&self, value: &dyn Reflect) -> Result<(), String> {
+        (self.validate)(value)
+    }
+}
+
+impl<T: ValidateInput + FromReflect> FromType<T> for ReflectValidateInput {
+    fn from_type() -> Self {
+        Self {
+            validate: |value| {
+                let value = T::from_reflect(value).ok_or_else(|| {
+                    format!(
+                        "could not convert value to `{}`",
+                        std::any::type_name::<T>()
+                    )
+                })?;
+                value.validate_input()
+            },
+        }
+    }
+}
+
+/// Recursively validates every value within the input of the given schematic.
+///
+/// Values are validated using their [`ReflectValidateInput`] type data, if registered.
+pub(crate) fn validate_schematic_input(
+    schematic: &'static str,
+    input: &dyn Reflect,
+    registry: &TypeRegistryInternal,
+) -> Result<(), SchematicError> {
+    validate_value(input, registry)
+        .map_err(|reason| SchematicError::InvalidInput { schematic, reason })
+}
+
+fn validate_value(value: &dyn Reflect, registry: &TypeRegistryInternal) -> Result<(), String> {
+    // Look up by name so that dynamic values are also recognized
+    if let Some(validate) = registry
+        .get_with_name(value.type_name())
+        .and_then(|registration| registration.data::<ReflectValidateInput>())
+    {
+        validate.validate(value)?;
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => value
+            .iter_fields()
+            .try_for_each(|field| validate_value(field, registry)),
+        ReflectRef::TupleStruct(value) => value
+            .iter_fields()
+            .try_for_each(|field| validate_value(field, registry)),
+        ReflectRef::Tuple(value) => value
+            .iter_fields()
+            .try_for_each(|field| validate_value(field, registry)),
+        ReflectRef::List(value) => value
+            .iter()
+            .try_for_each(|item| validate_value(item, registry)),
+        ReflectRef::Array(value) => value
+            .iter()
+            .try_for_each(|item| validate_value(item, registry)),
+        ReflectRef::Map(value) => value
+            .iter()
+            .try_for_each(|(_, item)| validate_value(item, registry)),
+        ReflectRef::Enum(value) => value
+            .iter_fields()
+            .try_for_each(|field| validate_value(field.value(), registry)),
+        ReflectRef::Value(_) => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::reflect::{DynamicStruct, TypeRegistryInternal, Typed};
+
+    use super::*;
+
+    #[derive(Reflect)]
+    #[reflect(ValidateInput)]
+    struct Even(u32);
+
+    impl ValidateInput for Even {
+        fn validate_input(&self) -> Result<(), String> {
+            if self.0 % 2 == 0 {
+                Ok(())
+            } else {
+                Err(format!("{} is odd", self.0))
+            }
+        }
+    }
+
+    #[derive(Reflect)]
+    struct Numbers {
+        numbers: Vec<Even>,
+    }
+
+    #[test]
+    fn should_validate_nested_inputs() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<Even>();
+        registry.register::<Numbers>();
+
+        let valid = Numbers {
+            numbers: vec![Even(2), Even(4)],
+        };
+        assert!(validate_schematic_input("Numbers", &valid, &registry).is_ok());
+
+        let invalid = Numbers {
+            numbers: vec![Even(2), Even(3)],
+        };
+        let Err(SchematicError::InvalidInput { schematic, reason }) =
+            validate_schematic_input("Numbers", &invalid, &registry)
+        else {
+            panic!("expected invalid input error");
+        };
+        assert_eq!("Numbers", schematic);
+        assert_eq!("3 is odd", reason);
+
+        // Dynamic values should be validated as well
+        let mut dynamic = DynamicStruct::default();
+        dynamic.set_represented_type(Some(Numbers::type_info()));
+        dynamic.insert("numbers", vec![Even(1)]);
+        assert!(validate_schematic_input("Numbers", &dynamic, &registry).is_err());
+    }
+}
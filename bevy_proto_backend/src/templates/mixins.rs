@@ -0,0 +1,184 @@
+use bevy::prelude::Resource;
+use bevy::utils::HashMap;
+use indexmap::IndexSet;
+
+use crate::schematics::Schematics;
+
+/// The names of the [mixins] included by a given [prototype].
+///
+/// # Order
+///
+/// Mixins are applied in the order they were inserted,
+/// so mixins inserted later may overwrite mixins inserted earlier.
+///
+/// [mixins]: ProtoMixins
+/// [prototype]: crate::proto::Prototypical
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct Mixins {
+    names: IndexSet<String>,
+}
+
+impl Mixins {
+    /// Include the mixin with the given name.
+    ///
+    /// Returns `false` if the mixin was already included.
+    pub fn insert<N: Into<String>>(&mut self, name: N) -> bool {
+        self.names.insert(name.into())
+    }
+
+    /// Returns true if the mixin with the given name is included.
+    pub fn contains(&self, name: &str) -> bool {
+        self.names.contains(name)
+    }
+
+    /// Returns an iterator over the names of the included mixins, in application order.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+
+    /// Returns the number of included mixins.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns true if no mixins are included.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+impl FromIterator<String> for Mixins {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        Self {
+            names: IndexSet::from_iter(iter),
+        }
+    }
+}
+
+/// Resource containing all named mixins.
+///
+/// A mixin is a lightweight, reusable group of [`Schematics`] that can be
+/// included into any number of [prototypes] by name.
+/// Unlike templates, mixins are not prototypes themselves:
+/// they have no ID, templates, or children.
+///
+/// # Precedence
+///
+/// For each prototype, its included mixins are applied after its templates,
+/// but before its own schematics.
+/// This means a prototype's own schematics always take precedence over its mixins,
+/// which in turn take precedence over its templates.
+///
+/// Mixins are resolved when a prototype is registered,
+/// so they should be registered before any prototypes that include them.
+/// Including a mixin that doesn't exist at that point logs a single warning
+/// and the mixin is ignored until the prototype is reloaded.
+/// The schematics of an existing mixin may still be replaced at any time.
+///
+/// # Example
+///
+/// ```ignore
+/// fn setup(mut mixins: ResMut<ProtoMixins>) {
+///   let mut schematics = Schematics::default();
+///   schematics.insert::<RigidBody>(RigidBody::Dynamic);
+///   schematics.insert::<Collider>(ColliderInput::Ball(1.0));
+///   mixins.insert("Physics", schematics);
+/// }
+/// ```
+///
+/// [prototypes]: crate::proto::Prototypical
+#[derive(Resource, Default, Debug)]
+pub struct ProtoMixins {
+    mixins: HashMap<String, Schematics>,
+}
+
+impl ProtoMixins {
+    /// Register a mixin with the given name.
+    ///
+    /// Returns the previous schematics if a mixin with the same name already existed.
+    pub fn insert<N: Into<String>>(
+        &mut self,
+        name: N,
+        schematics: Schematics,
+    ) -> Option<Schematics> {
+        self.mixins.insert(name.into(), schematics)
+    }
+
+    /// Get the schematics of the mixin with the given name.
+    pub fn get(&self, name: &str) -> Option<&Schematics> {
+        self.mixins.get(name)
+    }
+
+    /// Remove the mixin with the given name.
+    pub fn remove(&mut self, name: &str) -> Option<Schematics> {
+        self.mixins.remove(name)
+    }
+
+    /// Returns true if a mixin with the given name exists.
+    pub fn contains(&self, name: &str) -> bool {
+        self.mixins.contains_key(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::Component;
+    use bevy::reflect::Reflect;
+    use bevy_proto_derive::Schematic;
+
+    use crate::test_utils::{self, TestProto};
+
+    use super::*;
+
+    #[derive(Component, Reflect, Schematic, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Schematic, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Speed(u32);
+
+    #[test]
+    fn should_apply_mixins_before_own_schematics() {
+        let mut app = test_utils::app();
+        app.register_type::<Health>().register_type::<Speed>();
+
+        let mut schematics = Schematics::default();
+        schematics.insert::<Health>(Health(1));
+        schematics.insert::<Speed>(Speed(1));
+        app.world
+            .resource_mut::<ProtoMixins>()
+            .insert("Physics", schematics);
+
+        test_utils::add(
+            &mut app,
+            TestProto::new("Player")
+                .with_mixin("Physics")
+                .with_mixin("Missing")
+                .with_schematic::<Health>(Health(10)),
+        );
+        test_utils::register(&mut app);
+
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("Player").id());
+        assert_eq!(Some(&Health(10)), app.world.get::<Health>(entity));
+        assert_eq!(Some(&Speed(1)), app.world.get::<Speed>(entity));
+    }
+
+    #[test]
+    fn should_ignore_mixins_registered_after_prototype() {
+        let mut app = test_utils::app();
+        app.register_type::<Speed>();
+
+        test_utils::add(&mut app, TestProto::new("Player").with_mixin("Physics"));
+        test_utils::register(&mut app);
+
+        let mut schematics = Schematics::default();
+        schematics.insert::<Speed>(Speed(1));
+        app.world
+            .resource_mut::<ProtoMixins>()
+            .insert("Physics", schematics);
+
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("Player").id());
+        assert_eq!(None, app.world.get::<Speed>(entity));
+    }
+}
@@ -1,7 +1,9 @@
-//! Items for adding templates to [prototypes].
+//! Items for adding templates and mixins to [prototypes].
 //!
 //! [prototypes]: crate::proto::Prototypical
 
 pub use collection::*;
+pub use mixins::*;
 
 mod collection;
+mod mixins;
@@ -0,0 +1,264 @@
+//! Fixtures shared by the unit tests of this crate.
+
+use bevy::app::App;
+use bevy::asset::{AssetPlugin, Assets, Handle};
+use bevy::core::TaskPoolPlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::{Resource, World};
+use bevy::reflect::{TypePath, TypeUuid};
+
+use crate::children::{Children, PrototypicalChild};
+use crate::deps::Dependencies;
+use crate::load::{Loader, ProtoLoadContext};
+use crate::path::ProtoPath;
+use crate::proto::{Config, ProtoCommands, Prototypical, DEFAULT_APPLY_BUDGET};
+use crate::schematics::{Schematic, SchematicError, Schematics};
+use crate::templates::{Mixins, Templates};
+use crate::ProtoBackendPlugin;
+
+/// A simple [prototype] whose data is defined in code rather than loaded from a file.
+///
+/// [prototype]: Prototypical
+#[derive(TypeUuid, TypePath)]
+#[uuid = "0b1e8e4c-3b1e-4a69-9d7f-6c0a8d9a7e21"]
+pub(crate) struct TestProto {
+    pub id: String,
+    pub path: ProtoPath,
+    pub requires_entity: bool,
+    pub is_abstract: bool,
+    pub schematics: Schematics,
+    pub templates: Templates,
+    pub mixins: Mixins,
+    pub dependencies: Dependencies,
+    pub children: Children<Self>,
+}
+
+impl TestProto {
+    /// Create an empty prototype with the given ID.
+    ///
+    /// Its path is derived from the ID (i.e. `"{id}.prototype.ron"`).
+    pub fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            path: ProtoPath::from(format!("{}.prototype.ron", id)),
+            requires_entity: true,
+            is_abstract: false,
+            schematics: Schematics::default(),
+            templates: Templates::default(),
+            mixins: Mixins::default(),
+            dependencies: Dependencies::default(),
+            children: Children::default(),
+        }
+    }
+
+    /// Add the given schematic.
+    pub fn with_schematic<S: Schematic>(mut self, input: S::Input) -> Self {
+        self.schematics.insert::<S>(input);
+        self
+    }
+
+    /// Inherit from the template with the given ID and handle.
+    pub fn with_template(mut self, id: &str, handle: &Handle<Self>) -> Self {
+        self.templates.insert(
+            ProtoPath::from(format!("{}.prototype.ron", id)),
+            handle.clone_untyped(),
+        );
+        self
+    }
+
+    /// Include the mixin with the given name.
+    pub fn with_mixin(mut self, name: &str) -> Self {
+        self.mixins.insert(name);
+        self
+    }
+
+    /// Add the child with the given handle.
+    pub fn with_child(mut self, handle: &Handle<Self>) -> Self {
+        self.children.insert(TestChild {
+            handle: handle.clone(),
+            local_id: None,
+        });
+        self
+    }
+
+    /// Add the child with the given handle and [local ID].
+    ///
+    /// [local ID]: PrototypicalChild::local_id
+    pub fn with_local_child(mut self, handle: &Handle<Self>, local_id: &str) -> Self {
+        self.children.insert(TestChild {
+            handle: handle.clone(),
+            local_id: Some(local_id.to_string()),
+        });
+        self
+    }
+}
+
+impl Prototypical for TestProto {
+    type Id = String;
+    type Child = TestChild;
+
+    fn id(&self) -> &Self::Id {
+        &self.id
+    }
+    fn path(&self) -> &ProtoPath {
+        &self.path
+    }
+    fn requires_entity(&self) -> bool {
+        self.requires_entity
+    }
+    fn is_abstract(&self) -> bool {
+        self.is_abstract
+    }
+    fn schematics(&self) -> &Schematics {
+        &self.schematics
+    }
+    fn schematics_mut(&mut self) -> &mut Schematics {
+        &mut self.schematics
+    }
+    fn templates(&self) -> Option<&Templates> {
+        Some(&self.templates)
+    }
+    fn templates_mut(&mut self) -> Option<&mut Templates> {
+        Some(&mut self.templates)
+    }
+    fn mixins(&self) -> Option<&Mixins> {
+        Some(&self.mixins)
+    }
+    fn dependencies(&self) -> &Dependencies {
+        &self.dependencies
+    }
+    fn dependencies_mut(&mut self) -> &mut Dependencies {
+        &mut self.dependencies
+    }
+    fn children(&self) -> Option<&Children<Self>> {
+        Some(&self.children)
+    }
+    fn children_mut(&mut self) -> Option<&mut Children<Self>> {
+        Some(&mut self.children)
+    }
+}
+
+/// The child type of a [`TestProto`].
+pub(crate) struct TestChild {
+    handle: Handle<TestProto>,
+    local_id: Option<String>,
+}
+
+impl PrototypicalChild<TestProto> for TestChild {
+    type Key = ();
+
+    fn handle(&self) -> &Handle<TestProto> {
+        &self.handle
+    }
+
+    fn local_id(&self) -> Option<&str> {
+        self.local_id.as_deref()
+    }
+}
+
+/// The [`Config`] used for [`TestProto`].
+#[derive(Resource, Default)]
+pub(crate) struct TestConfig {
+    pub allow_missing_templates: bool,
+    pub default_templates: Vec<String>,
+    pub skip_failed_schematics: bool,
+    /// Overrides the [apply budget] if set.
+    ///
+    /// [apply budget]: Config::apply_budget
+    pub apply_budget: Option<usize>,
+    /// The IDs of the prototypes passed to [`Config::on_prototype_ready`], in call order.
+    pub ready: Vec<String>,
+}
+
+impl Config<TestProto> for TestConfig {
+    fn allow_missing_templates(&self) -> bool {
+        self.allow_missing_templates
+    }
+
+    fn default_templates(&self) -> &[String] {
+        &self.default_templates
+    }
+
+    fn skip_failed_schematics(&self) -> bool {
+        self.skip_failed_schematics
+    }
+
+    fn apply_budget(&self) -> usize {
+        self.apply_budget.unwrap_or(DEFAULT_APPLY_BUDGET)
+    }
+
+    fn on_prototype_ready(&mut self, prototype: &TestProto, _world: &mut World) {
+        self.ready.push(prototype.id().clone());
+    }
+}
+
+/// The [`Loader`] used for [`TestProto`].
+///
+/// Test prototypes are added directly to their [`Assets`] rather than loaded from files,
+/// so deserialization always fails.
+#[derive(Clone, Default)]
+pub(crate) struct TestLoader;
+
+impl Loader<TestProto> for TestLoader {
+    type Error = SchematicError;
+
+    fn deserialize(
+        _bytes: &[u8],
+        _ctx: &mut ProtoLoadContext<TestProto, Self>,
+    ) -> Result<TestProto, Self::Error> {
+        Err(SchematicError::InvalidInput {
+            schematic: "TestProto",
+            reason: String::from("test prototypes cannot be deserialized"),
+        })
+    }
+
+    fn extensions(&self) -> &[&'static str] {
+        &["test.ron"]
+    }
+}
+
+/// Create an [`App`] set up to register and apply [`TestProto`] prototypes.
+pub(crate) fn app() -> App {
+    app_with_config(TestConfig::default())
+}
+
+/// Same as [`app`], but with the given [`TestConfig`].
+pub(crate) fn app_with_config(config: TestConfig) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        TaskPoolPlugin::default(),
+        AssetPlugin::default(),
+        ProtoBackendPlugin::<TestProto, TestLoader, TestConfig>::new().with_config(config),
+    ));
+    app
+}
+
+/// Add the given prototype to the app's [`Assets`].
+///
+/// The prototype is not registered until [`register`] is called.
+pub(crate) fn add(app: &mut App, prototype: TestProto) -> Handle<TestProto> {
+    app.world.resource_mut::<Assets<TestProto>>().add(prototype)
+}
+
+/// Run the app until all prototypes added (or modified) beforehand have been registered.
+///
+/// The first update sends the asset events and the second registers the prototypes.
+pub(crate) fn register(app: &mut App) {
+    app.update();
+    app.update();
+}
+
+/// Queue commands using [`ProtoCommands`] and immediately flush them to the world.
+///
+/// Note that ordered and budgeted insertions still require an [update] to be applied.
+///
+/// [update]: App::update
+pub(crate) fn run_commands<R>(
+    app: &mut App,
+    f: impl FnOnce(&mut ProtoCommands<TestProto, TestConfig>) -> R,
+) -> R {
+    let mut state = SystemState::<ProtoCommands<TestProto, TestConfig>>::new(&mut app.world);
+    let result = f(&mut state.get_mut(&mut app.world));
+    state.apply(&mut app.world);
+    result
+}
@@ -5,16 +5,31 @@ use std::slice::Iter;
 use std::str::FromStr;
 
 use bevy::prelude::Entity;
-use bevy::reflect::{std_traits::ReflectDefault, Reflect, ReflectDeserialize};
+use bevy::reflect::{std_traits::ReflectDefault, FromType, Reflect, ReflectDeserialize};
 use serde::Deserialize;
 
 use crate::schematics::{FromSchematicInput, SchematicContext, SchematicId};
 
+/// [Type data] used to identify entity reference types, such as [`ProtoEntity`]
+/// and [`EntityAccess`], through reflection.
+///
+/// This is registered automatically along with the entity reference types in this module.
+///
+/// [Type data]: bevy::reflect::TypeData
+#[derive(Clone)]
+pub struct ReflectProtoEntity;
+
+impl<T> FromType<T> for ReflectProtoEntity {
+    fn from_type() -> Self {
+        Self
+    }
+}
+
 /// A deserializable prototype entity reference.
 ///
 /// [prototype]: crate::proto::Prototypical
 #[derive(Clone, Debug, PartialEq, Reflect, Deserialize)]
-#[reflect(Deserialize)]
+#[reflect(ProtoEntity, Deserialize)]
 pub enum ProtoEntity {
     /// Access the entity from the given access path.
     EntityPath(PathBuf),
@@ -28,6 +43,13 @@ pub enum ProtoEntity {
     Ancestor(usize),
     /// Access the root entity.
     Root,
+    /// Access the entity with the given [local ID].
+    ///
+    /// Local IDs are unique within a prototype's entire tree and may be used to
+    /// reference any entity in the tree, regardless of where it is defined.
+    ///
+    /// [local ID]: crate::children::PrototypicalChild::local_id
+    Local(String),
 }
 
 /// Determines how a child entity is accessed.
@@ -39,6 +61,16 @@ pub enum ChildAccess {
     /// The second argument denotes the occurrence.
     /// A negative occurrence begins the search from the last child.
     ///
+    /// Occurrences are 1-based and only count children with a matching ID,
+    /// in the order they were declared.
+    /// For example, given the children `[X, Y, X, X]`,
+    /// `@1:X` is the first child, `@2:X` is the third child,
+    /// and `@-1:X` is the fourth child.
+    ///
+    /// Since child entities are matched by their position among their siblings,
+    /// this resolution remains stable across hot-reloads as long as the
+    /// declaration order of the children is unchanged.
+    ///
     /// # Example
     ///
     /// ```
@@ -142,6 +174,8 @@ pub(crate) enum AccessOp {
     Child(ChildAccess),
     /// Access a sibling entity.
     Sibling(SiblingAccess),
+    /// Access the entity with the given local ID.
+    Local(String),
 }
 
 /// An accessor used to retrieve an [`Entity`] within an [`EntityTree`].
@@ -164,11 +198,12 @@ pub(crate) enum AccessOp {
 /// | `./~2`, `~2`         | [`SiblingAccess::At`]                  |
 /// | `./~foo`, `~foo`     | [`SiblingAccess::Id`] (1st occurrence) |
 /// | `./~2:foo`, `~2:foo` | [`SiblingAccess::Id`] (nth occurrence) |
+/// | `#foo`               | [`ProtoEntity::Local`]                 |
 ///
 ///
 /// [`EntityTree`]: crate::tree::EntityTree
 #[derive(Default, Clone, Eq, PartialEq, Reflect, Deserialize)]
-#[reflect(Default, Deserialize)]
+#[reflect(ProtoEntity, Default, Deserialize)]
 #[serde(from = "ProtoEntity")]
 pub struct EntityAccess {
     ops: Vec<AccessOp>,
@@ -204,6 +239,14 @@ impl EntityAccess {
         self
     }
 
+    /// Add a [local ID] access.
+    ///
+    /// [local ID]: ProtoEntity::Local
+    pub fn local<S: Into<String>>(mut self, id: S) -> Self {
+        self.ops.push(AccessOp::Local(id.into()));
+        self
+    }
+
     /// Create an access path using the operations in this struct.
     pub fn to_path(&self) -> PathBuf {
         let mut path = if matches!(self.ops.first(), Some(AccessOp::Root)) {
@@ -236,6 +279,7 @@ impl EntityAccess {
                         format!("~{}", id)
                     })
                 }
+                AccessOp::Local(id) => path.push(format!("#{}", id)),
             }
         }
 
@@ -268,6 +312,7 @@ impl From<ProtoEntity> for EntityAccess {
                 access
             }
             ProtoEntity::Root => EntityAccess::root(),
+            ProtoEntity::Local(id) => EntityAccess::default().local(id),
         }
     }
 }
@@ -327,6 +372,9 @@ impl<T: AsRef<Path>> From<T> for EntityAccess {
                                 .ops
                                 .push(AccessOp::Sibling(SiblingAccess::At(offset)));
                         }
+                    } else if let Some(id) = path.strip_prefix('#') {
+                        // "#baz"
+                        access.ops.push(AccessOp::Local(id.trim().to_string()));
                     } else {
                         // "foo/bar/baz"
                         access.ops.push(AccessOp::Child(ChildAccess::Id(
@@ -388,7 +436,7 @@ impl FromSchematicInput<ProtoEntity> for Option<Entity> {
 /// ```
 ///
 #[derive(Default, Clone, PartialEq, Reflect, Deserialize)]
-#[reflect(Default, Deserialize)]
+#[reflect(ProtoEntity, Default, Deserialize)]
 #[serde(transparent)]
 pub struct ProtoEntityList(pub Vec<ProtoEntity>);
 
@@ -411,6 +459,61 @@ impl FromSchematicInput<ProtoEntityList> for Vec<Entity> {
     }
 }
 
+/// A helper enum to deserialize `Vec<Entity>` by matching the IDs of the
+/// prototypes within the [`EntityTree`].
+///
+/// Unlike [`ProtoEntityList`], which selects entities by position,
+/// this selects every entity in the spawned tree whose prototype ID matches.
+/// Entities are returned in breadth-first order and nodes without an entity are ignored.
+///
+/// # Example
+///
+/// ```ignore
+/// # use bevy::prelude::{Entity, Reflect};
+/// # use bevy_proto_backend::tree::ProtoEntityByName;
+/// # use bevy_proto_backend::schematics::{Schematic, ReflectSchematic};
+/// #[derive(Reflect, Schematic)]
+/// #[reflect(Schematic)]
+/// struct Patrol {
+///   // In a prototype file: `waypoints: Prefix("Waypoint")`
+///   #[schematic(from = ProtoEntityByName)]
+///   waypoints: Vec<Entity>
+/// }
+/// ```
+///
+/// [`EntityTree`]: crate::tree::EntityTree
+#[derive(Clone, Debug, PartialEq, Reflect, Deserialize)]
+#[reflect(ProtoEntity, Deserialize)]
+pub enum ProtoEntityByName {
+    /// Matches prototypes whose ID is exactly equal to the given string.
+    Exact(String),
+    /// Matches prototypes whose ID starts with the given string.
+    Prefix(String),
+}
+
+impl ProtoEntityByName {
+    /// Returns true if the given prototype ID is a match.
+    pub fn matches(&self, id: &str) -> bool {
+        match self {
+            Self::Exact(name) => id == name,
+            Self::Prefix(prefix) => id.starts_with(prefix.as_str()),
+        }
+    }
+}
+
+impl FromSchematicInput<ProtoEntityByName> for Vec<Entity> {
+    fn from_input(
+        input: ProtoEntityByName,
+        _id: SchematicId,
+        context: &mut SchematicContext,
+    ) -> Self {
+        context
+            .tree()
+            .find_entities(|id| input.matches(id))
+            .collect()
+    }
+}
+
 impl Debug for EntityAccess {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self.to_path())
@@ -1,18 +1,20 @@
 use std::borrow::Cow;
 
-use bevy::asset::{Assets, Handle};
+use bevy::asset::{Assets, Handle, HandleId};
+use bevy::log::{info_span, warn};
 
 use crate::children::{Children, MergeKey, PrototypicalChild};
 use crate::cycles::{Cycle, CycleChecker, CycleNode, CycleResponse};
 use crate::proto::{Config, ProtoError, Prototypical};
 use crate::registration::ProtoRegistry;
-use crate::templates::Templates;
+use crate::templates::{ProtoMixins, Templates};
 use crate::tree::ProtoTree;
 
 /// Cache object used to create [`ProtoTree`] objects.
 pub(crate) struct ProtoTreeBuilder<'a, T: Prototypical, C: Config<T>> {
     registry: &'a mut ProtoRegistry<T, C>,
     prototypes: &'a Assets<T>,
+    mixins: &'a ProtoMixins,
     config: &'a C,
 }
 
@@ -20,11 +22,13 @@ impl<'a, T: Prototypical, C: Config<T>> ProtoTreeBuilder<'a, T, C> {
     pub fn new(
         registry: &'a mut ProtoRegistry<T, C>,
         prototypes: &'a Assets<T>,
+        mixins: &'a ProtoMixins,
         config: &'a C,
     ) -> Self {
         Self {
             registry,
             prototypes,
+            mixins,
             config,
         }
     }
@@ -34,6 +38,7 @@ impl<'a, T: Prototypical, C: Config<T>> ProtoTreeBuilder<'a, T, C> {
     /// [prototype]: Prototypical
     pub fn build(&mut self, handle: &Handle<T>) -> Result<(), ProtoError> {
         let prototype = self.get_prototype(handle)?;
+        let _span = info_span!("build_proto_tree", id = ?prototype.id()).entered();
 
         let mut checker = CycleChecker::<T>::new(Cow::Borrowed(prototype.id()));
         self.recursive_build(prototype, handle.clone_weak(), None, &mut checker)?;
@@ -53,11 +58,13 @@ impl<'a, T: Prototypical, C: Config<T>> ProtoTreeBuilder<'a, T, C> {
         checker: &mut CycleChecker<'a, T>,
     ) -> Result<Option<ProtoTree<T>>, ProtoError> {
         let handle_id = handle.id();
-        if let Some(tree) = self.registry.get_tree(&handle).cloned() {
+        if let Some(tree) = self.registry.get_tree(handle).cloned() {
             // Tree already cached -> return that value
             return Ok(Some(tree));
         }
 
+        self.resolve_mixins(prototype, handle_id);
+
         let mut tree = ProtoTree::new(handle, merge_key, prototype);
 
         if let Some(children) = prototype.children() {
@@ -66,6 +73,17 @@ impl<'a, T: Prototypical, C: Config<T>> ProtoTreeBuilder<'a, T, C> {
         if let Some(templates) = prototype.templates() {
             self.recurse_templates(templates, &mut tree, checker)?;
         }
+        if prototype.uses_default_templates() {
+            self.recurse_default_templates(prototype, &mut tree, checker)?;
+        }
+
+        // Local IDs must be unique across the entire tree, not just within a single file
+        if let Some(local_id) = tree.find_duplicate_local_id() {
+            return Err(ProtoError::DuplicateLocalId {
+                id: prototype.id().to_string(),
+                local_id: local_id.to_string(),
+            });
+        }
 
         if !tree.requires_entity() && !tree.children().is_empty() {
             return Err(ProtoError::RequiresEntity {
@@ -73,18 +91,121 @@ impl<'a, T: Prototypical, C: Config<T>> ProtoTreeBuilder<'a, T, C> {
             });
         }
 
+        if self.config.cascade_templates() {
+            tree.cascade_templates();
+        }
+
         self.registry.insert_tree(handle_id, tree);
         Ok(self.registry.get_tree(handle_id).cloned())
     }
 
+    /// Resolve the [mixins] included by the given [prototype].
+    ///
+    /// Mixins that don't exist are reported once here, rather than every time
+    /// the prototype is applied.
+    ///
+    /// [mixins]: ProtoMixins
+    /// [prototype]: Prototypical
+    fn resolve_mixins(&mut self, prototype: &T, handle_id: HandleId) {
+        let Some(included) = prototype.mixins() else {
+            return;
+        };
+
+        let resolved = included
+            .iter()
+            .filter(|name| {
+                let exists = self.mixins.contains(name);
+                if !exists {
+                    warn!(
+                        "prototype {:?} includes unknown mixin {:?}: skipping",
+                        prototype.id(),
+                        name
+                    );
+                }
+                exists
+            })
+            .map(ToString::to_string)
+            .collect();
+
+        self.registry.insert_mixins(handle_id, resolved);
+    }
+
     fn recurse_templates(
         &mut self,
         templates: &'a Templates,
         tree: &mut ProtoTree<T>,
         checker: &mut CycleChecker<'a, T>,
     ) -> Result<(), ProtoError> {
-        for (_, template_handle) in templates.iter() {
+        for (template_path, template_handle) in templates.iter() {
             let template_handle = template_handle.typed_weak();
+            let template_prototype = match self.get_prototype(&template_handle) {
+                Ok(template_prototype) => template_prototype,
+                Err(_) if self.config.allow_missing_templates() => {
+                    warn!(
+                        "prototype {:?} references missing template {:?}: skipping",
+                        tree.id_str(),
+                        template_path
+                    );
+                    continue;
+                }
+                Err(err) => return Err(err),
+            };
+
+            self.registry
+                .add_dependent(template_handle.id(), tree.handle());
+
+            let node = CycleNode::Template {
+                id: Cow::Borrowed(template_prototype.id()),
+            };
+            if let Err(cycle) = checker.try_push(node) {
+                if self.handle_cycle(cycle)? {
+                    checker.pop();
+                    continue;
+                } else {
+                    checker.pop();
+                    return Ok(());
+                }
+            }
+
+            if let Some(template_tree) =
+                self.recursive_build(template_prototype, template_handle, None, checker)?
+            {
+                tree.inherit(template_tree.clone());
+            }
+
+            checker.pop();
+        }
+        Ok(())
+    }
+
+    /// Inherit the [default templates] defined by the config.
+    ///
+    /// These are inherited after all other templates, giving them the lowest priority.
+    /// Prototypes that are themselves default templates do not inherit them.
+    ///
+    /// Default templates are looked up by ID among the registered prototypes.
+    /// Any that are not yet registered are skipped until they are.
+    ///
+    /// [default templates]: Config::default_templates
+    fn recurse_default_templates(
+        &mut self,
+        prototype: &'a T,
+        tree: &mut ProtoTree<T>,
+        checker: &mut CycleChecker<'a, T>,
+    ) -> Result<(), ProtoError> {
+        let default_templates = self.config.default_templates();
+        if default_templates.contains(prototype.id()) {
+            return Ok(());
+        }
+
+        for template_id in default_templates {
+            let Some(template_handle) = self.registry.get_handle(template_id).cloned() else {
+                // The template may simply not be registered yet,
+                // in which case this prototype will be rebuilt once it is
+                self.registry
+                    .await_default_template(template_id.clone(), tree.handle());
+                continue;
+            };
             let template_prototype = self.get_prototype(&template_handle)?;
 
             self.registry
@@ -145,7 +266,9 @@ impl<'a, T: Prototypical, C: Config<T>> ProtoTreeBuilder<'a, T, C> {
             if let Some(child_tree) =
                 self.recursive_build(child_prototype, child_handle, merge_key, checker)?
             {
-                tree.append_child(child_tree.clone());
+                let mut child_tree = child_tree.clone();
+                child_tree.set_local_id(child.local_id().map(ToString::to_string));
+                tree.append_child(child_tree);
             }
 
             checker.pop();
@@ -178,3 +301,218 @@ impl<'a, T: Prototypical, C: Config<T>> ProtoTreeBuilder<'a, T, C> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::asset::{AddAsset, AssetPath, AssetPlugin, AssetServer};
+    use bevy::core::TaskPoolPlugin;
+    use bevy::prelude::{App, Component, Entity};
+    use bevy::reflect::Reflect;
+    use bevy_proto_derive::Schematic;
+
+    use crate::test_utils::{self, TestConfig, TestProto};
+
+    use super::*;
+
+    /// Builds the tree for a prototype whose only template is not loaded.
+    fn build_with_missing_template(
+        config: &TestConfig,
+    ) -> (
+        Result<(), ProtoError>,
+        ProtoRegistry<TestProto, TestConfig>,
+        HandleId,
+    ) {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .add_asset::<TestProto>();
+
+        let missing = app
+            .world
+            .resource::<AssetServer>()
+            .get_handle_untyped(HandleId::random::<TestProto>());
+
+        let mut templates = Templates::default();
+        templates.insert(AssetPath::from("Missing.prototype.ron"), missing);
+
+        let mut prototypes = app.world.resource_mut::<Assets<TestProto>>();
+        let handle = prototypes.add(TestProto {
+            templates,
+            ..TestProto::new("Root")
+        });
+
+        let mut registry = ProtoRegistry::default();
+        let result =
+            ProtoTreeBuilder::new(&mut registry, &prototypes, &ProtoMixins::default(), config)
+                .build(&handle);
+        (result, registry, handle.id())
+    }
+
+    #[test]
+    fn should_fail_on_missing_template_by_default() {
+        let (result, registry, handle) = build_with_missing_template(&TestConfig::default());
+
+        assert!(matches!(result, Err(ProtoError::DoesNotExist(_))));
+        assert!(registry.get_tree(handle).is_none());
+    }
+
+    #[test]
+    fn should_skip_missing_template_when_allowed() {
+        let (result, registry, handle) = build_with_missing_template(&TestConfig {
+            allow_missing_templates: true,
+            ..Default::default()
+        });
+
+        assert!(result.is_ok());
+        let tree = registry.get_tree(handle).unwrap();
+        assert_eq!(1, tree.prototypes().len());
+        assert_eq!(handle, tree.handle());
+    }
+
+    #[derive(Component, Reflect, Schematic, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Schematic, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Armor(u32);
+
+    fn default_template_app() -> App {
+        let mut app = test_utils::app_with_config(TestConfig {
+            default_templates: vec![String::from("Base")],
+            ..Default::default()
+        });
+        app.register_type::<Health>().register_type::<Armor>();
+        app
+    }
+
+    fn add_base(app: &mut App) -> Handle<TestProto> {
+        test_utils::add(
+            app,
+            TestProto::new("Base")
+                .with_schematic::<Health>(Health(1))
+                .with_schematic::<Armor>(Armor(5)),
+        )
+    }
+
+    fn spawn(app: &mut App, id: &str) -> Entity {
+        test_utils::run_commands(app, |commands| commands.spawn(id).id())
+    }
+
+    #[test]
+    fn should_inherit_default_templates() {
+        let mut app = default_template_app();
+        let base = add_base(&mut app);
+        test_utils::register(&mut app);
+        let root = test_utils::add(
+            &mut app,
+            TestProto::new("Root").with_schematic::<Health>(Health(10)),
+        );
+        test_utils::register(&mut app);
+
+        let tree = app
+            .world
+            .resource::<ProtoRegistry<TestProto, TestConfig>>()
+            .get_tree(root.id())
+            .unwrap();
+        assert_eq!(
+            vec![root.id(), base.id()],
+            tree.prototypes().iter().copied().collect::<Vec<_>>()
+        );
+
+        // The prototype's own schematics take precedence over the default template's
+        let entity = spawn(&mut app, "Root");
+        assert_eq!(Some(&Health(10)), app.world.get::<Health>(entity));
+        assert_eq!(Some(&Armor(5)), app.world.get::<Armor>(entity));
+
+        // The default template does not inherit itself
+        let tree = app
+            .world
+            .resource::<ProtoRegistry<TestProto, TestConfig>>()
+            .get_tree(base.id())
+            .unwrap();
+        assert_eq!(1, tree.prototypes().len());
+    }
+
+    #[test]
+    fn should_inherit_default_templates_registered_later() {
+        let mut app = default_template_app();
+        test_utils::add(
+            &mut app,
+            TestProto::new("Root").with_schematic::<Health>(Health(10)),
+        );
+        test_utils::register(&mut app);
+
+        let entity = spawn(&mut app, "Root");
+        assert_eq!(None, app.world.get::<Armor>(entity));
+
+        add_base(&mut app);
+        test_utils::register(&mut app);
+
+        let entity = spawn(&mut app, "Root");
+        assert_eq!(Some(&Health(10)), app.world.get::<Health>(entity));
+        assert_eq!(Some(&Armor(5)), app.world.get::<Armor>(entity));
+    }
+
+    #[test]
+    fn should_resolve_mixins() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .add_asset::<TestProto>();
+
+        let mut mixins = ProtoMixins::default();
+        mixins.insert("Physics", Default::default());
+        mixins.insert("Audio", Default::default());
+
+        let mut prototypes = app.world.resource_mut::<Assets<TestProto>>();
+        let root = prototypes.add(
+            TestProto::new("Root")
+                .with_mixin("Audio")
+                .with_mixin("Missing")
+                .with_mixin("Physics"),
+        );
+
+        let mut registry = ProtoRegistry::default();
+        ProtoTreeBuilder::new(&mut registry, &prototypes, &mixins, &TestConfig::default())
+            .build(&root)
+            .unwrap();
+
+        // Missing mixins are dropped and the rest keep their inclusion order
+        assert_eq!(
+            &[String::from("Audio"), String::from("Physics")],
+            registry.get_mixins(root.id())
+        );
+    }
+
+    #[test]
+    fn should_fail_on_duplicate_local_ids_across_files() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .add_asset::<TestProto>();
+
+        let mut prototypes = app.world.resource_mut::<Assets<TestProto>>();
+        let leaf = prototypes.add(TestProto::new("Leaf"));
+        // Each prototype only uses the local ID once within its own "file"
+        let branch = prototypes.add(TestProto::new("Branch").with_local_child(&leaf, "mount"));
+        let root = prototypes.add(
+            TestProto::new("Root")
+                .with_local_child(&leaf, "mount")
+                .with_child(&branch),
+        );
+
+        let mut registry = ProtoRegistry::default();
+        let mixins = ProtoMixins::default();
+        let config = TestConfig::default();
+
+        ProtoTreeBuilder::new(&mut registry, &prototypes, &mixins, &config)
+            .build(&branch)
+            .unwrap();
+
+        let result =
+            ProtoTreeBuilder::new(&mut registry, &prototypes, &mixins, &config).build(&root);
+        assert!(matches!(
+            result,
+            Err(ProtoError::DuplicateLocalId { id, local_id }) if id == "Root" && local_id == "mount"
+        ));
+        assert!(registry.get_tree(root.id()).is_none());
+    }
+}
@@ -17,7 +17,8 @@ use crate::tree::{AccessOp, ChildAccess, EntityAccess, ProtoTree, SiblingAccess}
 
 /// A tree structure containing all the entities to be mutated by a [prototype].
 ///
-/// The tree is processed breadth-first by [`ProtoCommands`].
+/// By default, the tree is processed breadth-first by [`ProtoCommands`]
+/// (see [`EntityTreeOrder`] for other options).
 /// However, it is generated with all the necessary entities,
 /// allowing those entities to be safely retrieved within a [`Schematic`].
 ///
@@ -31,6 +32,10 @@ pub struct EntityTree<'a> {
     parents: HashMap<usize, usize>,
     /// A mapping of a node to its collection of children.
     children: HashMap<usize, EntityChildren<'a>>,
+    /// A mapping of [local IDs] to their node.
+    ///
+    /// [local IDs]: crate::children::PrototypicalChild::local_id
+    local_ids: HashMap<&'a str, usize>,
     /// The current node index being processed.
     ///
     /// This must be manually updated during processing.
@@ -42,6 +47,27 @@ impl<'a> EntityTree<'a> {
         tree: &'a ProtoTree<T>,
         root: Option<Entity>,
         world: &mut World,
+    ) -> Self {
+        Self::build(tree, root, world, true)
+    }
+
+    /// Creates a tree for the given root entity using only its existing child entities.
+    ///
+    /// Unlike [`EntityTree::new`], no entities are spawned:
+    /// nodes whose entity could not be found are left without one.
+    pub(crate) fn existing<T: Prototypical>(
+        tree: &'a ProtoTree<T>,
+        root: Option<Entity>,
+        world: &mut World,
+    ) -> Self {
+        Self::build(tree, root, world, false)
+    }
+
+    fn build<T: Prototypical>(
+        tree: &'a ProtoTree<T>,
+        root: Option<Entity>,
+        world: &mut World,
+        spawn_missing: bool,
     ) -> Self {
         let mut nodes = vec![EntityTreeNode {
             id: tree.id_str(),
@@ -54,6 +80,7 @@ impl<'a> EntityTree<'a> {
 
         let mut parents = HashMap::<usize, usize>::new();
         let mut children = HashMap::<usize, EntityChildren>::new();
+        let mut local_ids = HashMap::<&str, usize>::new();
 
         while let Some((parent_index, parent_entity, tree)) = queue.pop_front() {
             let mut entity_children = EntityChildren::default();
@@ -64,14 +91,21 @@ impl<'a> EntityTree<'a> {
                 let child_index = entity_children.insert(index, child.id_str());
                 parents.insert(index, parent_index);
 
-                let entity = if child.requires_entity() {
+                if let Some(local_id) = child.local_id() {
+                    local_ids.entry(local_id).or_insert(index);
+                }
+
+                let entity = if !child.requires_entity() {
+                    None
+                } else if spawn_missing {
                     Some(Self::init_entity(
-                        ProtoInstance::new(child.handle(), child_index),
+                        child.handle(),
+                        child_index,
                         parent_entity,
                         world,
                     ))
                 } else {
-                    None
+                    Self::find_existing_entity(child.handle(), child_index, parent_entity, world)
                 };
 
                 nodes.push(EntityTreeNode {
@@ -91,6 +125,25 @@ impl<'a> EntityTree<'a> {
             nodes: nodes.into_boxed_slice(),
             parents,
             children,
+            local_ids,
+            current: Cell::new(0),
+        }
+    }
+
+    /// Creates a tree containing only a root node for the given entity.
+    #[cfg(test)]
+    pub(crate) fn single(entity: Option<Entity>, prototypes: &'a IndexSet<HandleId>) -> Self {
+        Self {
+            nodes: vec![EntityTreeNode {
+                id: "Root",
+                index: 0,
+                entity,
+                prototypes,
+            }]
+            .into_boxed_slice(),
+            parents: HashMap::new(),
+            children: HashMap::new(),
+            local_ids: HashMap::new(),
             current: Cell::new(0),
         }
     }
@@ -101,10 +154,26 @@ impl<'a> EntityTree<'a> {
     }
 
     /// Find an entity in the tree using the given [`EntityAccess`].
+    ///
+    /// When accessing a parent (or ancestor), nodes that do not have an entity
+    /// are skipped, resolving to the nearest ancestor that does.
     pub fn find_entity(&self, access: &EntityAccess) -> Option<Entity> {
         self.get(access).and_then(EntityTreeNode::entity)
     }
 
+    /// Returns an iterator over the entities in the tree whose prototype ID
+    /// satisfies the given predicate.
+    ///
+    /// Nodes are visited in breadth-first order and nodes without an entity are skipped.
+    pub fn find_entities<'t, F>(&'t self, predicate: F) -> impl Iterator<Item = Entity> + 't
+    where
+        F: Fn(&str) -> bool + 't,
+    {
+        self.iter(EntityTreeOrder::BreadthFirst)
+            .filter(move |node| predicate(node.id()))
+            .filter_map(EntityTreeNode::entity)
+    }
+
     pub(crate) fn get(&self, access: &EntityAccess) -> Option<&EntityTreeNode<'a>> {
         let mut current = self.current.get();
         for op in access.ops() {
@@ -114,6 +183,15 @@ impl<'a> EntityTree<'a> {
                 }
                 AccessOp::Parent => {
                     current = *self.parents.get(&current)?;
+
+                    // Nodes that don't require an entity aren't part of the entity hierarchy,
+                    // so skip them in favor of the nearest ancestor that does have one
+                    while self.nodes[current].entity.is_none() {
+                        match self.parents.get(&current) {
+                            Some(parent) => current = *parent,
+                            None => break,
+                        }
+                    }
                 }
                 AccessOp::Child(ChildAccess::At(index)) => {
                     current = self.children.get(&current)?.get_at(*index)?;
@@ -158,17 +236,23 @@ impl<'a> EntityTree<'a> {
                         .iter()
                         .position(|child| *child == current)?;
 
+                    // The search range must exclude the current node, so bail early
+                    // if there are no siblings in the requested direction
                     let (start, end) = if occurrence.get().is_negative() {
-                        (child_index.saturating_sub(1), 0)
+                        (child_index.checked_sub(1)?, 0)
                     } else {
-                        (
-                            child_index.saturating_add(1),
-                            siblings.len().saturating_sub(1),
-                        )
+                        let start = child_index + 1;
+                        if start >= siblings.len() {
+                            return None;
+                        }
+                        (start, siblings.len() - 1)
                     };
 
                     current = siblings.get(id, start, end, occurrence.unsigned_abs())?;
                 }
+                AccessOp::Local(id) => {
+                    current = *self.local_ids.get(id.as_str())?;
+                }
             }
         }
 
@@ -183,20 +267,29 @@ impl<'a> EntityTree<'a> {
         self.current.set(node.index);
     }
 
-    pub(crate) fn iter(&self) -> EntityTreeIter<'_, 'a, '_> {
-        EntityTreeIter::from_index(0, self)
+    pub(crate) fn iter(&self, order: EntityTreeOrder) -> EntityTreeIter<'_, 'a, '_> {
+        match order {
+            EntityTreeOrder::BreadthFirst => EntityTreeIter::from_index(0, self),
+            EntityTreeOrder::PostOrder => EntityTreeIter::post_order(0, self),
+        }
     }
 
     /// Spawns an [`Entity`] with the proper parent-child relationship,
     /// along with any additional components.
     ///
-    /// If a child entity with the given [`ProtoInstance`] already exists,
+    /// If a child entity with a matching [`ProtoInstance`] already exists,
     /// it will be returned instead of spawning a new entity.
-    fn init_entity(instance: ProtoInstance, parent: Option<Entity>, world: &mut World) -> Entity {
-        if let Some(entity) = Self::find_existing_entity(&instance, parent, world) {
+    fn init_entity(
+        handle: HandleId,
+        child_index: usize,
+        parent: Option<Entity>,
+        world: &mut World,
+    ) -> Entity {
+        if let Some(entity) = Self::find_existing_entity(handle, child_index, parent, world) {
             return entity;
         }
 
+        let instance = ProtoInstance::new(handle, child_index, world);
         let entity = world.spawn(instance).id();
         if let Some(parent) = parent {
             Command::apply(
@@ -210,9 +303,11 @@ impl<'a> EntityTree<'a> {
         entity
     }
 
-    /// Attempts to find an existing child entity with the given [`ProtoInstance`].
+    /// Attempts to find an existing child entity whose [`ProtoInstance`] matches
+    /// the given prototype handle and child index.
     fn find_existing_entity(
-        instance: &ProtoInstance,
+        handle: HandleId,
+        child_index: usize,
         parent: Option<Entity>,
         world: &mut World,
     ) -> Option<Entity> {
@@ -220,7 +315,8 @@ impl<'a> EntityTree<'a> {
         let children = world.get::<bevy::prelude::Children>(parent)?;
         for child in children {
             if let Some(child_instance) = world.get::<ProtoInstance>(*child) {
-                if child_instance == instance {
+                if child_instance.handle() == handle && child_instance.child_index() == child_index
+                {
                     return Some(*child);
                 }
             }
@@ -405,10 +501,33 @@ impl<'a> EntityChildren<'a> {
     }
 }
 
-/// Iterator that iterates over an [`EntityTree`] in breadth-first order.
+/// The order in which the nodes of an [`EntityTree`] are processed.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum EntityTreeOrder {
+    /// Process the tree breadth-first, starting at the root.
+    ///
+    /// This guarantees that a parent's schematics are applied before those of its children.
+    ///
+    /// This is the default order.
+    #[default]
+    BreadthFirst,
+    /// Process the tree depth-first, visiting each node only after all of its descendants.
+    ///
+    /// This guarantees that a child is fully built before any schematic on its parent is applied,
+    /// allowing parents to read data from their descendants (e.g. to aggregate their bounds).
+    ///
+    /// Siblings are still processed in the order they were defined.
+    PostOrder,
+}
+
+/// Iterator that iterates over an [`EntityTree`] in a given [`EntityTreeOrder`].
 pub(crate) struct EntityTreeIter<'a: 'node, 'tree, 'node> {
     tree: &'a EntityTree<'tree>,
     queue: VecDeque<&'node EntityTreeNode<'tree>>,
+    /// Whether children should be queued as nodes are visited.
+    ///
+    /// This is `false` when the full order has already been computed up front.
+    expand: bool,
 }
 
 impl<'a: 'node, 'tree, 'node> EntityTreeIter<'a, 'tree, 'node> {
@@ -418,7 +537,40 @@ impl<'a: 'node, 'tree, 'node> EntityTreeIter<'a, 'tree, 'node> {
             .get(root)
             .map(|node| VecDeque::from([node]))
             .unwrap_or_default();
-        Self { tree, queue }
+        Self {
+            tree,
+            queue,
+            expand: true,
+        }
+    }
+
+    fn post_order(root: usize, tree: &'a EntityTree<'tree>) -> Self {
+        let mut queue = VecDeque::with_capacity(tree.nodes.len());
+        // Stack of (node index, whether its children have already been pushed)
+        let mut stack = vec![(root, false)];
+
+        while let Some((index, expanded)) = stack.pop() {
+            let Some(node) = tree.nodes.get(index) else {
+                continue;
+            };
+
+            if expanded {
+                queue.push_back(node);
+                continue;
+            }
+
+            stack.push((index, true));
+            if let Some(children) = tree.children.get(&index) {
+                // Push in reverse so that siblings are visited in order
+                stack.extend(children.iter().rev().map(|child| (*child, false)));
+            }
+        }
+
+        Self {
+            tree,
+            queue,
+            expand: false,
+        }
     }
 }
 
@@ -428,13 +580,234 @@ impl<'a: 'node, 'tree, 'node> Iterator for EntityTreeIter<'a, 'tree, 'node> {
     fn next(&mut self) -> Option<Self::Item> {
         let node = self.queue.pop_front()?;
 
-        if let Some(children) = self.tree.children.get(&node.index) {
-            for child in children.iter() {
-                let child_node = self.tree.nodes.get(*child).unwrap();
-                self.queue.push_back(child_node);
+        if self.expand {
+            if let Some(children) = self.tree.children.get(&node.index) {
+                for child in children.iter() {
+                    let child_node = self.tree.nodes.get(*child).unwrap();
+                    self.queue.push_back(child_node);
+                }
             }
         }
 
         Some(node)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::ProtoEntity;
+
+    #[test]
+    fn should_skip_entity_less_ancestors() {
+        let prototypes = IndexSet::new();
+        let root = Entity::from_raw(0);
+        let child = Entity::from_raw(1);
+
+        let node = |index: usize, entity: Option<Entity>| EntityTreeNode {
+            id: "Node",
+            index,
+            entity,
+            prototypes: &prototypes,
+        };
+
+        // Root (entity) -> Intermediate (no entity) -> Child (entity)
+        let tree = EntityTree {
+            nodes: vec![node(0, Some(root)), node(1, None), node(2, Some(child))]
+                .into_boxed_slice(),
+            parents: HashMap::from_iter([(1, 0), (2, 1)]),
+            children: HashMap::new(),
+            local_ids: HashMap::new(),
+            current: Cell::new(2),
+        };
+
+        let parent = EntityAccess::from(ProtoEntity::Parent);
+        assert_eq!(Some(root), tree.find_entity(&parent));
+
+        let ancestor = EntityAccess::from(ProtoEntity::Ancestor(2));
+        assert_eq!(None, tree.find_entity(&ancestor));
+    }
+
+    /// Creates a tree with a root and the given children, starting at the child with the given index.
+    ///
+    /// The entity of each child is its index plus one (the root has entity `0`).
+    fn sibling_tree<'a>(
+        ids: &[&'a str],
+        current: usize,
+        prototypes: &'a IndexSet<HandleId>,
+    ) -> EntityTree<'a> {
+        let node = |index: usize, id: &'a str| EntityTreeNode {
+            id,
+            index,
+            entity: Some(Entity::from_raw(index as u32)),
+            prototypes,
+        };
+
+        let mut nodes = vec![node(0, "Root")];
+        let mut parents = HashMap::new();
+        let mut children = EntityChildren::default();
+        for (child_index, id) in ids.iter().enumerate() {
+            let index = child_index + 1;
+            nodes.push(node(index, *id));
+            parents.insert(index, 0);
+            children.insert(index, *id);
+        }
+
+        EntityTree {
+            nodes: nodes.into_boxed_slice(),
+            parents,
+            children: HashMap::from_iter([(0, children)]),
+            local_ids: HashMap::new(),
+            current: Cell::new(current + 1),
+        }
+    }
+
+    fn find_sibling(tree: &EntityTree, id: &str, occurrence: isize) -> Option<u32> {
+        let access = EntityAccess::default().sibling((
+            id.to_string(),
+            std::num::NonZeroIsize::new(occurrence).unwrap(),
+        ));
+        tree.find_entity(&access).map(|entity| entity.index())
+    }
+
+    #[test]
+    fn should_find_siblings_by_id_from_first_child() {
+        let prototypes = IndexSet::new();
+        let tree = sibling_tree(&["Foo", "Bar", "Foo", "Foo"], 0, &prototypes);
+
+        assert_eq!(None, find_sibling(&tree, "Foo", -1));
+        assert_eq!(None, find_sibling(&tree, "Bar", -1));
+        assert_eq!(Some(3), find_sibling(&tree, "Foo", 1));
+        assert_eq!(Some(4), find_sibling(&tree, "Foo", 2));
+        assert_eq!(None, find_sibling(&tree, "Foo", 3));
+        assert_eq!(Some(2), find_sibling(&tree, "Bar", 1));
+    }
+
+    #[test]
+    fn should_find_siblings_by_id_from_last_child() {
+        let prototypes = IndexSet::new();
+        let tree = sibling_tree(&["Foo", "Bar", "Foo", "Foo"], 3, &prototypes);
+
+        assert_eq!(None, find_sibling(&tree, "Foo", 1));
+        assert_eq!(None, find_sibling(&tree, "Bar", 1));
+        assert_eq!(Some(3), find_sibling(&tree, "Foo", -1));
+        assert_eq!(Some(1), find_sibling(&tree, "Foo", -2));
+        assert_eq!(None, find_sibling(&tree, "Foo", -3));
+        assert_eq!(Some(2), find_sibling(&tree, "Bar", -1));
+    }
+
+    #[test]
+    fn should_find_siblings_by_id_from_middle_child() {
+        let prototypes = IndexSet::new();
+        let tree = sibling_tree(&["Foo", "Bar", "Foo", "Foo"], 2, &prototypes);
+
+        assert_eq!(Some(1), find_sibling(&tree, "Foo", -1));
+        assert_eq!(None, find_sibling(&tree, "Foo", -2));
+        assert_eq!(Some(4), find_sibling(&tree, "Foo", 1));
+        assert_eq!(None, find_sibling(&tree, "Foo", 2));
+        assert_eq!(Some(2), find_sibling(&tree, "Bar", -1));
+        assert_eq!(None, find_sibling(&tree, "Bar", 1));
+    }
+
+    #[test]
+    fn should_not_find_self_as_only_sibling() {
+        let prototypes = IndexSet::new();
+        let tree = sibling_tree(&["Foo"], 0, &prototypes);
+
+        assert_eq!(None, find_sibling(&tree, "Foo", 1));
+        assert_eq!(None, find_sibling(&tree, "Foo", -1));
+    }
+
+    #[test]
+    fn should_find_local_ids_across_subtrees() {
+        let prototypes = IndexSet::new();
+        let node = |index: usize, id: &'static str| EntityTreeNode {
+            id,
+            index,
+            entity: Some(Entity::from_raw(index as u32)),
+            prototypes: &prototypes,
+        };
+
+        // Root -> Body -> Mount (#turret_mount)
+        //      -> Turret -> Gun
+        let mut root_children = EntityChildren::default();
+        root_children.insert(1, "Body");
+        root_children.insert(2, "Turret");
+        let mut body_children = EntityChildren::default();
+        body_children.insert(3, "Mount");
+        let mut turret_children = EntityChildren::default();
+        turret_children.insert(4, "Gun");
+
+        let tree = EntityTree {
+            nodes: vec![
+                node(0, "Root"),
+                node(1, "Body"),
+                node(2, "Turret"),
+                node(3, "Mount"),
+                node(4, "Gun"),
+            ]
+            .into_boxed_slice(),
+            parents: HashMap::from_iter([(1, 0), (2, 0), (3, 1), (4, 2)]),
+            children: HashMap::from_iter([
+                (0, root_children),
+                (1, body_children),
+                (2, turret_children),
+            ]),
+            local_ids: HashMap::from_iter([("turret_mount", 3)]),
+            current: Cell::new(4),
+        };
+
+        let access = EntityAccess::from(ProtoEntity::Local(String::from("turret_mount")));
+        assert_eq!(Some(Entity::from_raw(3)), tree.find_entity(&access));
+
+        let access = EntityAccess::from("#turret_mount");
+        assert_eq!(Some(Entity::from_raw(3)), tree.find_entity(&access));
+
+        let access = EntityAccess::from("#missing");
+        assert_eq!(None, tree.find_entity(&access));
+    }
+
+    #[test]
+    fn should_iterate_in_apply_order() {
+        let prototypes = IndexSet::new();
+        let node = |index: usize, id: &'static str| EntityTreeNode {
+            id,
+            index,
+            entity: Some(Entity::from_raw(index as u32)),
+            prototypes: &prototypes,
+        };
+
+        // Root -> Body -> Wheel
+        //      -> Turret
+        let mut root_children = EntityChildren::default();
+        root_children.insert(1, "Body");
+        root_children.insert(2, "Turret");
+        let mut body_children = EntityChildren::default();
+        body_children.insert(3, "Wheel");
+
+        let tree = EntityTree {
+            nodes: vec![
+                node(0, "Root"),
+                node(1, "Body"),
+                node(2, "Turret"),
+                node(3, "Wheel"),
+            ]
+            .into_boxed_slice(),
+            parents: HashMap::from_iter([(1, 0), (2, 0), (3, 1)]),
+            children: HashMap::from_iter([(0, root_children), (1, body_children)]),
+            local_ids: HashMap::new(),
+            current: Cell::new(0),
+        };
+
+        let ids = |order: EntityTreeOrder| tree.iter(order).map(|node| node.id).collect::<Vec<_>>();
+
+        assert_eq!(
+            vec!["Root", "Body", "Turret", "Wheel"],
+            ids(EntityTreeOrder::BreadthFirst)
+        );
+        assert_eq!(
+            vec!["Wheel", "Body", "Turret", "Root"],
+            ids(EntityTreeOrder::PostOrder)
+        );
+    }
+}
@@ -1,8 +1,9 @@
+use std::collections::VecDeque;
 use std::fmt::{Debug, Formatter};
 
 use bevy::asset::{Handle, HandleId};
 use bevy::prelude::{Entity, World};
-use bevy::utils::HashMap;
+use bevy::utils::{HashMap, HashSet};
 use indexmap::IndexSet;
 
 use crate::children::MergeKey;
@@ -27,6 +28,10 @@ pub(crate) struct ProtoTree<T: Prototypical> {
     handle: HandleId,
     /// Whether or not this tree requires an entity to be spawned
     requires_entity: bool,
+    /// Whether or not this prototype is abstract (i.e. may only be used as a template).
+    ///
+    /// Unlike `requires_entity`, this is not inherited from templates.
+    is_abstract: bool,
     /// The set of template prototypes, in their reverse-application order.
     ///
     /// The first entry in the set should be this prototype itself.
@@ -37,6 +42,10 @@ pub(crate) struct ProtoTree<T: Prototypical> {
     ///
     /// [merge key]: MergeKey
     merge_key: Option<MergeKey<T>>,
+    /// The [local ID] of this prototype (if any).
+    ///
+    /// [local ID]: crate::children::PrototypicalChild::local_id
+    local_id: Option<String>,
     /// The immediate children of this tree.
     children: Vec<ProtoTree<T>>,
     /// A mapping of [merge keys] to children.
@@ -51,9 +60,11 @@ impl<T: Prototypical> ProtoTree<T> {
             id: prototype.id().clone(),
             id_str: prototype.id().to_string(),
             requires_entity: prototype.requires_entity(),
+            is_abstract: prototype.is_abstract(),
             handle: handle.id(),
             prototypes: IndexSet::from([handle.id()]),
             merge_key,
+            local_id: None,
             children: Vec::new(),
             merge_keys: HashMap::new(),
         }
@@ -71,6 +82,36 @@ impl<T: Prototypical> ProtoTree<T> {
         self.requires_entity
     }
 
+    pub fn is_abstract(&self) -> bool {
+        self.is_abstract
+    }
+
+    pub fn local_id(&self) -> Option<&str> {
+        self.local_id.as_deref()
+    }
+
+    pub(crate) fn set_local_id(&mut self, local_id: Option<String>) {
+        self.local_id = local_id;
+    }
+
+    /// Returns the first [local ID] that is used by more than one descendant of this tree.
+    ///
+    /// [local ID]: crate::children::PrototypicalChild::local_id
+    pub fn find_duplicate_local_id(&self) -> Option<&str> {
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::from_iter(&self.children);
+        while let Some(tree) = queue.pop_front() {
+            if let Some(local_id) = tree.local_id() {
+                if !seen.insert(local_id) {
+                    return Some(local_id);
+                }
+            }
+            queue.extend(&tree.children);
+        }
+
+        None
+    }
+
     /// Append the given tree as a new child of this one.
     pub fn append_child(&mut self, tree: Self) {
         if let Some(merge_key) = tree.merge_key.as_ref() {
@@ -96,12 +137,31 @@ impl<T: Prototypical> ProtoTree<T> {
         // 2. Update entity requirement
         self.requires_entity |= tree.requires_entity;
 
-        // 3. Merge children
+        // 3. Keep the existing local ID (if any)
+        if self.local_id.is_none() {
+            self.local_id = tree.local_id;
+        }
+
+        // 4. Merge children
         for child in tree.children {
             self.append_child(child);
         }
     }
 
+    /// Adds the templates of this tree to each of its descendants.
+    ///
+    /// Cascaded templates are given the lowest priority,
+    /// such that they are applied before a child's own prototype and templates.
+    pub fn cascade_templates(&mut self) {
+        // The first entry is always this tree's own prototype
+        let templates = self.prototypes.iter().skip(1).copied().collect::<Vec<_>>();
+
+        for child in &mut self.children {
+            child.prototypes.extend(templates.iter().copied());
+            child.cascade_templates();
+        }
+    }
+
     /// The set of prototypes for this tree (in reverse-application order).
     pub fn prototypes(&self) -> &IndexSet<HandleId> {
         &self.prototypes
@@ -125,8 +185,10 @@ impl<T: Prototypical> Clone for ProtoTree<T> {
             id_str: self.id_str.clone(),
             handle: self.handle,
             requires_entity: self.requires_entity,
+            is_abstract: self.is_abstract,
             prototypes: self.prototypes.clone(),
             merge_key: self.merge_key.clone(),
+            local_id: self.local_id.clone(),
             children: self.children.clone(),
             merge_keys: self.merge_keys.clone(),
         }
@@ -139,10 +201,140 @@ impl<T: Prototypical> Debug for ProtoTree<T> {
             .field("id", &self.id)
             .field("handle", &self.handle)
             .field("requires_entity", &self.requires_entity)
+            .field("is_abstract", &self.is_abstract)
             .field("prototypes", &self.prototypes)
             .field("merge_key", &self.merge_key)
+            .field("local_id", &self.local_id)
             .field("children", &self.children)
             .field("merge_keys", &self.merge_keys)
             .finish()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::app::App;
+    use bevy::asset::{Assets, HandleId};
+    use bevy::prelude::{Children, Component, Mut, Reflect};
+    use bevy::reflect::TypeUuid;
+
+    use crate::registration::ProtoRegistry;
+    use crate::schematics::{Schematic, SchematicContext, SchematicId};
+    use crate::test_utils::{self, TestConfig, TestProto};
+
+    use super::*;
+
+    fn tree(
+        handle: u64,
+        templates: &[u64],
+        children: Vec<ProtoTree<TestProto>>,
+    ) -> ProtoTree<TestProto> {
+        let id = |value: u64| HandleId::new(TestProto::TYPE_UUID, value);
+        let mut prototypes = IndexSet::from([id(handle)]);
+        prototypes.extend(templates.iter().map(|value| id(*value)));
+
+        ProtoTree {
+            id: handle.to_string(),
+            id_str: handle.to_string(),
+            handle: id(handle),
+            requires_entity: true,
+            is_abstract: false,
+            prototypes,
+            merge_key: None,
+            local_id: None,
+            children,
+            merge_keys: HashMap::new(),
+        }
+    }
+
+    #[derive(Component)]
+    struct Reloaded;
+
+    /// A schematic that marks its entity as [`Reloaded`] when hot-reloaded.
+    #[derive(Reflect)]
+    struct MarkReloaded;
+
+    impl Schematic for MarkReloaded {
+        type Input = Self;
+
+        fn apply(_: &Self::Input, _: SchematicId, _: &mut SchematicContext) {}
+
+        fn remove(_: &Self::Input, _: SchematicId, _: &mut SchematicContext) {}
+
+        fn reload(_: &Self::Input, _: SchematicId, context: &mut SchematicContext) {
+            context.entity_mut().unwrap().insert(Reloaded);
+        }
+    }
+
+    #[test]
+    fn should_resolve_same_id_children_in_declaration_order() {
+        let mut app = test_utils::app();
+        let x = test_utils::add(
+            &mut app,
+            TestProto::new("X").with_schematic::<MarkReloaded>(MarkReloaded),
+        );
+        let y = test_utils::add(&mut app, TestProto::new("Y"));
+        test_utils::add(
+            &mut app,
+            TestProto::new("Parent")
+                .with_child(&x)
+                .with_child(&y)
+                .with_child(&x)
+                .with_child(&x),
+        );
+        test_utils::register(&mut app);
+
+        let entity = test_utils::run_commands(&mut app, |commands| commands.spawn("Parent").id());
+
+        let resolve = |app: &mut App| {
+            app.world.resource_scope(
+                |world, registry: Mut<ProtoRegistry<TestProto, TestConfig>>| {
+                    let entity_tree = registry
+                        .get_tree_by_id(&String::from("Parent"))
+                        .unwrap()
+                        .to_entity_tree(Some(entity), world);
+                    ["@1:X", "@2:X", "@-1:X"]
+                        .map(|path| entity_tree.find_entity(&path.into()).unwrap())
+                },
+            )
+        };
+
+        let before = resolve(&mut app);
+        let declared = app.world.get::<Children>(entity).unwrap().to_vec();
+        assert_eq!([declared[0], declared[2], declared[3]], before);
+
+        // Modifying `X` reloads it along with its dependent `Parent`
+        app.world
+            .resource_mut::<Assets<TestProto>>()
+            .get_mut(&x)
+            .unwrap();
+        test_utils::register(&mut app);
+
+        for child in before {
+            assert!(app.world.get::<Reloaded>(child).is_some());
+        }
+
+        let after = resolve(&mut app);
+        assert_eq!(before, after);
+        assert_eq!(4, app.world.get::<Children>(entity).unwrap().len());
+    }
+
+    #[test]
+    fn should_cascade_templates() {
+        // Root (Theme) -> Child (Button) -> Grandchild
+        let mut root = tree(0, &[10], vec![tree(1, &[11], vec![tree(2, &[], vec![])])]);
+
+        let expected = tree(
+            0,
+            &[10],
+            vec![tree(1, &[11, 10], vec![tree(2, &[11, 10], vec![])])],
+        );
+        root.cascade_templates();
+
+        assert_eq!(expected.children[0].prototypes, root.children[0].prototypes);
+        assert_eq!(
+            expected.children[0].children[0].prototypes,
+            root.children[0].children[0].prototypes
+        );
+    }
+}
@@ -10,6 +10,14 @@ pub(crate) enum SchematicData {
 }
 
 impl SchematicData {
+    /// Returns true if converting the input to this schematic may fail.
+    pub fn is_fallible(&self) -> bool {
+        match self {
+            Self::Struct(fields) => fields.is_fallible(),
+            Self::Enum(variants) => variants.iter().any(|variant| variant.fields.is_fallible()),
+        }
+    }
+
     pub fn new(data: Data, io: &mut SchematicIo, derive_type: DeriveType) -> Result<Self, Error> {
         match data {
             Data::Struct(data) => Ok(Self::Struct(SchematicFields::new(
@@ -22,6 +30,7 @@ impl SchematicData {
                     .into_iter()
                     .map(|variant| {
                         let fields = SchematicFields::new(&variant.fields, io, derive_type)?;
+                        fields.deny_flatten()?;
                         Ok(SchematicVariant {
                             ident: variant.ident,
                             fields,
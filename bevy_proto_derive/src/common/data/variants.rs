@@ -25,7 +25,8 @@ impl SchematicVariant {
                 for field in fields.iter() {
                     match field.member() {
                         Member::Named(ident) => {
-                            patterns.push(quote!(#ident));
+                            let input_member = field.input_member();
+                            patterns.push(quote!(#input_member: #ident));
 
                             let conversion = field.generate_conversion(Some(quote!(#ident)))?;
                             conversions.push(quote!(#ident: #conversion))
@@ -62,7 +63,8 @@ impl SchematicVariant {
                 for field in fields.iter() {
                     match field.member() {
                         Member::Named(ident) => {
-                            patterns.push(quote!(#ident));
+                            let input_member = field.input_member();
+                            patterns.push(quote!(#input_member: #ident));
 
                             let conversion =
                                 field.generate_preload_conversion(Some(quote!(#ident)))?;
@@ -99,12 +101,14 @@ impl SchematicVariant {
                 let mut preloads = Vec::new();
                 let preload_fields = fields.iter().filter(|field| match field.config().kind() {
                     Some(FieldKind::Asset(config)) => config.preload(),
+                    Some(FieldKind::Each(_)) => true,
                     _ => false,
                 });
                 for field in preload_fields {
                     match field.member() {
                         Member::Named(ident) => {
-                            patterns.push(quote!(#ident));
+                            let input_member = field.input_member();
+                            patterns.push(quote!(#input_member: #ident));
                             preloads.push(field.generate_preload(Some(quote!(*#ident)))?)
                         }
                         Member::Unnamed(index) => {
@@ -13,8 +13,10 @@ define_attribute!("preload" => AssetPreloadArg(bool) for AttrTarget::Asset);
 define_attribute!("inline" => AssetInlineArg(bool) for AttrTarget::Asset);
 define_attribute!("untyped" => AssetUntypedArg(bool) for AttrTarget::Asset);
 define_attribute!("unique" => AssetUniqueArg(bool) for AttrTarget::Asset);
+define_attribute!("reload" => AssetReloadArg(bool) for AttrTarget::Asset);
 define_attribute!("type" => AssetTypeArg(Type) for AttrTarget::Asset);
 define_attribute!("path" => AssetPathArg(LitStr) for AttrTarget::Asset);
+define_attribute!("collection" => AssetCollectionArg(bool) for AttrTarget::Asset);
 
 /// Specifies the configuration for asset-containing fields.
 #[derive(Default)]
@@ -27,6 +29,10 @@ pub(crate) struct AssetConfig {
     ///
     /// Form: `#[asset_schematic(asset(unique))]`.
     unique: AssetUniqueArg,
+    /// Used to specify whether the asset should be re-resolved when the prototype is hot-reloaded.
+    ///
+    /// Form: `#[schematic(asset(reload))]`.
+    reload: AssetReloadArg,
     /// Used to specify whether the asset should be allowed to be defined inlined.
     ///
     /// Form: `#[asset_schematic(asset(inline))]`.
@@ -35,6 +41,10 @@ pub(crate) struct AssetConfig {
     ///
     /// Form: `#[asset_schematic(asset(path = "path/to/asset.png"))]`.
     path: AssetPathArg,
+    /// Used to specify that the field is a `Vec` of handles loaded from the folder at `path`.
+    ///
+    /// Form: `#[schematic(asset(collection, path = "path/to/folder"))]`.
+    collection: AssetCollectionArg,
     /// Used to set the type of the asset.
     ///
     /// Form: `#[asset_schematic(asset(type = path::to::Foo))]`.
@@ -70,6 +80,26 @@ impl AssetConfig {
         self.unique.try_set(Some(value), span)
     }
 
+    pub fn reload(&self) -> bool {
+        self.reload.get().copied().unwrap_or_default()
+    }
+
+    pub fn try_set_reload(&mut self, value: bool, span: Span) -> Result<(), Error> {
+        self.reload.try_set(Some(value), span)
+    }
+
+    pub fn collection(&self) -> bool {
+        self.collection.get().copied().unwrap_or_default()
+    }
+
+    pub fn try_set_collection(&mut self, value: bool, span: Span) -> Result<(), Error> {
+        if self.inline() {
+            return Err(self.collection.invariant_error::<AssetInlineArg>(span));
+        }
+
+        self.collection.try_set(Some(value), span)
+    }
+
     pub fn inline(&self) -> bool {
         self.inline.get().copied().unwrap_or_default()
     }
@@ -79,6 +109,10 @@ impl AssetConfig {
             return Err(self.inline.invariant_error::<AssetPathArg>(span));
         }
 
+        if self.collection() {
+            return Err(self.inline.invariant_error::<AssetCollectionArg>(span));
+        }
+
         self.inline.try_set(Some(value), span)
     }
 
@@ -136,7 +170,8 @@ impl AssetConfig {
         }
     }
 
-    /// Attempts to extract the asset type from either a `Handle` or an `Option<Handle>`.
+    /// Attempts to extract the asset type from either a `Handle`, an `Option<Handle>`,
+    /// or a `Vec<Handle>`.
     fn extract_asset_type(ty: &Type) -> Result<&Type, Error> {
         let create_error = || {
             Error::new(ty.span(), format_args!(
@@ -158,7 +193,7 @@ impl AssetConfig {
 
                     if segment.ident == "Handle" {
                         return Ok(ty);
-                    } else if segment.ident == "Option" {
+                    } else if segment.ident == "Option" || segment.ident == "Vec" {
                         return Self::extract_asset_type(ty);
                     }
                 }
@@ -176,10 +211,12 @@ impl Debug for AssetConfig {
 
         debug_attribute(f, |write| {
             write(format_args!("{:?}", self.preload))?;
+            write(format_args!("{:?}", self.reload))?;
             write(format_args!("{:?}", self.inline))?;
             write(format_args!("{:?}", self.untyped))?;
             write(format_args!("{:?}", self.custom_type))?;
             write(format_args!("{:?}", self.path))?;
+            write(format_args!("{:?}", self.collection))?;
 
             Ok(())
         })?;
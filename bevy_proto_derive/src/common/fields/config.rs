@@ -1,14 +1,19 @@
 use crate::common::data::DeriveType;
 use crate::common::fields::{AssetConfig, EntityConfig};
-use crate::utils::constants::{ASSET_ATTR, ENTITY_ATTR, FROM_ATTR};
+use crate::utils::constants::{
+    ASSET_ATTR, EACH_ATTR, ENTITY_ATTR, FLATTEN_ATTR, FROM_ATTR, TRY_FROM_ATTR,
+};
 use crate::utils::debug_attribute;
 use crate::utils::{define_attribute, AttrArgValue, AttrTarget};
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
 use quote::ToTokens;
 use std::fmt::{Debug, Formatter};
-use syn::{Error, Type};
+use syn::parse::{Parse, ParseStream};
+use syn::{Error, ExprPath, LitStr, Token, Type};
 
 define_attribute!("optional" => OptionalArg(bool) for AttrTarget::Field);
+define_attribute!("rename" => RenameArg(LitStr) for AttrTarget::Field);
+define_attribute!("default_with" => DefaultWithArg(ExprPath) for AttrTarget::Field);
 
 /// The base configuration for the field of a `Schematic` or `AssetSchematic`.
 pub(crate) struct FieldConfig {
@@ -39,6 +44,20 @@ pub(crate) struct FieldConfig {
     ///
     /// [`ProtoFieldBuilder`]: crate::common::fields::ProtoFieldBuilder
     optional: OptionalArg,
+    /// The name of the field in the generated input type.
+    ///
+    /// This allows the field to be given a different name in prototype files
+    /// without renaming the actual field.
+    ///
+    /// Form: `#[schematic(rename = "new_name")]`.
+    rename: RenameArg,
+    /// A function used to compute the field's value when it is missing from the input.
+    ///
+    /// The function is given the `&SchematicContext` and is called during application,
+    /// allowing it to access the world.
+    ///
+    /// Form: `#[schematic(default_with = "path::to::func")]`.
+    default_with: DefaultWithArg,
 }
 
 impl FieldConfig {
@@ -58,6 +77,58 @@ impl FieldConfig {
         Ok(())
     }
 
+    pub fn try_init_try_from_kind(&mut self, ty: Type, span: Span) -> Result<(), Error> {
+        match &self.kind {
+            None => {
+                self.kind = Some(FieldKind::TryFrom(ty));
+            }
+            Some(current) => {
+                return Err(Error::new(
+                    span,
+                    format!("field already configured as `{:?}`", current),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn try_init_flatten_kind(
+        &mut self,
+        fields: Vec<FlattenedField>,
+        span: Span,
+    ) -> Result<(), Error> {
+        match &self.kind {
+            None => {
+                self.kind = Some(FieldKind::Flatten(fields));
+            }
+            Some(current) => {
+                return Err(Error::new(
+                    span,
+                    format!("field already configured as `{:?}`", current),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn try_init_each_kind(&mut self, element_ty: Type, span: Span) -> Result<(), Error> {
+        match &self.kind {
+            None => {
+                self.kind = Some(FieldKind::Each(element_ty));
+            }
+            Some(current) => {
+                return Err(Error::new(
+                    span,
+                    format!("field already configured as `{:?}`", current),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn try_init_entity_kind(&mut self, span: Span) -> Result<&mut EntityConfig, Error> {
         match &self.kind {
             None => {
@@ -108,13 +179,48 @@ impl FieldConfig {
 
     pub fn try_set_optional(&mut self, value: bool, span: Span) -> Result<(), Error> {
         match self.kind() {
-            None | Some(FieldKind::From(_)) => Err(Error::new(
+            None
+            | Some(FieldKind::From(_))
+            | Some(FieldKind::TryFrom(_))
+            | Some(FieldKind::Flatten(_))
+            | Some(FieldKind::Each(_)) => Err(Error::new(
                 span,
                 "cannot set `optional` on a field that is not marked as an `entity` or `asset`",
             )),
             _ => self.optional.try_set(Some(value), span),
         }
     }
+
+    pub fn rename(&self) -> Option<&LitStr> {
+        self.rename.get()
+    }
+
+    pub fn try_set_rename(&mut self, value: LitStr, span: Span) -> Result<(), Error> {
+        // Ensure the new name is a valid field name
+        value.parse::<syn::Ident>().map_err(|_| {
+            Error::new(
+                value.span(),
+                format_args!("`{}` is not a valid field name", value.value()),
+            )
+        })?;
+
+        self.rename.try_set(Some(value), span)
+    }
+
+    pub fn default_with(&self) -> Option<&ExprPath> {
+        self.default_with.get()
+    }
+
+    pub fn try_set_default_with(&mut self, value: LitStr, span: Span) -> Result<(), Error> {
+        let path = value.parse::<ExprPath>().map_err(|_| {
+            Error::new(
+                value.span(),
+                format_args!("`{}` is not a valid function path", value.value()),
+            )
+        })?;
+
+        self.default_with.try_set(Some(path), span)
+    }
 }
 
 impl Default for FieldConfig {
@@ -123,6 +229,8 @@ impl Default for FieldConfig {
             derive_type: DeriveType::Schematic,
             kind: None,
             optional: OptionalArg::default(),
+            rename: RenameArg::default(),
+            default_with: DefaultWithArg::default(),
         }
     }
 }
@@ -134,6 +242,8 @@ impl Debug for FieldConfig {
 
         debug_attribute(f, |write| {
             write(format_args!("{:?}", self.optional))?;
+            write(format_args!("{:?}", self.rename))?;
+            write(format_args!("{:?}", self.default_with))?;
             write(format_args!("{:?}", self.kind))?;
 
             Ok(())
@@ -145,14 +255,52 @@ impl Debug for FieldConfig {
 
 pub(crate) enum FieldKind {
     From(Type),
+    /// A field whose conversion may fail, using `TryFromSchematicInput`.
+    ///
+    /// The contained type is the input type of the field.
+    TryFrom(Type),
+    Flatten(Vec<FlattenedField>),
+    /// A `Vec<T>` whose elements are converted using the input of `T: Schematic`.
+    ///
+    /// The contained type is the element type, `T`.
+    Each(Type),
     Entity(EntityConfig),
     Asset(AssetConfig),
 }
 
+/// A single field hoisted into the parent input type by `#[schematic(flatten(...))]`.
+///
+/// Form: `name: Type`.
+pub(crate) struct FlattenedField {
+    pub ident: Ident,
+    pub ty: Type,
+}
+
+impl Parse for FlattenedField {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse::<Type>()?;
+        Ok(Self { ident, ty })
+    }
+}
+
 impl Debug for FieldKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::From(ty) => write!(f, "{FROM_ATTR} = {}", ty.to_token_stream()),
+            Self::TryFrom(ty) => write!(f, "{TRY_FROM_ATTR} = {}", ty.to_token_stream()),
+            Self::Flatten(fields) => {
+                write!(f, "{FLATTEN_ATTR}(")?;
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", field.ident, field.ty.to_token_stream())?;
+                }
+                write!(f, ")")
+            }
+            Self::Each(_) => write!(f, "{EACH_ATTR}"),
             Self::Entity(config) => write!(f, "{ENTITY_ATTR}{:?}", config),
             Self::Asset(config) => write!(f, "{ASSET_ATTR}{:?}", config),
         }
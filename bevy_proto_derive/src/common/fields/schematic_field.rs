@@ -1,15 +1,19 @@
-use crate::common::fields::{FieldConfig, FieldKind};
+use crate::common::fields::{
+    AssetCollectionArg, AssetConfig, AssetPathArg, FieldConfig, FieldKind,
+};
 use crate::common::input::ForwardAttributes;
-use crate::utils::constants::{CONTEXT_IDENT, DEPENDENCIES_IDENT, INPUT_IDENT, TEMP_IDENT};
+use crate::utils::constants::{
+    ASSET_ATTR, CONTEXT_IDENT, DEPENDENCIES_IDENT, ID_IDENT, INPUT_IDENT, TEMP_IDENT,
+};
 use crate::utils::exports::{
     AssetServer, EntityAccess, FromReflect, FromSchematicInput, FromSchematicPreloadInput,
-    InlinableProtoAsset, ProtoAsset, Reflect,
+    InlinableProtoAsset, ProtoAsset, Reflect, Schematic, SchematicError, TryFromSchematicInput,
 };
-use crate::utils::NextId;
-use proc_macro2::{Span, TokenStream};
+use crate::utils::{AttrArg, NextId};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
-use syn::{parse_quote, Error, Field, Member, Type};
+use syn::{parse_quote, Error, Field, LitStr, Member, Type};
 
 /// The base field information for fields of a `Schematic` or `AssetSchematic`.
 pub(crate) struct SchematicField {
@@ -49,11 +53,31 @@ impl SchematicField {
         &mut self.config
     }
 
+    /// Returns true if this field is an asset marked to be re-resolved on hot-reload.
+    pub fn reload(&self) -> bool {
+        matches!(self.config.kind(), Some(FieldKind::Asset(config)) if config.reload())
+    }
+
+    /// Returns true if this field is marked with `#[schematic(try_from = ...)]`.
+    pub fn is_fallible(&self) -> bool {
+        matches!(self.config.kind(), Some(FieldKind::TryFrom(_)))
+    }
+
     /// The member (ident or index) used to access this field.
     pub fn member(&self) -> &Member {
         &self.member
     }
 
+    /// The member (ident or index) used to access this field within a generated input type.
+    ///
+    /// This will differ from [`SchematicField::member`] if the field has been renamed.
+    pub fn input_member(&self) -> Member {
+        match self.config.rename() {
+            Some(rename) => Member::Named(Ident::new(&rename.value(), rename.span())),
+            None => self.member.clone(),
+        }
+    }
+
     /// The attributes that should be forwarded to the generated input field.
     pub fn forward_attrs(&self) -> &ForwardAttributes {
         &self.forward_attrs
@@ -64,6 +88,23 @@ impl SchematicField {
         &mut self.forward_attrs
     }
 
+    /// Returns the folder path of an asset collection field.
+    ///
+    /// Returns an error if no path was given.
+    fn collection_path<'a>(&self, config: &'a AssetConfig) -> Result<&'a LitStr, Error> {
+        config.path().ok_or_else(|| {
+            Error::new(
+                self.member.span(),
+                format_args!(
+                    "asset collections require a folder path: `{}({}, {} = \"path/to/folder\")`",
+                    ASSET_ATTR,
+                    AssetCollectionArg::NAME,
+                    AssetPathArg::NAME
+                ),
+            )
+        })
+    }
+
     /// Determines whether or not a generated input type should contain this field.
     ///
     /// For fields that cannot be configured by a prototype file (e.g. `path` attributes),
@@ -80,6 +121,17 @@ impl SchematicField {
     ///
     /// This may or may not be the same as the field's user-defined type.
     pub fn input_ty(&self) -> Result<Type, Error> {
+        let ty = self.base_input_ty()?;
+
+        Ok(if self.config.default_with().is_some() {
+            parse_quote!(::core::option::Option<#ty>)
+        } else {
+            ty
+        })
+    }
+
+    /// The type of the field within a generated input type, ignoring any `default_with` wrapping.
+    fn base_input_ty(&self) -> Result<Type, Error> {
         let wrap_option = |ty: Type| -> Type {
             if self.config.optional() {
                 parse_quote!(::core::option::Option<#ty>)
@@ -91,6 +143,16 @@ impl SchematicField {
         Ok(match self.config.kind() {
             None => self.defined_ty.clone(),
             Some(FieldKind::From(ty)) => wrap_option(ty.clone()),
+            Some(FieldKind::TryFrom(ty)) => ty.clone(),
+            Some(FieldKind::Flatten(_)) => {
+                return Err(Error::new(
+                    self.member.span(),
+                    "flattened fields do not have a single input type",
+                ))
+            }
+            Some(FieldKind::Each(element_ty)) => {
+                parse_quote!(::std::vec::Vec<<#element_ty as #Schematic>::Input>)
+            }
             Some(FieldKind::Entity(_)) => wrap_option(parse_quote!(#EntityAccess)),
             Some(FieldKind::Asset(config)) => {
                 let ty = if config.untyped() {
@@ -118,17 +180,33 @@ impl SchematicField {
     /// Generate this field's definition within a generated input type.
     pub fn generate_definition(&self) -> TokenStream {
         let forward_attrs = self.forward_attrs();
+
+        if let Some(FieldKind::Flatten(fields)) = self.config.kind() {
+            let definitions = fields.iter().map(|field| {
+                let ident = &field.ident;
+                let ty = &field.ty;
+                quote!(#forward_attrs #ident: #ty)
+            });
+            return quote!(#(#definitions),*);
+        }
+
         let ty = match self.input_ty() {
             Ok(ty) => ty,
             Err(err) => return err.to_compile_error(),
         };
-        match &self.member {
+        let default_attr = self
+            .config
+            .default_with()
+            .map(|_| quote!(#[reflect(default)]));
+        match &self.input_member() {
             Member::Named(ident) => quote! {
                 #forward_attrs
+                #default_attr
                 #ident: #ty
             },
             Member::Unnamed(_) => quote! {
                 #forward_attrs
+                #default_attr
                 #ty
             },
         }
@@ -145,11 +223,40 @@ impl SchematicField {
         // Locate span at the field's member so that error messages point to the offending field.
         let span = Span::call_site().located_at(self.member.span());
 
+        if let Some(FieldKind::Flatten(_)) = self.config.kind() {
+            let input = self.generate_flattened_input()?;
+            return Ok(quote_spanned! {span =>
+                #FromSchematicInput::from_input(
+                    #input,
+                    #NextId,
+                    #CONTEXT_IDENT,
+                )
+            });
+        }
+
         let accessor = custom_accessor.unwrap_or_else(|| {
-            let member = self.member();
+            let member = self.input_member();
             quote_spanned!(span => #INPUT_IDENT.#member)
         });
 
+        if let Some(default_with) = self.config.default_with() {
+            let conversion = self.generate_base_conversion(quote_spanned!(span => #TEMP_IDENT))?;
+            return Ok(quote_spanned! {span =>
+                match #accessor {
+                    ::core::option::Option::Some(#TEMP_IDENT) => #conversion,
+                    ::core::option::Option::None => #default_with(&*#CONTEXT_IDENT),
+                }
+            });
+        }
+
+        self.generate_base_conversion(accessor)
+    }
+
+    /// Generate this field's conversion from the given accessor, ignoring any `default_with` handling.
+    fn generate_base_conversion(&self, accessor: TokenStream) -> Result<TokenStream, Error> {
+        // Locate span at the field's member so that error messages point to the offending field.
+        let span = Span::call_site().located_at(self.member.span());
+
         Ok(match self.config.kind() {
             Some(FieldKind::From(_)) => {
                 if self.config.optional() {
@@ -170,6 +277,34 @@ impl SchematicField {
                     }
                 }
             }
+            Some(FieldKind::TryFrom(_)) => {
+                quote_spanned! {span =>
+                    #TryFromSchematicInput::try_from_input(
+                        #accessor,
+                        #NextId,
+                        #CONTEXT_IDENT,
+                    )?
+                }
+            }
+            Some(FieldKind::Each(element_ty)) => {
+                let id = self.element_id();
+                quote_spanned! {span =>
+                    ::core::iter::Iterator::collect(
+                        ::core::iter::Iterator::map(
+                            ::core::iter::Iterator::enumerate(
+                                ::core::iter::IntoIterator::into_iter(#accessor)
+                            ),
+                            |(index, #TEMP_IDENT)| <#element_ty as #FromSchematicInput<
+                                <#element_ty as #Schematic>::Input
+                            >>::from_input(
+                                #TEMP_IDENT,
+                                #id,
+                                #CONTEXT_IDENT,
+                            ),
+                        )
+                    )
+                }
+            }
             Some(FieldKind::Entity(config)) => {
                 let access = if let Some(path) = config.path() {
                     quote_spanned!(span => #EntityAccess::from(#path))
@@ -198,12 +333,52 @@ impl SchematicField {
             Some(FieldKind::Asset(config)) => {
                 let id = config.asset_id();
 
-                if let Some(path) = config.path() {
-                    quote_spanned! {span =>
-                        #CONTEXT_IDENT
-                            .world()
-                            .resource::<#AssetServer>()
-                            .load(#path)
+                if config.collection() {
+                    let path = self.collection_path(config)?;
+                    let load = if config.preload() {
+                        quote_spanned! {span =>
+                            #ProtoAsset::load_collection(#CONTEXT_IDENT.world_mut(), #path)
+                        }
+                    } else {
+                        // Lazy assets may be configured to use weak handles
+                        quote_spanned! {span =>
+                            {
+                                let #TEMP_IDENT =
+                                    #ProtoAsset::load_collection(#CONTEXT_IDENT.world_mut(), #path);
+                                ::core::iter::Iterator::collect(
+                                    ::core::iter::Iterator::map(
+                                        ::core::iter::IntoIterator::into_iter(#TEMP_IDENT),
+                                        |#TEMP_IDENT| #CONTEXT_IDENT.lazy_asset_handle(#TEMP_IDENT),
+                                    )
+                                )
+                            }
+                        }
+                    };
+
+                    if self.config.optional() {
+                        quote_spanned!(span => ::core::option::Option::Some(#load))
+                    } else {
+                        load
+                    }
+                } else if let Some(path) = config.path() {
+                    if config.preload() {
+                        quote_spanned! {span =>
+                            #CONTEXT_IDENT
+                                .world()
+                                .resource::<#AssetServer>()
+                                .load(#path)
+                        }
+                    } else {
+                        // Lazy assets may be configured to use weak handles
+                        quote_spanned! {span =>
+                            {
+                                let #TEMP_IDENT = #CONTEXT_IDENT
+                                    .world()
+                                    .resource::<#AssetServer>()
+                                    .load(#path);
+                                #CONTEXT_IDENT.lazy_asset_handle(#TEMP_IDENT)
+                            }
+                        }
                     }
                 } else if self.config.optional() {
                     quote_spanned! {span =>
@@ -238,8 +413,19 @@ impl SchematicField {
         // Locate span at the field's member so that error messages point to the offending field.
         let span = Span::call_site().located_at(self.member.span());
 
+        if let Some(FieldKind::Flatten(_)) = self.config.kind() {
+            let input = self.generate_flattened_input()?;
+            return Ok(quote_spanned! {span =>
+                #FromSchematicPreloadInput::from_preload_input(
+                    #input,
+                    #NextId,
+                    #DEPENDENCIES_IDENT,
+                )
+            });
+        }
+
         let accessor = custom_accessor.unwrap_or_else(|| {
-            let member = self.member();
+            let member = self.input_member();
             quote_spanned!(span => #INPUT_IDENT.#member)
         });
 
@@ -263,11 +449,41 @@ impl SchematicField {
                     }
                 }
             }
+            Some(FieldKind::Each(element_ty)) => {
+                let id = self.element_id();
+                quote_spanned! {span =>
+                    ::core::iter::Iterator::collect(
+                        ::core::iter::Iterator::map(
+                            ::core::iter::Iterator::enumerate(
+                                ::core::iter::IntoIterator::into_iter(#accessor)
+                            ),
+                            |(index, #TEMP_IDENT)| <#element_ty as #FromSchematicPreloadInput<
+                                <#element_ty as #Schematic>::Input
+                            >>::from_preload_input(
+                                #TEMP_IDENT,
+                                #id,
+                                #DEPENDENCIES_IDENT,
+                            ),
+                        )
+                    )
+                }
+            }
             Some(FieldKind::Entity(_)) => TokenStream::new(),
             Some(FieldKind::Asset(config)) => {
                 let id = config.asset_id();
 
-                if let Some(path) = config.path() {
+                if config.collection() {
+                    let path = self.collection_path(config)?;
+                    if self.config.optional() {
+                        quote_spanned! {span =>
+                            ::core::option::Option::Some(#DEPENDENCIES_IDENT.add_folder_dependency(#path))
+                        }
+                    } else {
+                        quote_spanned! {span =>
+                            #DEPENDENCIES_IDENT.add_folder_dependency(#path)
+                        }
+                    }
+                } else if let Some(path) = config.path() {
                     if self.config.optional() {
                         quote_spanned! {span =>
                             ::core::option::Option::Some(#DEPENDENCIES_IDENT.add_dependency(#path))
@@ -299,6 +515,49 @@ impl SchematicField {
         })
     }
 
+    /// Generates the `SchematicId` expression for the element at `index` of an `each` field.
+    ///
+    /// This is derived from the field's name so that the same ID is generated
+    /// during both preloading and application.
+    fn element_id(&self) -> TokenStream {
+        let field_name = self.member.to_token_stream().to_string();
+        quote!(#ID_IDENT.field(#field_name).next(index))
+    }
+
+    /// Generates an expression that gathers the hoisted fields of a flattened field
+    /// back into the input type of the field's schematic.
+    ///
+    /// The input type is first created via `Default` and then has each hoisted field assigned to it,
+    /// so that any mismatch between the hoisted fields and the input type is caught at compile time.
+    fn generate_flattened_input(&self) -> Result<TokenStream, Error> {
+        // Locate span at the field's member so that error messages point to the offending field.
+        let span = Span::call_site().located_at(self.member.span());
+
+        let Some(FieldKind::Flatten(fields)) = self.config.kind() else {
+            return Err(Error::new(
+                self.member.span(),
+                "expected field to be configured as `flatten`",
+            ));
+        };
+
+        let ty = &self.defined_ty;
+        let assignments = fields.iter().map(|field| {
+            let ident = &field.ident;
+            // Locate span at the hoisted field so that type errors point to its declaration.
+            let span = Span::call_site().located_at(ident.span());
+            quote_spanned!(span => #TEMP_IDENT.#ident = #INPUT_IDENT.#ident;)
+        });
+
+        Ok(quote_spanned! {span =>
+            {
+                #[allow(clippy::field_reassign_with_default)]
+                let mut #TEMP_IDENT: <#ty as #Schematic>::Input = ::core::default::Default::default();
+                #(#assignments)*
+                #TEMP_IDENT
+            }
+        })
+    }
+
     /// Generates the preload code for the field (assets-only).
     ///
     /// The generated `TokenStream` will be a statement that sets the user-defined field to the asset
@@ -311,9 +570,36 @@ impl SchematicField {
         let span = Span::call_site().located_at(self.member.span());
 
         Ok(match self.config.kind() {
+            Some(FieldKind::Each(element_ty)) => {
+                let accessor = variant_field_ident.unwrap_or_else(|| {
+                    let member = self.input_member();
+                    quote_spanned!(span => #INPUT_IDENT.#member)
+                });
+                let id = self.element_id();
+
+                quote_spanned! {span =>
+                    for (index, #TEMP_IDENT) in ::core::iter::Iterator::enumerate(::core::iter::IntoIterator::into_iter(&mut #accessor)) {
+                        <#element_ty as #Schematic>::preload_dependencies(
+                            #TEMP_IDENT,
+                            #id,
+                            #DEPENDENCIES_IDENT,
+                        )?;
+                    }
+                }
+            }
+            Some(FieldKind::Asset(config)) if config.preload() && config.collection() => {
+                let path = self.collection_path(config)?;
+                let asset_ty = config.try_extract_asset_type(&self.defined_ty)?;
+
+                // Collections have no input field to update,
+                // so we only need to register the folder's contents as dependencies
+                quote_spanned! {span =>
+                    #DEPENDENCIES_IDENT.add_folder_dependency::<#asset_ty>(#path);
+                }
+            }
             Some(FieldKind::Asset(config)) if config.preload() => {
                 let accessor = variant_field_ident.unwrap_or_else(|| {
-                    let member = self.member();
+                    let member = self.input_member();
                     quote_spanned!(span => #INPUT_IDENT.#member)
                 });
 
@@ -337,7 +623,6 @@ impl SchematicField {
                     }
                 } else {
                     let input_ty = self.input_ty()?;
-                    let asset_ty = config.try_extract_asset_type(&self.defined_ty)?;
                     let id = config.asset_id();
 
                     let convert = if self.config.optional() {
@@ -361,16 +646,17 @@ impl SchematicField {
                         }
                     };
 
+                    let field_name = self.member.to_token_stream().to_string();
+
                     quote_spanned! {span =>
                         #accessor = {
                             let #TEMP_IDENT = <#input_ty as #FromReflect>::from_reflect(
                                 &*#Reflect::clone_value(&#accessor)
-                            ).unwrap_or_else(|| {
-                                panic!(
-                                    "{} should have a functioning `FromReflect` impl",
-                                    ::std::any::type_name::<#asset_ty>()
-                                )
-                            });
+                            ).ok_or_else(|| #SchematicError::PreloadFromReflectFail {
+                                schematic: ::std::any::type_name::<Self>(),
+                                field: ::std::string::String::from(#field_name),
+                                input: ::std::any::type_name::<#input_ty>(),
+                            })?;
 
                             #convert
                         };
@@ -1,18 +1,24 @@
 use crate::common::data::DeriveType;
 use crate::common::fields::{
-    AssetInlineArg, AssetPathArg, AssetPreloadArg, AssetTypeArg, AssetUniqueArg, EntityPathArg,
-    OptionalArg, SchematicField,
+    AssetCollectionArg, AssetInlineArg, AssetPathArg, AssetPreloadArg, AssetReloadArg,
+    AssetTypeArg, AssetUniqueArg, DefaultWithArg, EntityPathArg, FieldKind, FlattenedField,
+    OptionalArg, RenameArg, SchematicField,
 };
 use crate::common::input::{InputType, SchematicIo};
 use crate::utils::constants::{
-    ASSET_ATTR, ASSET_SCHEMATIC_ATTR, ASSET_SCHEMATIC_ATTR_ATTR, ENTITY_ATTR, FROM_ATTR,
-    SCHEMATIC_ATTR, SCHEMATIC_ATTR_ATTR,
+    ASSET_ATTR, ASSET_SCHEMATIC_ATTR, ASSET_SCHEMATIC_ATTR_ATTR, EACH_ATTR, ENTITY_ATTR,
+    FLATTEN_ATTR, FROM_ATTR, SCHEMATIC_ATTR, SCHEMATIC_ATTR_ATTR, TRY_FROM_ATTR,
 };
 use crate::utils::{parse_bool, parse_nested_meta, AttrArg};
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
+use std::collections::HashSet;
 use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Attribute, Error, Field, Fields, Type};
+use syn::{
+    parenthesized, Attribute, Error, Field, Fields, GenericArgument, Member, PathArguments, Token,
+    Type,
+};
 
 /// The collection of fields for a struct or enum.
 pub(crate) enum SchematicFields {
@@ -54,6 +60,7 @@ impl SchematicFields {
                     .collect::<Result<_, _>>()?;
 
                 if is_named {
+                    Self::validate_input_names(&fields)?;
                     Self::Named(fields)
                 } else {
                     Self::Unnamed(fields)
@@ -61,6 +68,71 @@ impl SchematicFields {
             }
         })
     }
+
+    /// Returns true if any field uses a fallible conversion (i.e. `#[schematic(try_from = ...)]`).
+    pub fn is_fallible(&self) -> bool {
+        match self {
+            Self::Unit => false,
+            Self::Named(fields) | Self::Unnamed(fields) => {
+                fields.iter().any(SchematicField::is_fallible)
+            }
+        }
+    }
+
+    /// Returns an error if any flattened field is not allowed in this position.
+    ///
+    /// This is used to prevent `#[schematic(flatten)]` on enum variants.
+    pub fn deny_flatten(&self) -> Result<(), Error> {
+        let fields = match self {
+            Self::Unit => return Ok(()),
+            Self::Named(fields) | Self::Unnamed(fields) => fields,
+        };
+
+        for field in fields {
+            if let Some(FieldKind::Flatten(_)) = field.config().kind() {
+                return Err(Error::new(
+                    field.member().span(),
+                    format_args!("`{FLATTEN_ATTR}` can only be used on struct fields"),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ensures that no two fields share the same name in the generated input type.
+    ///
+    /// Collisions can occur when a field is renamed or flattened.
+    fn validate_input_names(fields: &[SchematicField]) -> Result<(), Error> {
+        let mut names = HashSet::new();
+        let mut insert = |ident: &Ident| {
+            if names.insert(ident.to_string()) {
+                Ok(())
+            } else {
+                Err(Error::new(
+                    ident.span(),
+                    format_args!("duplicate field `{}` in generated input type", ident),
+                ))
+            }
+        };
+
+        for field in fields.iter().filter(SchematicField::requires_input_field) {
+            match field.config().kind() {
+                Some(FieldKind::Flatten(flattened)) => {
+                    for flattened in flattened {
+                        insert(&flattened.ident)?;
+                    }
+                }
+                _ => {
+                    if let Member::Named(ident) = field.input_member() {
+                        insert(&ident)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Builds a [`SchematicField`] from a [`Field`].
@@ -87,9 +159,14 @@ impl<'a> ProtoFieldBuilder<'a> {
 
                     parse_nested_meta!(attr, |meta| {
                         FROM_ATTR => self.parse_from_meta(meta),
+                        TRY_FROM_ATTR => self.parse_try_from_meta(meta),
+                        FLATTEN_ATTR => self.parse_flatten_meta(meta),
+                        EACH_ATTR => self.parse_each_meta(meta),
                         ASSET_ATTR => self.parse_asset_meta(meta),
                         ENTITY_ATTR => self.parse_entity_meta(meta),
                         OptionalArg::NAME => self.parse_optional_meta(meta),
+                        RenameArg::NAME => self.parse_rename_meta(meta),
+                        DefaultWithArg::NAME => self.parse_default_with_meta(meta),
                     })?;
                 }
                 DeriveType::AssetSchematic => {
@@ -107,11 +184,41 @@ impl<'a> ProtoFieldBuilder<'a> {
                         ASSET_ATTR => self.parse_asset_meta(meta),
                         ENTITY_ATTR => self.parse_entity_meta(meta),
                         OptionalArg::NAME => self.parse_optional_meta(meta),
+                        RenameArg::NAME => self.parse_rename_meta(meta),
                     })?;
                 }
             }
         }
 
+        if self.proto_field.config().default_with().is_some() {
+            match self.proto_field.config().kind() {
+                None | Some(FieldKind::From(_)) | Some(FieldKind::TryFrom(_)) => {}
+                Some(kind) => {
+                    return Err(Error::new(
+                        self.field.span(),
+                        format_args!(
+                            "cannot use `{}` on a field configured as `{:?}`",
+                            DefaultWithArg::NAME,
+                            kind
+                        ),
+                    ))
+                }
+            }
+        }
+
+        if let Some(FieldKind::Flatten(_)) = self.proto_field.config().kind() {
+            if self.proto_field.config().rename().is_some() {
+                return Err(Error::new(
+                    self.field.span(),
+                    format_args!(
+                        "cannot use `{}` on a field configured as `{}`",
+                        RenameArg::NAME,
+                        FLATTEN_ATTR
+                    ),
+                ));
+            }
+        }
+
         // Automatically detect `Option` types
         if self.detect_optional() {
             self.proto_field
@@ -163,7 +270,9 @@ impl<'a> ProtoFieldBuilder<'a> {
                     AssetPreloadArg::NAME => config.try_set_preload(parse_bool(&meta)?, meta.input.span()),
                     AssetInlineArg::NAME => config.try_set_inline(parse_bool(&meta)?, meta.input.span()),
                     AssetUniqueArg::NAME => config.try_set_unique(parse_bool(&meta)?, meta.input.span()),
+                    AssetReloadArg::NAME => config.try_set_reload(parse_bool(&meta)?, meta.input.span()),
                     AssetPathArg::NAME => config.try_set_path(meta.value()?.parse()?, meta.input.span()),
+                    AssetCollectionArg::NAME => config.try_set_collection(parse_bool(&meta)?, meta.input.span()),
                     AssetTypeArg::NAME => config.try_set_custom_type(meta.value()?.parse()?, meta.input.span()),
                 })
             }
@@ -208,6 +317,87 @@ impl<'a> ProtoFieldBuilder<'a> {
             .try_init_from_kind(meta.value()?.parse()?, meta.input.span())
     }
 
+    /// Parse a `#[schematic(try_from = Type)]` attribute.
+    fn parse_try_from_meta(&mut self, meta: ParseNestedMeta) -> Result<(), Error> {
+        self.require_input(meta.path.span())?;
+
+        self.proto_field
+            .config_mut()
+            .try_init_try_from_kind(meta.value()?.parse()?, meta.input.span())
+    }
+
+    /// Parse a `#[schematic(flatten(field: Type, ...))]` attribute.
+    ///
+    /// This takes in the meta starting at `flatten`.
+    fn parse_flatten_meta(&mut self, meta: ParseNestedMeta) -> Result<(), Error> {
+        if self.field.ident.is_none() {
+            return Err(Error::new(
+                meta.path.span(),
+                "cannot flatten an unnamed field",
+            ));
+        }
+
+        self.require_input(meta.path.span())?;
+
+        let content;
+        parenthesized!(content in meta.input);
+        let fields = Punctuated::<FlattenedField, Token![,]>::parse_terminated(&content)?;
+
+        if fields.is_empty() {
+            return Err(Error::new(
+                meta.path.span(),
+                "expected at least one field to flatten",
+            ));
+        }
+
+        self.proto_field
+            .config_mut()
+            .try_init_flatten_kind(fields.into_iter().collect(), meta.path.span())
+    }
+
+    /// Parse a `#[schematic(each)]` attribute.
+    ///
+    /// This takes in the meta starting at `each`.
+    fn parse_each_meta(&mut self, meta: ParseNestedMeta) -> Result<(), Error> {
+        self.require_input(meta.path.span())?;
+
+        let element_ty = Self::extract_vec_element_type(&self.field.ty)?.clone();
+
+        self.proto_field
+            .config_mut()
+            .try_init_each_kind(element_ty, meta.path.span())
+    }
+
+    /// Attempts to extract the element type from a `Vec`.
+    fn extract_vec_element_type(ty: &Type) -> Result<&Type, Error> {
+        let create_error = || {
+            Error::new(
+                ty.span(),
+                format_args!("`{EACH_ATTR}` can only be used on `Vec` fields"),
+            )
+        };
+
+        let Type::Path(type_path) = ty else {
+            return Err(create_error());
+        };
+
+        let segment = type_path
+            .path
+            .segments
+            .last()
+            .filter(|segment| type_path.qself.is_none() && segment.ident == "Vec")
+            .ok_or_else(create_error)?;
+
+        let PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return Err(create_error());
+        };
+
+        match args.args.first() {
+            Some(GenericArgument::Type(ty)) => Ok(ty),
+            _ => Err(create_error()),
+        }
+    }
+
     /// Parse a `#[schematic(optional)]` attribute.
     ///
     /// This takes in the meta starting at `optional`.
@@ -217,6 +407,35 @@ impl<'a> ProtoFieldBuilder<'a> {
             .try_set_optional(parse_bool(&meta)?, meta.input.span())
     }
 
+    /// Parse a `#[schematic(rename = "...")]` attribute.
+    ///
+    /// This takes in the meta starting at `rename`.
+    fn parse_rename_meta(&mut self, meta: ParseNestedMeta) -> Result<(), Error> {
+        if self.field.ident.is_none() {
+            return Err(Error::new(
+                meta.path.span(),
+                "cannot rename an unnamed field",
+            ));
+        }
+
+        self.require_input(meta.path.span())?;
+
+        self.proto_field
+            .config_mut()
+            .try_set_rename(meta.value()?.parse()?, meta.input.span())
+    }
+
+    /// Parse a `#[schematic(default_with = "...")]` attribute.
+    ///
+    /// This takes in the meta starting at `default_with`.
+    fn parse_default_with_meta(&mut self, meta: ParseNestedMeta) -> Result<(), Error> {
+        self.require_input(meta.path.span())?;
+
+        self.proto_field
+            .config_mut()
+            .try_set_default_with(meta.value()?.parse()?, meta.input.span())
+    }
+
     /// Parse a `#[schematic_attr]` attribute.
     fn parse_forwarded_attr(&mut self, attr: &Attribute) -> Result<(), Error> {
         self.proto_field
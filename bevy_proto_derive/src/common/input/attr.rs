@@ -1,6 +1,6 @@
 use crate::common::input::{InputType, SchematicIo};
-use crate::utils::parse_nested_meta;
 use crate::utils::{define_attribute, AttrArg, AttrTarget};
+use crate::utils::{parse_bool, parse_nested_meta};
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 use std::fmt::{Debug, Formatter};
@@ -11,6 +11,7 @@ use syn::{Attribute, Error, Meta, Token, Visibility};
 
 define_attribute!("vis" => InputVisArg(Visibility) for AttrTarget::InputVisibility, no_debug);
 define_attribute!("name" => InputNameArg(Ident) for AttrTarget::Input);
+define_attribute!("derive_serialize" => InputDeriveSerializeArg(bool) for AttrTarget::Input);
 
 impl Debug for InputVisArg {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -30,6 +31,7 @@ pub(crate) fn parse_input_meta(meta: ParseNestedMeta, io: &mut SchematicIo) -> R
     parse_nested_meta!(meta, |meta| {
         InputVisArg::NAME => io.try_set_input_vis(meta.value()?.parse()?, None),
         InputNameArg::NAME => io.try_set_input_ty(InputType::Generated(meta.value()?.parse()?), None),
+        InputDeriveSerializeArg::NAME => io.try_set_derive_serialize(parse_bool(&meta)?, meta.input.span()),
     })
 }
 
@@ -1,6 +1,8 @@
 use crate::common::input::{InputType, OutputType, SchematicIo};
 use crate::utils::constants::{CONTEXT_IDENT, DEPENDENCIES_IDENT, INPUT_IDENT};
-use crate::utils::exports::{FromReflect, FromSchematicInput, FromSchematicPreloadInput, Reflect};
+use crate::utils::exports::{
+    FromReflect, FromSchematicInput, FromSchematicPreloadInput, Reflect, TryFromSchematicInput,
+};
 use crate::utils::NextId;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -39,6 +41,28 @@ pub(crate) fn generate_input_conversion(io: &SchematicIo) -> Option<TokenStream>
     }
 }
 
+/// Generates a statement that sets [`INPUT_IDENT`] to the output type,
+/// using `TryFromSchematicInput` to convert the input to `Self`.
+///
+/// Any conversion error is returned early using the `?` operator.
+pub(crate) fn generate_try_input_conversion(io: &SchematicIo) -> TokenStream {
+    let output_conversion = match io.output_ty() {
+        OutputType::Custom(output_ty) => Some(quote! {
+            let #INPUT_IDENT = <#output_ty as #FromSchematicInput<Self>>::from_input(
+                #INPUT_IDENT, #NextId, #CONTEXT_IDENT
+            );
+        }),
+        OutputType::Reflexive => None,
+    };
+
+    quote! {
+        let #INPUT_IDENT = <Self as #TryFromSchematicInput<Self::Input>>::try_from_input(
+            #INPUT_IDENT, #NextId, #CONTEXT_IDENT
+        )?;
+        #output_conversion
+    }
+}
+
 /// Generates a statement that sets [`INPUT_IDENT`] to the output type using `FromSchematicPreloadInput`.
 ///
 /// Returns `None` if no conversion is necessary.
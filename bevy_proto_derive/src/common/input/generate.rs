@@ -8,9 +8,10 @@ use crate::common::fields::{SchematicField, SchematicFields};
 use crate::common::input::{ForwardAttributes, InputType, SchematicIo};
 use crate::utils::constants::{CONTEXT_IDENT, DEPENDENCIES_IDENT, ID_IDENT, INPUT_IDENT};
 use crate::utils::exports::{
-    DependenciesBuilder, FromSchematicInput, FromSchematicPreloadInput, Reflect, SchematicContext,
-    SchematicId,
+    DependenciesBuilder, Deserialize, FromSchematicInput, FromSchematicPreloadInput, Reflect,
+    SchematicContext, SchematicError, SchematicId, Serialize, TryFromSchematicInput,
 };
+use crate::utils::get_proto_crate;
 
 /// Generates the input type for the schematic.
 pub(crate) fn generate_input(
@@ -42,11 +43,45 @@ pub(crate) fn generate_input(
         Some(generics.to_phantom())
     };
 
+    // Opt-in serde derives, pointed at the re-exported crate so users don't need `serde` themselves
+    let (serde_derive, serde_skip) = if io.derive_serialize() {
+        let serde_crate = format!("{}::__private::serde", get_proto_crate());
+        (
+            Some(quote! {
+                #[derive(#Serialize, #Deserialize)]
+                #[serde(crate = #serde_crate)]
+            }),
+            Some(quote!(#[serde(skip)])),
+        )
+    } else {
+        (None, None)
+    };
+
+    let is_fallible = data.is_fallible();
     let make_from_impl = |body: TokenStream| {
-        quote! {
-            impl #impl_generics #FromSchematicInput<#input_ty> for #base_ident #impl_ty_generics #where_clause {
-                fn from_input(#INPUT_IDENT: #input_ty, #ID_IDENT: #SchematicId, #CONTEXT_IDENT: &mut #SchematicContext) -> Self {
-                    #body
+        if is_fallible {
+            // Fallible conversions are generated as `TryFromSchematicInput`,
+            // with `FromSchematicInput` simply panicking on error
+            quote! {
+                impl #impl_generics #TryFromSchematicInput<#input_ty> for #base_ident #impl_ty_generics #where_clause {
+                    fn try_from_input(#INPUT_IDENT: #input_ty, #ID_IDENT: #SchematicId, #CONTEXT_IDENT: &mut #SchematicContext) -> ::core::result::Result<Self, #SchematicError> {
+                        ::core::result::Result::Ok(#body)
+                    }
+                }
+
+                impl #impl_generics #FromSchematicInput<#input_ty> for #base_ident #impl_ty_generics #where_clause {
+                    fn from_input(#INPUT_IDENT: #input_ty, #ID_IDENT: #SchematicId, #CONTEXT_IDENT: &mut #SchematicContext) -> Self {
+                        <Self as #TryFromSchematicInput<#input_ty>>::try_from_input(#INPUT_IDENT, #ID_IDENT, #CONTEXT_IDENT)
+                            .unwrap_or_else(|err| panic!("{}", err))
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #impl_generics #FromSchematicInput<#input_ty> for #base_ident #impl_ty_generics #where_clause {
+                    fn from_input(#INPUT_IDENT: #input_ty, #ID_IDENT: #SchematicId, #CONTEXT_IDENT: &mut #SchematicContext) -> Self {
+                        #body
+                    }
                 }
             }
         }
@@ -73,6 +108,7 @@ pub(crate) fn generate_input(
 
             Some(quote! {
                 #[derive(#Reflect)]
+                #serde_derive
                 #attributes
                 #vis struct #input_ty_def #where_clause;
 
@@ -112,12 +148,14 @@ pub(crate) fn generate_input(
             let phantom_ty = phantom_ty.map(|phantom_ty| {
                 quote! {
                     #[reflect(ignore)]
+                    #serde_skip
                     #phantom_ty,
                 }
             });
 
             Some(quote! {
                 #[derive(#Reflect)]
+                #serde_derive
                 #attributes
                 #vis struct #input_ty_def (
                     #(#definitions,)*
@@ -165,12 +203,14 @@ pub(crate) fn generate_input(
             let phantom_ty = phantom_ty.map(|phantom_ty| {
                 quote! {
                     #[reflect(ignore)]
+                    #serde_skip
                     __phantom_ty__: #phantom_ty,
                 }
             });
 
             Some(quote! {
                 #[derive(#Reflect)]
+                #serde_derive
                 #attributes
                 #vis struct #input_ty_def #where_clause {
                     #(#definitions,)*
@@ -211,12 +251,14 @@ pub(crate) fn generate_input(
 
             let phantom_ty = phantom_ty.map(|phantom_ty| {
                 quote! {
+                    #serde_skip
                     _Phantom(#[reflect(ignore)] #phantom_ty),
                 }
             });
 
             Some(quote! {
                 #[derive(#Reflect)]
+                #serde_derive
                 #attributes
                 #vis enum #input_ty_def #where_clause {
                     #(#definitions,)*
@@ -1,4 +1,4 @@
-use crate::common::input::{InputType, InputVisArg, OutputType};
+use crate::common::input::{InputDeriveSerializeArg, InputType, InputVisArg, OutputType};
 use crate::utils::AttrArgValue;
 use proc_macro2::{Ident, Span};
 use quote::ToTokens;
@@ -20,6 +20,8 @@ pub(crate) struct SchematicIo {
     input_ty: InputType,
     /// The output type.
     output_ty: OutputType,
+    /// Whether the generated input type should derive `Serialize` and `Deserialize`.
+    derive_serialize: InputDeriveSerializeArg,
 }
 
 impl SchematicIo {
@@ -30,6 +32,7 @@ impl SchematicIo {
             input_vis: InputVisArg::default(),
             input_ty: InputType::Reflexive,
             output_ty: OutputType::Reflexive,
+            derive_serialize: InputDeriveSerializeArg::default(),
         }
     }
 
@@ -104,4 +107,12 @@ impl SchematicIo {
         let span = span.unwrap_or_else(|| value.span());
         self.input_vis.try_set(Some(value), span)
     }
+
+    pub fn derive_serialize(&self) -> bool {
+        self.derive_serialize.get().copied().unwrap_or_default()
+    }
+
+    pub fn try_set_derive_serialize(&mut self, value: bool, span: Span) -> Result<(), Error> {
+        self.derive_serialize.try_set(Some(value), span)
+    }
 }
@@ -16,6 +16,27 @@ mod utils;
 /// This macro will generate the impl for `Schematic` as well as a corresponding
 /// `Schematic::Input` type if one is needed.
 ///
+/// # Generics
+///
+/// Generic types are supported, including those with multiple type parameters.
+/// For generic types, the generated `Schematic` impl is bounded on whatever it needs
+/// (e.g. `FromReflect` and `Component`), so it's only implemented for valid sets of parameters.
+///
+/// Since each concrete type is a distinct type, each one used in a prototype must be registered
+/// separately, and is referenced in prototype files by its full type name:
+///
+/// ```ignore
+/// #[derive(Component, Reflect, Schematic)]
+/// #[reflect(Schematic)]
+/// struct Pair<A, B> {
+///   first: A,
+///   second: B,
+/// }
+///
+/// app.register_type::<Pair<usize, String>>();
+/// // In a prototype file: "my_crate::Pair<usize, alloc::string::String>": (first: 1, second: "a")
+/// ```
+///
 /// # Attributes
 ///
 /// ## Container Attributes
@@ -53,6 +74,17 @@ mod utils;
 ///
 /// This is generally used in conjunction with the `vis` argument.
 ///
+/// ##### `(derive_serialize)`
+///
+/// _Optional_
+///
+/// Adds `Serialize` and `Deserialize` derives to the generated input type,
+/// allowing it to be used directly with serde (e.g. by tools that write prototype files).
+///
+/// Every field of the input type must then be serde-compatible.
+/// Note that fields using `#[schematic(default)]` are still required when deserializing.
+/// This has no effect if no input type is generated.
+///
 /// ### `#[schematic(from = path::to::Type)]`
 ///
 /// Instead of generating an input type, this attribute has the derive macro use the given one.
@@ -73,6 +105,11 @@ mod utils;
 /// - `From<CustomSchematic> for ExternalType`
 /// - `FromSchematicInput<CustomSchematic> for ExternalType`
 ///
+/// If the type is a bundle, it is inserted directly (i.e. not through reflection),
+/// so each of its components is added to the entity individually and can be queried
+/// as soon as the schematic has been applied.
+/// Likewise, removing the schematic removes every component in the bundle.
+///
 /// ### `#[schematic_attr]`
 ///
 /// This attribute is used to forward attributes to the generated input type,
@@ -92,6 +129,8 @@ mod utils;
 /// The generated field will be of type `ProtoAsset`.
 ///
 /// By default, all assets are lazy-loaded— that is, they're only loaded once the schematic is used.
+/// Lazy assets are given strong handles by default, keeping them loaded for as long as the field exists.
+/// This can be changed to weak handles with `Config::weak_lazy_assets`.
 ///
 /// #### Arguments
 ///
@@ -101,8 +140,29 @@ mod utils;
 ///
 /// If present, then the asset will be preloaded as a dependency of the schematic.
 ///
+/// Since the prototype is not considered loaded until its dependencies are,
+/// this is the way to ensure an asset is ready by the time the schematic is applied.
+/// Bevy's asset server does not support blocking loads,
+/// so an asset cannot be loaded synchronously during the apply itself.
+///
 /// Cannot be used with the `unique` argument.
 ///
+/// ##### `(reload)`
+///
+/// _Optional_
+///
+/// If present, then the asset will be re-resolved whenever the prototype is hot-reloaded,
+/// updating the field on any existing entities (or the existing resource) spawned from it.
+/// This allows, for example, changing a texture's path in a prototype file and seeing
+/// the change immediately in a running app.
+///
+/// Only the fields marked with this argument are updated— the rest of the schematic
+/// is left untouched.
+/// Child entities are updated along with their parent,
+/// so modifying a child prototype updates every entity spawned from it.
+///
+/// Cannot be used on enums, bundles, or schematics with a custom `into` type.
+///
 /// ##### `(unique)`
 ///
 /// _Optional_
@@ -142,6 +202,26 @@ mod utils;
 ///
 /// Cannot be used with the `inline` argument.
 ///
+/// ##### `(collection)`
+///
+/// _Optional_
+///
+/// Loads every asset in the folder given by the `path` argument into a `Vec<Handle<T>>` field,
+/// such as the frames of an animation: `#[schematic(asset(collection, path = "frames"))]`.
+///
+/// The handles are sorted by file name, with numbers compared by value
+/// (e.g. `frame_2.png` comes before `frame_10.png`).
+/// Subfolders are ignored, and the folder should only contain assets of type `T`.
+/// When combined with `preload`, every asset in the folder is added as a dependency of the prototype
+/// and the folder is read when the prototype is loaded.
+/// Otherwise, it's read the first time the schematic is applied.
+/// Either way, the folder is only read once (or again when the prototype is reloaded).
+///
+/// Note that this relies on reading the folder's contents, which is not supported on all platforms
+/// (e.g. wasm). If the folder cannot be read, an error is logged and the field will be empty.
+///
+/// Requires the `path` argument. Cannot be used with the `inline` argument.
+///
 /// ##### `(type = path::to::AssetType)`
 ///
 /// _Optional_
@@ -178,6 +258,77 @@ mod utils;
 ///
 /// This is useful for defining custom logic or controlling the serialized representation.
 ///
+/// ### `#[schematic(try_from = path::to::FieldType)]`
+///
+/// Like `from`, but allows the conversion to fail.
+///
+/// For this to work, `TryFromSchematicInput<CustomFieldInput> for MyFieldType` must be satisfied.
+///
+/// Any error returned by the conversion will be surfaced by `Schematic::try_apply`.
+/// Whether this panics or is logged and skipped is controlled by `Config::skip_failed_schematics`.
+///
+/// ### `#[schematic(flatten(field: Type, ...))]`
+///
+/// This hoists the fields of a nested schematic into the generated input type,
+/// allowing prototype files to stay shallow:
+///
+/// ```ignore
+/// #[derive(Component, Schematic, Reflect, Default)]
+/// struct Movement {
+///   speed: f32,
+///   jump_height: f32,
+/// }
+///
+/// #[derive(Component, Schematic, Reflect)]
+/// struct Player {
+///   name: String,
+///   #[schematic(flatten(speed: f32, jump_height: f32))]
+///   movement: Movement,
+/// }
+///
+/// // Player: (name: "Bob", speed: 1.5, jump_height: 2.0)
+/// ```
+///
+/// Because derive macros cannot see the definitions of other types,
+/// the hoisted fields must be listed along with their types.
+/// They should match the fields of the nested type's `Schematic::Input`,
+/// which is then built from them and converted as usual.
+/// That input type must implement `Default`, which is used for any unlisted fields.
+/// A hoisted field that is missing from the input type, or whose type does not match,
+/// results in a compile error.
+///
+/// Hoisted field names that collide with other fields of the input type result in a compile error.
+/// This attribute can only be used on named struct fields.
+///
+/// ### `#[schematic(each)]`
+///
+/// This is used with a `Vec<T>` field, where `T` itself implements `Schematic`,
+/// to convert each element through `T`'s own input.
+///
+/// The generated field will be of type `Vec<T::Input>`,
+/// allowing elements to contain their own asset and entity fields:
+///
+/// ```ignore
+/// #[derive(Component, Schematic, Reflect)]
+/// struct Slot {
+///   #[schematic(asset)]
+///   icon: Handle<Image>,
+///   #[schematic(entity)]
+///   target: Entity,
+/// }
+///
+/// #[derive(Component, Schematic, Reflect)]
+/// struct Inventory {
+///   #[schematic(each)]
+///   slots: Vec<Slot>,
+/// }
+/// ```
+///
+/// Each element is given its own `SchematicId` (based on the field name and element index)
+/// and preloads its dependencies along with the parent schematic.
+///
+/// Note that the `Vec<T::Input>` type must be registered in order for it to be deserialized.
+///
 /// ### `#[schematic(optional)]`
 ///
 /// Entity and asset fields are able to be defined as optional.
@@ -186,6 +337,25 @@ mod utils;
 ///
 /// It can also be used to opt-out by specifying `#[schematic(optional = false)]`.
 ///
+/// ### `#[schematic(rename = "new_name")]`
+///
+/// This controls the name of the field in the generated input type,
+/// and therefore the name used for the field in prototype files.
+///
+/// This is useful for exposing a friendlier name in prototype files without
+/// renaming the actual field.
+/// The new name must be a valid Rust identifier and this attribute can only be used on named fields.
+///
+/// ### `#[schematic(default_with = "path::to::func")]`
+///
+/// This allows the field to be omitted from prototype files, in which case its value
+/// is computed by the given function when the schematic is applied.
+///
+/// The function must have the signature `fn(&SchematicContext) -> FieldType`,
+/// giving it access to the world (e.g. to read a resource containing a default theme).
+///
+/// This attribute cannot be used on `entity` or `asset` fields.
+///
 /// ### `#[schematic_attr]`
 ///
 /// This attribute is used to forward attributes to the corresponding field on the generated input type,
@@ -275,6 +445,7 @@ pub fn impl_external_schematic(input: TokenStream) -> TokenStream {
 /// - `#[asset_schematic(asset)]`
 /// - `#[asset_schematic(from = path::to::FieldType)]`
 /// - `#[asset_schematic(optional)]`
+/// - `#[asset_schematic(rename = "new_name")]`
 ///
 /// For the `asset` attribute, the following arguments are supported:
 /// - `(inline)`
@@ -1,15 +1,21 @@
 use crate::common::data::{DeriveType, SchematicData};
 use crate::common::fields::SchematicFields;
 use crate::common::input::{
-    generate_from_reflect_conversion, generate_input, generate_input_conversion, InputType,
-    OutputType, SchematicIo,
+    generate_from_reflect_conversion, generate_input, generate_input_conversion,
+    generate_try_input_conversion, InputType, OutputType, SchematicIo,
+};
+use crate::utils::constants::{
+    CONTEXT_IDENT, DEPENDENCIES_IDENT, ID_IDENT, INPUT_IDENT, TEMP_IDENT,
+};
+use crate::utils::exports::{
+    Bundle, DependenciesBuilder, FromReflect, FromSchematicInput, GetTypeRegistration, Reflect,
+    Resource, Schematic, SchematicContext, SchematicError, SchematicId, Typed,
 };
-use crate::utils::constants::{CONTEXT_IDENT, DEPENDENCIES_IDENT, ID_IDENT, INPUT_IDENT};
-use crate::utils::exports::{DependenciesBuilder, Schematic, SchematicContext, SchematicId};
 use proc_macro2::{Ident, TokenStream};
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
-use syn::{DeriveInput, Error, Generics, Visibility};
+use syn::spanned::Spanned;
+use syn::{parse_quote, DeriveInput, Error, Generics, Visibility};
 
 use crate::schematic::container_attributes::{ContainerAttributes, SchematicKind};
 
@@ -45,8 +51,44 @@ impl DeriveSchematic {
     fn apply_def(&self) -> TokenStream {
         let from_reflect = generate_from_reflect_conversion();
         let conversion = generate_input_conversion(self.io());
+        let insert = self.insert_def();
+
+        quote! {
+            #from_reflect
+
+            #conversion
+
+            #insert
+
+        }
+    }
+
+    /// Generate the logic for `Schematic::try_apply`.
+    ///
+    /// Returns `None` if the schematic has no fallible fields.
+    fn try_apply_def(&self) -> Option<TokenStream> {
+        if !self.data.is_fallible() {
+            return None;
+        }
+
+        let from_reflect = generate_from_reflect_conversion();
+        let conversion = generate_try_input_conversion(self.io());
+        let insert = self.insert_def();
+
+        Some(quote! {
+            #from_reflect
+
+            #conversion
+
+            #insert
 
-        let insert = if matches!(self.attrs.kind(), SchematicKind::Resource) {
+            ::core::result::Result::Ok(())
+        })
+    }
+
+    /// Generate the statement that inserts the converted input.
+    fn insert_def(&self) -> TokenStream {
+        if matches!(self.attrs.kind(), SchematicKind::Resource) {
             quote!(#CONTEXT_IDENT.world_mut().insert_resource(#INPUT_IDENT);)
         } else {
             quote! {
@@ -55,15 +97,6 @@ impl DeriveSchematic {
                     .unwrap_or_else(|| panic!("schematic `{}` expected entity", std::any::type_name::<Self>()))
                     .insert(#INPUT_IDENT);
             }
-        };
-
-        quote! {
-            #from_reflect
-
-            #conversion
-
-            #insert
-
         }
     }
 
@@ -83,6 +116,89 @@ impl DeriveSchematic {
         }
     }
 
+    /// Generates the logic for `Schematic::reload`.
+    ///
+    /// Returns `None` if no fields are marked with `#[schematic(asset(reload))]`.
+    fn reload_def(&self) -> Result<Option<TokenStream>, Error> {
+        let fields = match &self.data {
+            SchematicData::Struct(SchematicFields::Unit) => return Ok(None),
+            SchematicData::Struct(
+                SchematicFields::Named(fields) | SchematicFields::Unnamed(fields),
+            ) => fields
+                .iter()
+                .filter(|field| field.reload())
+                .collect::<Vec<_>>(),
+            SchematicData::Enum(variants) => {
+                let field = variants
+                    .iter()
+                    .flat_map(|variant| match &variant.fields {
+                        SchematicFields::Unit => [].iter(),
+                        SchematicFields::Named(fields) | SchematicFields::Unnamed(fields) => {
+                            fields.iter()
+                        }
+                    })
+                    .find(|field| field.reload());
+
+                return match field {
+                    Some(field) => Err(Error::new(
+                        field.member().span(),
+                        "reloadable assets are not supported on enum schematics",
+                    )),
+                    None => Ok(None),
+                };
+            }
+        };
+
+        let Some(first) = fields.first() else {
+            return Ok(None);
+        };
+
+        if let OutputType::Custom(_) = self.output_ty() {
+            return Err(Error::new(
+                first.member().span(),
+                "reloadable assets are not supported on schematics with a custom `into` type",
+            ));
+        }
+
+        let target = match self.attrs.kind() {
+            SchematicKind::Resource => quote! {
+                #CONTEXT_IDENT.world_mut().get_resource_mut::<Self>()
+            },
+            SchematicKind::Bundle => {
+                return Err(Error::new(
+                    first.member().span(),
+                    "reloadable assets are not supported on bundle schematics",
+                ))
+            }
+            SchematicKind::Undefined => quote! {
+                #CONTEXT_IDENT
+                    .entity_mut()
+                    .as_mut()
+                    .and_then(|#TEMP_IDENT| #TEMP_IDENT.get_mut::<Self>())
+            },
+        };
+
+        let from_reflect = generate_from_reflect_conversion();
+        let conversions = fields
+            .iter()
+            .map(|field| field.generate_conversion(None))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let idents = (0..fields.len())
+            .map(|index| format_ident!("__reload_{}__", index))
+            .collect::<Vec<_>>();
+        let members = fields.iter().map(|field| field.member());
+
+        Ok(Some(quote! {
+            #from_reflect
+
+            #(let #idents = #conversions;)*
+
+            if let ::core::option::Option::Some(mut #TEMP_IDENT) = #target {
+                #(#TEMP_IDENT.#members = #idents;)*
+            }
+        }))
+    }
+
     /// Generates the logic for `Schematic::preload`.
     fn preload_def(&self) -> Result<TokenStream, Error> {
         Ok(match &self.data {
@@ -109,9 +225,56 @@ impl DeriveSchematic {
         })
     }
 
+    /// Generates the generics for the `Schematic` impl.
+    ///
+    /// For generic types, this adds the bounds required by the generated impl
+    /// so that it only applies when the type parameters allow it
+    /// (rather than failing to compile for any set of parameters).
+    fn impl_generics(&self, input_ty: &TokenStream) -> Generics {
+        let mut generics = self.generics().clone();
+
+        if generics.params.is_empty() {
+            return generics;
+        }
+
+        let output_ty = self.output_ty();
+        let where_clause = generics.make_where_clause();
+
+        where_clause
+            .predicates
+            .push(parse_quote!(Self: #Reflect + #Typed));
+        where_clause
+            .predicates
+            .push(parse_quote!(#input_ty: #FromReflect + #GetTypeRegistration));
+
+        if !matches!(self.input_ty(), InputType::Reflexive) {
+            where_clause
+                .predicates
+                .push(parse_quote!(Self: #FromSchematicInput<#input_ty>));
+        }
+
+        if let OutputType::Custom(_) = output_ty {
+            where_clause
+                .predicates
+                .push(parse_quote!(#output_ty: #FromSchematicInput<Self>));
+        }
+
+        if matches!(self.attrs.kind(), SchematicKind::Resource) {
+            where_clause
+                .predicates
+                .push(parse_quote!(#output_ty: #Resource));
+        } else {
+            where_clause
+                .predicates
+                .push(parse_quote!(#output_ty: #Bundle));
+        }
+
+        generics
+    }
+
     fn generate(&self) -> Result<TokenStream, Error> {
         let ident: &Ident = self.io.ident();
-        let (impl_generics, ty_generics, where_clause) = self.generics().split_for_impl();
+        let (_, ty_generics, _) = self.generics().split_for_impl();
 
         let input = generate_input(
             self.io(),
@@ -123,6 +286,21 @@ impl DeriveSchematic {
         let apply_def = self.apply_def();
         let remove_def = self.remove_def();
         let preload_def = self.preload_def()?;
+        let reload_impl = self.reload_def()?.map(|reload_def| {
+            quote! {
+                fn reload(#INPUT_IDENT: &Self::Input, #ID_IDENT: #SchematicId, #CONTEXT_IDENT: &mut #SchematicContext) {
+                    #reload_def
+                }
+            }
+        });
+        let try_apply_impl = self.try_apply_def().map(|try_apply_def| {
+            quote! {
+                fn try_apply(#INPUT_IDENT: &Self::Input, #ID_IDENT: #SchematicId, #CONTEXT_IDENT: &mut #SchematicContext) -> ::core::result::Result<(), #SchematicError> {
+                    #try_apply_def
+                }
+            }
+        });
+        let requires_entity = !matches!(self.attrs.kind(), SchematicKind::Resource);
 
         let input_vis = self.io.input_vis();
         let input_ty = match self.input_ty() {
@@ -132,6 +310,9 @@ impl DeriveSchematic {
             input_ty => input_ty.to_token_stream(),
         };
 
+        let generics = self.impl_generics(&input_ty);
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+
         let output = quote! {
             #input
 
@@ -142,13 +323,22 @@ impl DeriveSchematic {
                     #apply_def
                 }
 
+                #try_apply_impl
+
                 fn remove(#INPUT_IDENT: &Self::Input, #ID_IDENT: #SchematicId, #CONTEXT_IDENT: &mut #SchematicContext) {
                     #remove_def
                 }
 
-                fn preload_dependencies(#INPUT_IDENT: &mut Self::Input, #ID_IDENT: #SchematicId, #DEPENDENCIES_IDENT: &mut #DependenciesBuilder)  {
+                fn preload_dependencies(#INPUT_IDENT: &mut Self::Input, #ID_IDENT: #SchematicId, #DEPENDENCIES_IDENT: &mut #DependenciesBuilder) -> ::core::result::Result<(), #SchematicError> {
                     #preload_def
+                    ::core::result::Result::Ok(())
                 }
+
+                fn requires_entity() -> bool {
+                    #requires_entity
+                }
+
+                #reload_impl
             }
         };
 
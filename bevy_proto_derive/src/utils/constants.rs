@@ -16,7 +16,10 @@ pub(crate) const ASSET_ATTR: &str = "asset";
 pub(crate) const ENTITY_ATTR: &str = "entity";
 pub(crate) const INPUT_ATTR: &str = "input";
 pub(crate) const FROM_ATTR: &str = "from";
+pub(crate) const TRY_FROM_ATTR: &str = "try_from";
 pub(crate) const INTO_ATTR: &str = "into";
+pub(crate) const FLATTEN_ATTR: &str = "flatten";
+pub(crate) const EACH_ATTR: &str = "each";
 
 /// Ident for the `Schematic::Input` argument.
 pub(crate) const INPUT_IDENT: ConstIdent = ConstIdent("__input__");
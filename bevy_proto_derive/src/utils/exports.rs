@@ -32,11 +32,19 @@ create_export!(bevy_proto::deps::[DependenciesBuilder]);
 create_export!(bevy_proto::schematics::[Schematic]);
 create_export!(bevy_proto::schematics::[FromSchematicInput]);
 create_export!(bevy_proto::schematics::[FromSchematicPreloadInput]);
+create_export!(bevy_proto::schematics::[TryFromSchematicInput]);
 create_export!(bevy_proto::schematics::[SchematicId]);
 create_export!(bevy_proto::schematics::[SchematicContext]);
+create_export!(bevy_proto::schematics::[SchematicError]);
 create_export!(bevy_proto::tree::[EntityAccess]);
+create_export!(bevy_proto::__private::serde::[Serialize]);
+create_export!(bevy_proto::__private::serde::[Deserialize]);
 
 create_export!(bevy::reflect::[Reflect]);
 create_export!(bevy::reflect::[FromReflect]);
+create_export!(bevy::reflect::[GetTypeRegistration]);
+create_export!(bevy::reflect::[Typed]);
+create_export!(bevy::ecs::bundle::[Bundle]);
+create_export!(bevy::ecs::system::[Resource]);
 create_export!(bevy::assets::[AssetServer]);
 create_export!(bevy::utils::[Uuid]);
@@ -57,6 +57,8 @@ enum Alignment {
 /// This can be done by specifying the "kind" as "resource".
 /// It's also a good idea to set `entity: false` in the prototype file
 /// so that the prototype doesn't spawn an entity.
+/// If a prototype containing only resource schematics is spawned anyway,
+/// a warning is logged and the needless entity is despawned.
 ///
 /// Note that when a schematic is applied, it will replace the current instance
 /// of the resource in the world.
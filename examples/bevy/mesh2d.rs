@@ -0,0 +1,34 @@
+//! This example is a copy of Bevy's [`mesh2d`] example, but powered by the `bevy_proto` plugin.
+//!
+//! Both the mesh and its `ColorMaterial` are defined inline within the prototype.
+//! The triangle's mesh is built from raw vertex data rather than a primitive shape.
+//! The camera is also a prototype, using a fixed vertical size for its projection.
+//!
+//! [`mesh2d`]: https://github.com/bevyengine/bevy/blob/v0.11.2/examples/2d/mesh2d.rs
+
+use bevy::prelude::*;
+use bevy_proto::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, ProtoPlugin::default()))
+        .add_systems(Startup, load)
+        .add_systems(
+            Update,
+            spawn.run_if(
+                prototypes_ready(["Camera", "Circle", "Square", "Triangle"]).and_then(run_once()),
+            ),
+        )
+        .run();
+}
+
+fn load(mut prototypes: PrototypesMut) {
+    prototypes.load_folder("examples/bevy/mesh2d").unwrap();
+}
+
+fn spawn(mut commands: ProtoCommands) {
+    commands.spawn("Camera");
+    commands.spawn("Circle");
+    commands.spawn("Square");
+    commands.spawn("Triangle");
+}
@@ -96,9 +96,10 @@ impl Schematic for Foo {
         input: &mut Self::Input,
         _id: SchematicId,
         dependencies: &mut DependenciesBuilder,
-    ) {
+    ) -> Result<(), SchematicError> {
         // This method is optional, but it allows us to preload our assets.
         let _: Handle<Image> = dependencies.add_dependency(input.image.clone());
+        Ok(())
     }
 }
 
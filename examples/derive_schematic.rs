@@ -161,11 +161,12 @@ impl<T: ToString> From<T> for Bar {
 //             __context__.entity_mut().unwrap_or_else(|| panic!("schematic `{}` expected entity", std::any::type_name::<Self>())).insert(__input__);
 //         }
 //         fn remove(__input__: &Self::Input, __id__: bevy_proto::backend::schematics::SchematicId, __context__: &mut bevy_proto::backend::schematics::SchematicContext) { __context__.entity_mut().unwrap_or_else(|| panic!("schematic `{}` expected entity", std::any::type_name::<Self>())).remove::<Self>(); }
-//         fn preload_dependencies(__input__: &mut Self::Input, __id__: bevy_proto::backend::schematics::SchematicId, __context__: &mut bevy_proto::backend::deps::DependenciesBuilder) {
+//         fn preload_dependencies(__input__: &mut Self::Input, __id__: bevy_proto::backend::schematics::SchematicId, __context__: &mut bevy_proto::backend::deps::DependenciesBuilder) -> ::core::result::Result<(), bevy_proto::backend::schematics::SchematicError> {
 //             __input__.preloaded_asset = {
-//                 let __temp__ = <bevy_proto::backend::assets::ProtoAsset<Image> as ::bevy::reflect::FromReflect>::from_reflect(&*::bevy::reflect::Reflect::clone_value(&__input__.preloaded_asset)).unwrap_or_else(|| { panic!("{} should have a functioning `FromReflect` impl", ::std::any::type_name::<Image>()) });
+//                 let __temp__ = <bevy_proto::backend::assets::ProtoAsset<Image> as ::bevy::reflect::FromReflect>::from_reflect(&*::bevy::reflect::Reflect::clone_value(&__input__.preloaded_asset)).ok_or_else(|| bevy_proto::backend::schematics::SchematicError::PreloadFromReflectFail { schematic: ::std::any::type_name::<Self>(), field: ::std::string::String::from("preloaded_asset"), input: ::std::any::type_name::<bevy_proto::backend::assets::ProtoAsset<Image>>() })?;
 //                 bevy_proto::backend::assets::ProtoAsset::Handle(bevy_proto::backend::schematics::FromSchematicPreloadInput::from_preload_input(__temp__, __id__.next(143686424131491485342302652144513517898u128), __context__))
 //             };
+//             ::core::result::Result::Ok(())
 //         }
 //     }
 // };
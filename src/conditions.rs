@@ -1,6 +1,7 @@
 use crate::config::ProtoConfig;
 use crate::prelude::Prototypes;
 use crate::proto::Prototype;
+use bevy::prelude::Local;
 use bevy_proto_backend::proto::Config;
 use std::marker::PhantomData;
 
@@ -12,6 +13,34 @@ pub fn prototype_ready<I: ToString>(id: I) -> impl Fn(Prototypes<'_>) -> bool {
     ProtoCondition::prototype_ready(id)
 }
 
+/// Run condition that returns true only on the first run after the [prototype]
+/// with the given ID becomes ready to be used.
+///
+/// This is useful for running a system once as soon as a prototype is available,
+/// without needing to manually read [`ProtoAssetEvent`]s:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_proto::prelude::*;
+/// fn spawn_player(mut commands: ProtoCommands) {
+///   commands.spawn("Player");
+/// }
+///
+/// App::new().add_systems(Update, spawn_player.run_if(prototype_became_ready("Player")));
+/// ```
+///
+/// Note that readiness is checked each time the condition runs.
+/// If the prototype is unregistered and later becomes ready again (e.g. after a failed hot-reload),
+/// this will return true once more.
+///
+/// [prototype]: Prototype
+/// [`ProtoAssetEvent`]: crate::prelude::ProtoAssetEvent
+pub fn prototype_became_ready<I: ToString>(
+    id: I,
+) -> impl FnMut(Local<'_, bool>, Prototypes<'_>) -> bool {
+    ProtoCondition::prototype_became_ready(id)
+}
+
 /// Run condition that returns true if all the [prototypes] with the given
 /// IDs are loaded and ready to be used.
 ///
@@ -33,7 +62,23 @@ impl<C: Config<Prototype>> ProtoCondition<C> {
     /// [prototype]: Prototype
     pub fn prototype_ready<I: ToString>(id: I) -> impl Fn(Prototypes<'_, C>) -> bool {
         let id = id.to_string();
-        move |prototypes: Prototypes<C>| prototypes.is_ready(&id)
+        move |prototypes: Prototypes<C>| prototypes.is_ready(id.as_str())
+    }
+
+    /// Run condition that returns true only on the first run after the [prototype]
+    /// with the given ID becomes ready to be used.
+    ///
+    /// [prototype]: Prototype
+    pub fn prototype_became_ready<I: ToString>(
+        id: I,
+    ) -> impl FnMut(Local<'_, bool>, Prototypes<'_, C>) -> bool {
+        let id = id.to_string();
+        move |mut was_ready: Local<bool>, prototypes: Prototypes<C>| {
+            let is_ready = prototypes.is_ready(id.as_str());
+            let became_ready = is_ready && !*was_ready;
+            *was_ready = is_ready;
+            became_ready
+        }
     }
 
     /// Run condition that returns true if all the [prototypes] with the given
@@ -48,6 +93,6 @@ impl<C: Config<Prototype>> ProtoCondition<C> {
             .into_iter()
             .map(|id| id.to_string())
             .collect::<Vec<String>>();
-        move |prototypes: Prototypes<C>| ids.iter().all(|id| prototypes.is_ready(&id.to_string()))
+        move |prototypes: Prototypes<C>| ids.iter().all(|id| prototypes.is_ready(id.as_str()))
     }
 }
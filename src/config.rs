@@ -2,18 +2,29 @@
 //!
 //! [prototypes]: Prototype
 
+use std::any::TypeId;
+use std::borrow::Cow;
+
 use bevy::asset::Handle;
-use bevy::prelude::Resource;
+use bevy::ecs::reflect::{AppTypeRegistry, ReflectComponent, ReflectResource};
+use bevy::log::error;
+use bevy::prelude::{Resource, World};
+use bevy::reflect::{GetPath, Reflect};
+use bevy::utils::{HashMap, HashSet};
+use thiserror::Error;
 
 use bevy_proto_backend::cycles::{Cycle, CycleResponse};
-use bevy_proto_backend::proto::Config;
+use bevy_proto_backend::proto::{Config, Prototypical, DEFAULT_APPLY_BUDGET};
 use bevy_proto_backend::schematics::{DynamicSchematic, SchematicContext, SchematicId};
+use bevy_proto_backend::tree::EntityTreeOrder;
 
+#[cfg(feature = "auto_name")]
+use crate::hooks::NameFormatter;
 use crate::hooks::{
-    OnAfterApplyPrototype, OnAfterApplySchematic, OnAfterRemovePrototype, OnAfterRemoveSchematic,
-    OnBeforeApplyPrototype, OnBeforeApplySchematic, OnBeforeRemovePrototype,
-    OnBeforeRemoveSchematic, OnCycle, OnRegisterPrototype, OnReloadPrototype,
-    OnUnregisterPrototype,
+    FieldValidator, OnAfterApplyPrototype, OnAfterApplySchematic, OnAfterRemovePrototype,
+    OnAfterRemoveSchematic, OnBeforeApplyPrototype, OnBeforeApplySchematic,
+    OnBeforeRemovePrototype, OnBeforeRemoveSchematic, OnCycle, OnPrototypeReady,
+    OnRegisterPrototype, OnReloadPrototype, OnUnregisterPrototype, SchematicTransform,
 };
 use crate::proto::Prototype;
 
@@ -23,6 +34,7 @@ pub struct ProtoConfig {
     on_register_prototype: Option<OnRegisterPrototype>,
     on_reload_prototype: Option<OnReloadPrototype>,
     on_unregister_prototype: Option<OnUnregisterPrototype>,
+    on_prototype_ready: Option<OnPrototypeReady>,
     on_before_apply_prototype: Option<OnBeforeApplyPrototype>,
     on_after_apply_prototype: Option<OnAfterApplyPrototype>,
     on_before_remove_prototype: Option<OnBeforeRemovePrototype>,
@@ -32,6 +44,20 @@ pub struct ProtoConfig {
     on_before_remove_schematic: Option<OnBeforeRemoveSchematic>,
     on_after_remove_schematic: Option<OnAfterRemoveSchematic>,
     on_cycle: Option<OnCycle>,
+    apply_order: EntityTreeOrder,
+    apply_budget: Option<usize>,
+    cascade_templates: bool,
+    default_templates: Vec<Cow<'static, str>>,
+    strict_readiness: bool,
+    allow_missing_templates: bool,
+    skip_failed_schematics: bool,
+    weak_lazy_assets: bool,
+    active_flags: HashSet<String>,
+    type_path_aliases: HashMap<String, String>,
+    #[cfg(feature = "auto_name")]
+    name_formatter: Option<NameFormatter>,
+    field_validators: HashMap<TypeId, Vec<(&'static str, FieldValidator)>>,
+    schematic_transforms: HashMap<TypeId, Vec<SchematicTransform>>,
 }
 
 impl ProtoConfig {
@@ -53,6 +79,23 @@ impl ProtoConfig {
         self
     }
 
+    /// Register a callback for [`Config::on_prototype_ready`].
+    ///
+    /// This fires once each time a prototype is loaded or reloaded, not each time it is spawned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_proto::prelude::{ProtoConfig, Prototypical};
+    /// let config = ProtoConfig::default().on_prototype_ready(Box::new(|prototype, world| {
+    ///     println!("Prototype {:?} is ready ({} entities)", prototype.id(), world.entities().len());
+    /// }));
+    /// ```
+    pub fn on_prototype_ready(mut self, callback: OnPrototypeReady) -> Self {
+        self.on_prototype_ready = Some(callback);
+        self
+    }
+
     /// Register a callback for [`Config::on_before_apply_prototype`].
     pub fn on_before_apply_prototype(mut self, callback: OnBeforeApplyPrototype) -> Self {
         self.on_before_apply_prototype = Some(callback);
@@ -106,6 +149,288 @@ impl ProtoConfig {
         self.on_cycle = Some(callback);
         self
     }
+
+    /// Register a validator for a field on the given schematic type.
+    ///
+    /// This is useful for enforcing constraints on types that can't be modified
+    /// to perform their own validation, such as third-party components.
+    ///
+    /// The field is located using a [reflection path] (e.g. `"intensity"` or `"foo.bar[0]"`)
+    /// and is checked after the schematic has been applied to the entity or world.
+    /// Violations are logged as errors naming the prototype, type, and field.
+    ///
+    /// Only schematics registered as a component or resource can be validated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_proto::prelude::*;
+    /// let config = ProtoConfig::default().with_field_validator::<PointLight, f32>(
+    ///   "intensity",
+    ///   |intensity| {
+    ///     if *intensity >= 0.0 {
+    ///       Ok(())
+    ///     } else {
+    ///       Err(format!("must be >= 0 but was {intensity}"))
+    ///     }
+    ///   },
+    /// );
+    /// ```
+    ///
+    /// [reflection path]: GetPath
+    pub fn with_field_validator<T: Reflect, F: Reflect>(
+        mut self,
+        field: &'static str,
+        validator: impl Fn(&F) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        let validator: FieldValidator =
+            Box::new(move |value: &dyn Reflect| match value.downcast_ref::<F>() {
+                Some(value) => validator(value),
+                None => Err(format!(
+                    "expected a value of type `{}` but found `{}`",
+                    std::any::type_name::<F>(),
+                    value.type_name()
+                )),
+            });
+
+        self.field_validators
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push((field, validator));
+        self
+    }
+
+    /// Register a transform for schematics of the given type.
+    ///
+    /// The transform is given a copy of the schematic right before it's applied,
+    /// allowing its [input] to be modified via reflection.
+    /// Multiple transforms for the same type are run in the order they were registered.
+    ///
+    /// Note that changes only affect the instance being applied.
+    /// The schematic stored in the prototype is left unchanged.
+    ///
+    /// See [`Config::transform_schematic`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_proto::prelude::*;
+    /// const WORLD_UNIT: f32 = 32.0;
+    ///
+    /// let config = ProtoConfig::default().with_schematic_transform::<Transform>(
+    ///   Box::new(|schematic, _context| {
+    ///     if let Some(transform) = schematic.input_mut().downcast_mut::<Transform>() {
+    ///       transform.translation *= WORLD_UNIT;
+    ///     }
+    ///   }),
+    /// );
+    /// ```
+    ///
+    /// [input]: bevy_proto_backend::schematics::Schematic::Input
+    pub fn with_schematic_transform<T: Reflect>(mut self, transform: SchematicTransform) -> Self {
+        self.schematic_transforms
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push(transform);
+        self
+    }
+
+    /// Run the registered field validators for each schematic in the given prototype.
+    fn validate_fields(&self, prototype: &Prototype, context: &SchematicContext) {
+        if self.field_validators.is_empty() {
+            return;
+        }
+
+        let world = context.world();
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+
+        for (_, schematic) in prototype.schematics().iter() {
+            let type_info = schematic.type_info();
+            let Some(validators) = self.field_validators.get(&type_info.type_id()) else {
+                continue;
+            };
+            let Some(registration) = type_registry.get(type_info.type_id()) else {
+                continue;
+            };
+
+            let value = if let Some(reflect_component) = registration.data::<ReflectComponent>() {
+                context
+                    .entity()
+                    .and_then(|entity| reflect_component.reflect(entity))
+            } else if let Some(reflect_resource) = registration.data::<ReflectResource>() {
+                reflect_resource.reflect(world)
+            } else {
+                None
+            };
+
+            let Some(value) = value else {
+                continue;
+            };
+
+            for (field, validator) in validators {
+                let result = value
+                    .reflect_path(field)
+                    .map_err(|err| err.to_string())
+                    .and_then(validator);
+
+                if let Err(reason) = result {
+                    error!(
+                        "{}",
+                        FieldValidationError {
+                            prototype: prototype.id.to_string(),
+                            type_name: type_info.type_name(),
+                            field,
+                            reason,
+                        }
+                    );
+                }
+            }
+        }
+    }
+
+    /// Set the order in which the entities of a prototype are processed.
+    ///
+    /// See [`Config::apply_order`] for details.
+    pub fn with_apply_order(mut self, order: EntityTreeOrder) -> Self {
+        self.apply_order = order;
+        self
+    }
+
+    /// Set the maximum number of entities whose schematics are applied per frame
+    /// for budgeted insertions.
+    ///
+    /// See [`Config::apply_budget`] for details.
+    pub fn with_apply_budget(mut self, budget: usize) -> Self {
+        self.apply_budget = Some(budget);
+        self
+    }
+
+    /// Set whether children should inherit the templates of their parent.
+    ///
+    /// See [`Config::cascade_templates`] for details.
+    pub fn with_cascading_templates(mut self, cascade: bool) -> Self {
+        self.cascade_templates = cascade;
+        self
+    }
+
+    /// Add a template that is implicitly inherited by every prototype.
+    ///
+    /// Default templates have the lowest priority and are applied in the order they are added.
+    /// A prototype can opt out by setting `default_templates: false`.
+    ///
+    /// See [`Config::default_templates`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_proto::prelude::ProtoConfig;
+    /// let config = ProtoConfig::default().with_default_template("Faction");
+    /// ```
+    pub fn with_default_template(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.default_templates.push(id.into());
+        self
+    }
+
+    /// Set whether lazy assets must be loaded before a prototype is considered ready.
+    ///
+    /// Note that enabling this loads all lazy assets upfront.
+    ///
+    /// See [`Config::strict_readiness`] for details.
+    pub fn with_strict_readiness(mut self, strict: bool) -> Self {
+        self.strict_readiness = strict;
+        self
+    }
+
+    /// Set whether prototypes may reference templates that aren't loaded.
+    ///
+    /// See [`Config::allow_missing_templates`] for details.
+    pub fn with_missing_templates_allowed(mut self, allow: bool) -> Self {
+        self.allow_missing_templates = allow;
+        self
+    }
+
+    /// Set whether schematics that fail to apply should be logged and skipped
+    /// rather than causing a panic.
+    ///
+    /// See [`Config::skip_failed_schematics`] for details.
+    pub fn with_failed_schematics_skipped(mut self, skip: bool) -> Self {
+        self.skip_failed_schematics = skip;
+        self
+    }
+
+    /// Set whether lazy assets should be given weak handles.
+    ///
+    /// Note that when enabled, something else must keep a strong handle to these assets
+    /// or they may be unloaded while still in use.
+    ///
+    /// See [`Config::weak_lazy_assets`] for details.
+    pub fn with_weak_lazy_assets(mut self, weak: bool) -> Self {
+        self.weak_lazy_assets = weak;
+        self
+    }
+
+    /// Activate the given flag for conditional sections in prototype files.
+    ///
+    /// Flags are set in code (rather than read from the environment)
+    /// so that loading remains deterministic.
+    /// They must be set before the plugin is built.
+    ///
+    /// See the [`Prototype`] docs for details on conditional sections.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_proto::prelude::ProtoConfig;
+    /// let config = ProtoConfig::default().with_flag("platform=web");
+    /// ```
+    pub fn with_flag(mut self, flag: impl Into<String>) -> Self {
+        self.active_flags.insert(flag.into());
+        self
+    }
+
+    /// Resolve schematics written with the deprecated type path `old` as the type `new`.
+    ///
+    /// This is useful for renaming or moving a type without needing to update
+    /// every prototype file that references it.
+    /// A warning is logged whenever a deprecated path is used so that
+    /// prototype files can eventually be updated.
+    /// Aliases must be set before the plugin is built.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_proto::prelude::ProtoConfig;
+    /// let config = ProtoConfig::default()
+    ///     .with_type_path_alias("my_game::OldPlayer", "my_game::player::Player");
+    /// ```
+    pub fn with_type_path_alias(mut self, old: impl Into<String>, new: impl Into<String>) -> Self {
+        self.type_path_aliases.insert(old.into(), new.into());
+        self
+    }
+
+    /// Set the function used to format the [`Name`] automatically given to spawned entities.
+    ///
+    /// The function is given the ID of the prototype.
+    /// By default, names are formatted as `"{id} (Prototype)"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_proto::prelude::ProtoConfig;
+    /// // Use the prototype ID as-is
+    /// let config = ProtoConfig::default().with_name_formatter(Box::new(|id| id.to_string()));
+    /// ```
+    ///
+    /// See [`Config::auto_name`] for details.
+    ///
+    /// [`Name`]: bevy::core::Name
+    #[cfg(feature = "auto_name")]
+    pub fn with_name_formatter(mut self, formatter: NameFormatter) -> Self {
+        self.name_formatter = Some(formatter);
+        self
+    }
 }
 
 impl Config<Prototype> for ProtoConfig {
@@ -121,9 +446,17 @@ impl Config<Prototype> for ProtoConfig {
         }
     }
 
-    fn on_unregister_prototype(&mut self, id: &String, handle: Handle<Prototype>) {
+    fn on_unregister_prototype(&mut self, id: &Cow<'static, str>, handle: Handle<Prototype>) {
         if let Some(on_unregister_prototype) = &mut self.on_unregister_prototype {
-            on_unregister_prototype(id, handle);
+            // Unregistering is rare enough that allocating here is preferable to
+            // changing the callback signature
+            on_unregister_prototype(&id.to_string(), handle);
+        }
+    }
+
+    fn on_prototype_ready(&mut self, prototype: &Prototype, world: &mut World) {
+        if let Some(on_prototype_ready) = &mut self.on_prototype_ready {
+            on_prototype_ready(prototype, world);
         }
     }
 
@@ -134,6 +467,8 @@ impl Config<Prototype> for ProtoConfig {
     }
 
     fn on_after_apply_prototype(&mut self, prototype: &Prototype, context: &mut SchematicContext) {
+        self.validate_fields(prototype, context);
+
         if let Some(on_after_apply_prototype) = &mut self.on_after_apply_prototype {
             on_after_apply_prototype(prototype, context);
         }
@@ -177,6 +512,31 @@ impl Config<Prototype> for ProtoConfig {
         }
     }
 
+    fn transform_schematic(
+        &mut self,
+        schematic: &DynamicSchematic,
+        _id: SchematicId,
+        context: &mut SchematicContext,
+    ) -> Option<DynamicSchematic> {
+        let transforms = self
+            .schematic_transforms
+            .get_mut(&schematic.type_info().type_id())?;
+
+        let mut transformed = match schematic.try_clone() {
+            Ok(transformed) => transformed,
+            Err(err) => {
+                error!("could not transform schematic: {}", err);
+                return None;
+            }
+        };
+
+        for transform in transforms {
+            transform(&mut transformed, context);
+        }
+
+        Some(transformed)
+    }
+
     fn on_before_remove_schematic(
         &mut self,
         schematic: &DynamicSchematic,
@@ -199,6 +559,55 @@ impl Config<Prototype> for ProtoConfig {
         }
     }
 
+    fn apply_order(&self) -> EntityTreeOrder {
+        self.apply_order
+    }
+
+    fn apply_budget(&self) -> usize {
+        self.apply_budget.unwrap_or(DEFAULT_APPLY_BUDGET)
+    }
+
+    fn cascade_templates(&self) -> bool {
+        self.cascade_templates
+    }
+
+    fn default_templates(&self) -> &[Cow<'static, str>] {
+        &self.default_templates
+    }
+
+    fn strict_readiness(&self) -> bool {
+        self.strict_readiness
+    }
+
+    fn allow_missing_templates(&self) -> bool {
+        self.allow_missing_templates
+    }
+
+    fn skip_failed_schematics(&self) -> bool {
+        self.skip_failed_schematics
+    }
+
+    fn weak_lazy_assets(&self) -> bool {
+        self.weak_lazy_assets
+    }
+
+    fn active_flags(&self) -> HashSet<String> {
+        self.active_flags.clone()
+    }
+
+    fn type_path_aliases(&self) -> HashMap<String, String> {
+        self.type_path_aliases.clone()
+    }
+
+    #[cfg(feature = "auto_name")]
+    fn auto_name(&self, id: &str) -> String {
+        if let Some(name_formatter) = &self.name_formatter {
+            name_formatter(id)
+        } else {
+            format!("{} (Prototype)", id)
+        }
+    }
+
     fn on_cycle(&self, cycle: &Cycle<Prototype>) -> CycleResponse {
         if let Some(on_cycle) = &self.on_cycle {
             on_cycle(cycle)
@@ -209,3 +618,18 @@ impl Config<Prototype> for ProtoConfig {
         }
     }
 }
+
+/// Error produced when a field fails a validator registered with
+/// [`ProtoConfig::with_field_validator`].
+#[derive(Debug, Error)]
+#[error("field `{field}` of `{type_name}` in prototype {prototype:?} failed validation: {reason}")]
+pub struct FieldValidationError {
+    /// The ID of the prototype containing the schematic.
+    pub prototype: String,
+    /// The type name of the schematic.
+    pub type_name: &'static str,
+    /// The path to the validated field.
+    pub field: &'static str,
+    /// The reason validation failed.
+    pub reason: String,
+}
@@ -4,23 +4,53 @@
 //! common types.
 //! For example, many bundles in Bevy do not meet the requirements to implement
 //! `Schematic` themselves, so they have equivalent types defined here as a stopgap.
+//!
+//! Bundle schematics are converted to their Bevy counterpart and inserted directly,
+//! so each constituent component is immediately queryable once the prototype is applied
+//! (for example, a `SpriteBundle` schematic can be queried by `&Sprite` or `&Handle<Image>`).
 
 use bevy::app::App;
+#[cfg(any(
+    feature = "bevy_audio",
+    feature = "bevy_pbr",
+    feature = "bevy_scene",
+    feature = "bevy_sprite"
+))]
 use bevy::asset::Handle;
 use bevy::prelude::{Component, GlobalTransform, Transform};
 use bevy::reflect::{std_traits::ReflectDefault, Reflect};
+#[cfg(feature = "bevy_ui")]
 use bevy::ui::widget::UiImageSize;
+#[cfg(any(feature = "bevy_pbr", feature = "bevy_sprite"))]
 use bevy_proto_backend::assets::{AssetSchematic, InlinableProtoAsset};
+#[cfg(any(feature = "bevy_pbr", feature = "bevy_scene", feature = "bevy_sprite"))]
+use bevy_proto_backend::from;
+#[cfg(any(
+    feature = "bevy_core_pipeline",
+    feature = "bevy_pbr",
+    feature = "bevy_sprite",
+    feature = "bevy_text",
+    feature = "bevy_ui"
+))]
 use bevy_proto_backend::impls::bevy_impls;
+#[cfg(feature = "bevy_ui")]
 use bevy_proto_backend::proto::ProtoColor;
-use bevy_proto_backend::{from, from_to_default, register_schematic};
+use bevy_proto_backend::{from_to_default, register_schematic};
 
-use bevy_proto_backend::schematics::{
-    FromSchematicInput, ReflectSchematic, Schematic, SchematicContext, SchematicId,
-};
+#[cfg(any(feature = "bevy_pbr", feature = "bevy_sprite"))]
+use bevy_proto_backend::schematics::{FromSchematicInput, SchematicContext, SchematicId};
+use bevy_proto_backend::schematics::{ReflectSchematic, Schematic};
 
 pub(crate) fn register_custom_schematics(app: &mut App) {
     register_schematic!(app, TransformBundle);
+    #[cfg(feature = "bevy_audio")]
+    {
+        register_schematic!(app, SpatialAudioEmitter, SpatialAudioListener);
+        app.add_systems(
+            bevy::app::Update,
+            (setup_spatial_audio_emitters, update_spatial_audio_emitters),
+        );
+    }
     #[cfg(feature = "bevy_core_pipeline")]
     register_schematic!(app, Camera2dBundle, Camera3dBundle);
     #[cfg(feature = "bevy_pbr")]
@@ -35,6 +65,12 @@ pub(crate) fn register_custom_schematics(app: &mut App) {
     register_schematic!(app, VisibilityBundle, SpatialBundle);
     #[cfg(feature = "bevy_scene")]
     register_schematic!(app, DynamicSceneBundle, SceneBundle);
+    #[cfg(all(feature = "bevy_scene", feature = "bevy_pbr"))]
+    {
+        register_schematic!(app, SceneMaterialOverrides);
+        app.register_type::<SceneMaterialOverrideInput>()
+            .add_systems(bevy::app::Update, apply_scene_material_overrides);
+    }
     #[cfg(feature = "bevy_sprite")]
     register_schematic!(
         app,
@@ -45,13 +81,22 @@ pub(crate) fn register_custom_schematics(app: &mut App) {
     #[cfg(feature = "bevy_text")]
     register_schematic!(app, Text2dBundle);
     #[cfg(feature = "bevy_ui")]
-    register_schematic!(app, ButtonBundle, ImageBundle, NodeBundle, TextBundle);
+    register_schematic!(
+        app,
+        ButtonBundle,
+        ImageBundle,
+        NodeBundle,
+        Pickable,
+        TextBundle
+    );
 }
 
+#[cfg(feature = "bevy_ui")]
 fn transparent_background_color() -> bevy_impls::ui::BackgroundColorInput {
     bevy_impls::ui::BackgroundColorInput(ProtoColor::None)
 }
 
+#[cfg(feature = "bevy_ui")]
 fn transparent_border_color() -> bevy_impls::ui::BorderColorInput {
     bevy_impls::ui::BorderColorInput(ProtoColor::None)
 }
@@ -470,6 +515,122 @@ from!(bevy::scene::SceneBundle, SceneBundle, |value: Input| Self {
     computed_visibility: value.computed_visibility,
 });
 
+/// A [`Schematic`] that overrides the materials of a spawned [`SceneBundle`].
+///
+/// Since scenes are spawned asynchronously, the overrides are applied once the
+/// scene instance on this entity is ready.
+/// Each override targets the scene entities with the given [`Name`] (e.g. the name
+/// of a mesh in a glTF file) and replaces their [`StandardMaterial`].
+///
+/// This allows the same scene to be reused with per-instance variations:
+///
+/// ```text
+/// "bevy_proto::custom::SceneMaterialOverrides": (
+///   overrides: [
+///     (
+///       name: "Hull",
+///       material: Asset((base_color: Red)),
+///     ),
+///   ],
+/// ),
+/// ```
+///
+/// This component is removed once the overrides have been applied.
+///
+/// [`SceneBundle`]: bevy::scene::SceneBundle
+/// [`Name`]: bevy::core::Name
+/// [`StandardMaterial`]: bevy::pbr::StandardMaterial
+#[cfg(all(feature = "bevy_scene", feature = "bevy_pbr"))]
+#[derive(Component, Schematic, Reflect)]
+#[reflect(Schematic)]
+#[schematic(from = SceneMaterialOverridesInput)]
+pub struct SceneMaterialOverrides {
+    #[reflect(ignore)]
+    overrides: Vec<(String, Handle<bevy::pbr::StandardMaterial>)>,
+}
+
+/// The schematic input type for [`SceneMaterialOverrides`].
+#[cfg(all(feature = "bevy_scene", feature = "bevy_pbr"))]
+#[derive(Reflect)]
+pub struct SceneMaterialOverridesInput {
+    pub overrides: Vec<SceneMaterialOverrideInput>,
+}
+
+/// A single material override for [`SceneMaterialOverrides`].
+#[cfg(all(feature = "bevy_scene", feature = "bevy_pbr"))]
+#[derive(Reflect)]
+pub struct SceneMaterialOverrideInput {
+    /// The [`Name`] of the scene entities whose material should be replaced.
+    ///
+    /// [`Name`]: bevy::core::Name
+    pub name: String,
+    /// The material to use instead.
+    pub material: InlinableProtoAsset<bevy::pbr::StandardMaterial>,
+}
+
+#[cfg(all(feature = "bevy_scene", feature = "bevy_pbr"))]
+impl FromSchematicInput<SceneMaterialOverridesInput> for SceneMaterialOverrides {
+    fn from_input(
+        input: SceneMaterialOverridesInput,
+        id: SchematicId,
+        context: &mut SchematicContext,
+    ) -> Self {
+        Self {
+            overrides: input
+                .overrides
+                .into_iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    let material =
+                        FromSchematicInput::from_input(item.material, id.next(index), context);
+                    (item.name, material)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Applies any [`SceneMaterialOverrides`] once their scene instance is ready.
+#[cfg(all(feature = "bevy_scene", feature = "bevy_pbr"))]
+fn apply_scene_material_overrides(
+    mut commands: bevy::prelude::Commands,
+    scene_spawner: bevy::prelude::Res<bevy::scene::SceneSpawner>,
+    instances: bevy::prelude::Query<(
+        bevy::prelude::Entity,
+        &SceneMaterialOverrides,
+        &bevy::scene::SceneInstance,
+    )>,
+    children: bevy::prelude::Query<&bevy::prelude::Children>,
+    mut materials: bevy::prelude::Query<(
+        &bevy::core::Name,
+        &mut Handle<bevy::pbr::StandardMaterial>,
+    )>,
+) {
+    use bevy::hierarchy::HierarchyQueryExt;
+
+    for (entity, overrides, instance) in &instances {
+        if !scene_spawner.instance_is_ready(**instance) {
+            continue;
+        }
+
+        for descendant in children.iter_descendants(entity) {
+            let Ok((name, mut material)) = materials.get_mut(descendant) else {
+                continue;
+            };
+
+            if let Some((_, handle)) = overrides
+                .overrides
+                .iter()
+                .find(|(target, _)| target.as_str() == name.as_str())
+            {
+                *material = handle.clone();
+            }
+        }
+
+        commands.entity(entity).remove::<SceneMaterialOverrides>();
+    }
+}
+
 /// A [`Schematic`] implementation of [`Text2dBundle`].
 ///
 /// [`Text2dBundle`]: bevy::text::Text2dBundle
@@ -500,19 +661,11 @@ pub struct Text2dBundle {
 impl FromSchematicInput<Text2dBundle> for bevy::text::Text2dBundle {
     fn from_input(input: Text2dBundle, id: SchematicId, context: &mut SchematicContext) -> Self {
         Self {
-            text: FromSchematicInput::from_input(
-                input.text,
-                id.next(bevy::utils::Uuid::from_u128(
-                    0x14c512589e954232b77ee264aecebe56,
-                )),
-                context,
-            ),
+            text: FromSchematicInput::from_input(input.text, id.field("text"), context),
             text_anchor: input.text_anchor,
             text_2d_bounds: FromSchematicInput::from_input(
                 input.text_2d_bounds,
-                id.next(bevy::utils::Uuid::from_u128(
-                    0x73693c0f150b44389da54b25360886b2,
-                )),
+                id.field("text_2d_bounds"),
                 context,
             ),
             transform: input.transform,
@@ -521,9 +674,7 @@ impl FromSchematicInput<Text2dBundle> for bevy::text::Text2dBundle {
             computed_visibility: input.computed_visibility,
             text_layout_info: FromSchematicInput::from_input(
                 input.text_layout_info,
-                id.next(bevy::utils::Uuid::from_u128(
-                    0xa28731f3a3f445d69b85c438111c9949,
-                )),
+                id.field("text_layout_info"),
                 context,
             ),
         }
@@ -577,13 +728,7 @@ impl FromSchematicInput<ButtonBundle> for bevy::ui::node_bundles::ButtonBundle {
             focus_policy: input.focus_policy,
             background_color: input.background_color.into(),
             border_color: input.border_color.into(),
-            image: bevy::ui::UiImage::from_input(
-                input.image,
-                id.next(bevy::utils::Uuid::from_u128(
-                    0x1d002cb9c29f40cf97a71a341abe855f,
-                )),
-                context,
-            ),
+            image: bevy::ui::UiImage::from_input(input.image, id.field("image"), context),
             transform: input.transform,
             global_transform: input.global_transform,
             visibility: input.visibility,
@@ -593,6 +738,44 @@ impl FromSchematicInput<ButtonBundle> for bevy::ui::node_bundles::ButtonBundle {
     }
 }
 
+/// A convenience [`Schematic`] for making a UI node interactive.
+///
+/// This inserts the following components:
+/// * [`Interaction`] (defaults to `Interaction::None`)
+/// * [`FocusPolicy`] (defaults to `FocusPolicy::Block`)
+/// * [`RelativeCursorPosition`] (defaults to no cursor position)
+///
+/// Unlike [`ButtonBundle`], this does not insert a [`Node`] or any other
+/// layout components, so it should be combined with something like [`NodeBundle`]
+/// or [`ImageBundle`]:
+///
+/// ```text
+/// (
+///   name: "MenuItem",
+///   schematics: {
+///     "bevy_proto::custom::NodeBundle": (),
+///     "bevy_proto::custom::Pickable": (),
+///   },
+/// )
+/// ```
+///
+/// [`Interaction`]: bevy::ui::Interaction
+/// [`FocusPolicy`]: bevy::ui::FocusPolicy
+/// [`RelativeCursorPosition`]: bevy::ui::RelativeCursorPosition
+/// [`Node`]: bevy::ui::Node
+#[cfg(feature = "bevy_ui")]
+#[derive(bevy::ecs::bundle::Bundle, Schematic, Reflect, Default)]
+#[reflect(Schematic, Default)]
+#[schematic(kind = "bundle")]
+pub struct Pickable {
+    #[reflect(default)]
+    pub interaction: bevy::ui::Interaction,
+    #[reflect(default)]
+    pub focus_policy: bevy::ui::FocusPolicy,
+    #[reflect(default)]
+    pub relative_cursor_position: bevy::ui::RelativeCursorPosition,
+}
+
 /// A [`Schematic`] implementation of [`ImageBundle`].
 ///
 /// [`ImageBundle`]: bevy::ui::node_bundles::ImageBundle
@@ -633,13 +816,7 @@ impl FromSchematicInput<ImageBundle> for bevy::ui::node_bundles::ImageBundle {
             style: input.style.into(),
             calculated_size: Default::default(),
             background_color: input.background_color.into(),
-            image: bevy::ui::UiImage::from_input(
-                input.image,
-                id.next(bevy::utils::Uuid::from_u128(
-                    0x5f3dc1f3b56d49e99f2a5978aea6b745,
-                )),
-                context,
-            ),
+            image: bevy::ui::UiImage::from_input(input.image, id.field("image"), context),
             focus_policy: input.focus_policy,
             transform: input.transform,
             global_transform: input.global_transform,
@@ -731,25 +908,11 @@ impl FromSchematicInput<TextBundle> for bevy::ui::node_bundles::TextBundle {
     fn from_input(input: TextBundle, id: SchematicId, context: &mut SchematicContext) -> Self {
         Self {
             node: input.node,
-            style: FromSchematicInput::from_input(
-                input.style,
-                id.next(bevy::utils::Uuid::from_u128(
-                    0x63ca849a99e44de2b6fcad4cbdd03640,
-                )),
-                context,
-            ),
-            text: FromSchematicInput::from_input(
-                input.text,
-                id.next(bevy::utils::Uuid::from_u128(
-                    0xd3a21a2a19ab4a18b8a60f5ab95f299d,
-                )),
-                context,
-            ),
+            style: FromSchematicInput::from_input(input.style, id.field("style"), context),
+            text: FromSchematicInput::from_input(input.text, id.field("text"), context),
             text_layout_info: FromSchematicInput::from_input(
                 input.text_layout_info,
-                id.next(bevy::utils::Uuid::from_u128(
-                    0x3647343b704b40f2a789b890cf417c18,
-                )),
+                id.field("text_layout_info"),
                 context,
             ),
             text_flags: input.text_flags,
@@ -762,9 +925,7 @@ impl FromSchematicInput<TextBundle> for bevy::ui::node_bundles::TextBundle {
             z_index: input.z_index,
             background_color: FromSchematicInput::from_input(
                 input.background_color,
-                id.next(bevy::utils::Uuid::from_u128(
-                    0xc82dc5faf3ff4442878496f8d77187c3,
-                )),
+                id.field("background_color"),
                 context,
             ),
         }
@@ -856,13 +1017,7 @@ where
         context: &mut SchematicContext,
     ) -> Self {
         Self {
-            mesh: bevy::sprite::Mesh2dHandle::from_input(
-                input.mesh,
-                id.next(bevy::utils::Uuid::from_u128(
-                    0xc96384968f7f4143906ec8802addfac0,
-                )),
-                context,
-            ),
+            mesh: bevy::sprite::Mesh2dHandle::from_input(input.mesh, id.field("mesh"), context),
             material: input.material,
             transform: input.transform,
             global_transform: input.global_transform,
@@ -871,3 +1026,163 @@ where
         }
     }
 }
+
+/// The default gap between the listener's ears used by [`SpatialAudioListener`].
+#[cfg(feature = "bevy_audio")]
+const DEFAULT_EAR_GAP: f32 = 4.0;
+
+/// A spatial audio emitter.
+///
+/// This sets up a [`SpatialAudioBundle`] on the entity, positioned relative to the
+/// entity marked with [`SpatialAudioListener`] (if any).
+/// The emitter and listener positions are kept up to date every frame.
+///
+/// ```text
+/// "bevy_proto::custom::SpatialAudioEmitter": (
+///   source: "sounds/waterfall.ogg",
+///   gain: 0.8,
+///   spatial_scale: 0.1,
+///   repeat: true,
+/// ),
+/// ```
+///
+/// [`SpatialAudioBundle`]: bevy::audio::SpatialAudioBundle
+#[cfg(feature = "bevy_audio")]
+#[derive(Component, Schematic, Reflect)]
+#[reflect(Schematic)]
+pub struct SpatialAudioEmitter {
+    /// The audio to play.
+    #[schematic(asset)]
+    pub source: Handle<bevy::audio::AudioSource>,
+    /// The relative volume of the audio.
+    ///
+    /// Defaults to `1.0`.
+    #[schematic_attr(reflect(default = "default_gain"))]
+    pub gain: f32,
+    /// The scale applied to world positions before they're used to spatialize the audio.
+    ///
+    /// Smaller values make the sound fall off more slowly with distance.
+    ///
+    /// Defaults to `1.0`.
+    #[schematic_attr(reflect(default = "default_spatial_scale"))]
+    pub spatial_scale: f32,
+    /// Whether the audio should loop.
+    ///
+    /// Defaults to `false`.
+    #[schematic_attr(reflect(default))]
+    pub repeat: bool,
+}
+
+/// A marker for the entity whose position is used to listen to [`SpatialAudioEmitter`]s.
+///
+/// Only a single listener is supported at a time.
+#[cfg(feature = "bevy_audio")]
+#[derive(Component, Schematic, Reflect)]
+#[reflect(Schematic, Default)]
+pub struct SpatialAudioListener {
+    /// The distance between the listener's ears.
+    ///
+    /// Defaults to `4.0`.
+    pub ear_gap: f32,
+}
+
+#[cfg(feature = "bevy_audio")]
+impl Default for SpatialAudioListener {
+    fn default() -> Self {
+        Self {
+            ear_gap: DEFAULT_EAR_GAP,
+        }
+    }
+}
+
+#[cfg(feature = "bevy_audio")]
+fn default_gain() -> f32 {
+    1.0
+}
+
+#[cfg(feature = "bevy_audio")]
+fn default_spatial_scale() -> f32 {
+    1.0
+}
+
+/// Scales the translation of the given listener transform.
+#[cfg(feature = "bevy_audio")]
+fn scale_listener(listener: &GlobalTransform, scale: f32) -> Transform {
+    let mut transform = listener.compute_transform();
+    transform.translation *= scale;
+    transform
+}
+
+/// Inserts the audio bundle for any newly added [`SpatialAudioEmitter`].
+#[cfg(feature = "bevy_audio")]
+fn setup_spatial_audio_emitters(
+    mut commands: bevy::prelude::Commands,
+    emitters: bevy::prelude::Query<
+        (
+            bevy::prelude::Entity,
+            &SpatialAudioEmitter,
+            Option<&GlobalTransform>,
+        ),
+        bevy::prelude::Added<SpatialAudioEmitter>,
+    >,
+    listeners: bevy::prelude::Query<(&GlobalTransform, &SpatialAudioListener)>,
+) {
+    let listener = listeners.get_single().ok();
+
+    for (entity, emitter, transform) in &emitters {
+        let (listener_transform, ear_gap) = listener
+            .map(|(transform, listener)| {
+                (
+                    scale_listener(transform, emitter.spatial_scale),
+                    listener.ear_gap,
+                )
+            })
+            .unwrap_or((Transform::IDENTITY, DEFAULT_EAR_GAP));
+
+        let emitter_position = transform
+            .map(GlobalTransform::translation)
+            .unwrap_or_default()
+            * emitter.spatial_scale;
+
+        let settings = if emitter.repeat {
+            bevy::audio::PlaybackSettings::LOOP
+        } else {
+            bevy::audio::PlaybackSettings::ONCE
+        };
+
+        commands
+            .entity(entity)
+            .insert(bevy::audio::SpatialAudioBundle {
+                source: emitter.source.clone(),
+                settings: settings.with_volume(bevy::audio::Volume::new_relative(emitter.gain)),
+                spatial: bevy::audio::SpatialSettings::new(
+                    listener_transform,
+                    ear_gap,
+                    emitter_position,
+                ),
+            });
+    }
+}
+
+/// Keeps the emitter and listener positions of each [`SpatialAudioEmitter`] up to date.
+#[cfg(feature = "bevy_audio")]
+fn update_spatial_audio_emitters(
+    emitters: bevy::prelude::Query<(
+        &SpatialAudioEmitter,
+        &GlobalTransform,
+        &bevy::audio::SpatialAudioSink,
+    )>,
+    listeners: bevy::prelude::Query<(&GlobalTransform, &SpatialAudioListener)>,
+) {
+    let Ok((listener_transform, listener)) = listeners.get_single() else {
+        return;
+    };
+
+    for (emitter, transform, sink) in &emitters {
+        sink.set_emitter_position(transform.translation() * emitter.spatial_scale);
+        sink.set_listener_position(
+            scale_listener(listener_transform, emitter.spatial_scale),
+            listener.ear_gap,
+        );
+    }
+}
@@ -15,12 +15,14 @@ use crate::proto::{ProtoChild, ProtoChildValue};
 
 pub(super) const PROTO_CHILD: &str = "ProtoChild";
 const PROTO_CHILD_MERGE_KEY: &str = "merge_key";
+const PROTO_CHILD_ID: &str = "id";
 const PROTO_CHILD_VALUE: &str = "value";
 
 #[derive(Deserialize, Debug)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum ProtoChildField {
     MergeKey,
+    Id,
     Value,
 }
 
@@ -64,6 +66,7 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                 Ok(ProtoChild {
                     handle,
                     merge_key: None,
+                    local_id: None,
                 })
             }
 
@@ -72,6 +75,7 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                 A: MapAccess<'de>,
             {
                 let mut merge_key: Option<String> = None;
+                let mut local_id: Option<String> = None;
                 let mut handle: Option<Handle<Prototype>> = None;
 
                 while let Some(key) = map.next_key::<ProtoChildField>()? {
@@ -82,6 +86,16 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                             }
                             merge_key = map.next_value::<Option<String>>()?;
                         }
+                        ProtoChildField::Id => {
+                            if local_id.is_some() {
+                                return Err(Error::duplicate_field(PROTO_CHILD_ID));
+                            }
+                            let id = map.next_value::<String>()?;
+                            if !self.builder.context_mut().register_local_id(id.clone()) {
+                                return Err(Error::custom(format!("duplicate local ID {:?}", id)));
+                            }
+                            local_id = Some(id);
+                        }
                         ProtoChildField::Value => {
                             if handle.is_some() {
                                 return Err(Error::duplicate_field(PROTO_CHILD_VALUE));
@@ -103,6 +117,7 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
 
                 Ok(ProtoChild {
                     merge_key,
+                    local_id,
                     handle: handle.ok_or_else(|| Error::missing_field(PROTO_CHILD_VALUE))?,
                 })
             }
@@ -7,10 +7,11 @@ use serde::{Deserialize, Deserializer};
 use bevy_proto_backend::children::Children;
 use bevy_proto_backend::load::{Loader, ProtoLoadContext};
 use bevy_proto_backend::path::{ProtoPathContext, ProtoPathListDeserializer};
-use bevy_proto_backend::schematics::Schematics;
-use bevy_proto_backend::templates::Templates;
+use bevy_proto_backend::schematics::{Markers, Schematics};
+use bevy_proto_backend::templates::{Mixins, Templates};
 
 use crate::de::ProtoChildrenDeserializer;
+use crate::loader::{is_namespaced, namespaced};
 use crate::prelude::Prototype;
 use crate::schematics::SchematicsDeserializer;
 
@@ -19,6 +20,11 @@ const TEMPLATES: &str = "templates";
 const SCHEMATICS: &str = "schematics";
 const CHILDREN: &str = "children";
 const ENTITY: &str = "entity";
+const MARKERS: &str = "markers";
+const INCLUDE: &str = "include";
+const ABSTRACT: &str = "abstract";
+const DEFAULT_TEMPLATES: &str = "default_templates";
+const NAMESPACE: &str = "namespace";
 
 #[derive(Deserialize, Debug)]
 #[serde(field_identifier, rename_all = "snake_case")]
@@ -28,6 +34,11 @@ enum PrototypeField {
     Schematics,
     Children,
     Entity,
+    Markers,
+    Include,
+    Abstract,
+    DefaultTemplates,
+    Namespace,
 }
 
 pub struct PrototypeDeserializer<'a, 'ctx, 'load_ctx, L: Loader<Prototype>> {
@@ -71,6 +82,11 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                 let mut schematics: Option<Schematics> = None;
                 let mut children: Option<Children<Prototype>> = None;
                 let mut requires_entity: Option<bool> = None;
+                let mut markers: Option<Markers> = None;
+                let mut mixins: Option<Mixins> = None;
+                let mut is_abstract: Option<bool> = None;
+                let mut use_default_templates: Option<bool> = None;
+                let mut namespace: Option<String> = None;
 
                 while let Some(key) = map.next_key::<PrototypeField>()? {
                     match key {
@@ -99,9 +115,13 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                                 return Err(Error::duplicate_field(SCHEMATICS));
                             }
 
-                            schematics = Some(map.next_value_seed(SchematicsDeserializer::new(
-                                self.context.registry(),
-                            ))?);
+                            schematics = Some(
+                                map.next_value_seed(
+                                    SchematicsDeserializer::new(self.context.registry())
+                                        .with_flags(self.context.flags())
+                                        .with_type_path_aliases(self.context.type_path_aliases()),
+                                )?,
+                            );
                         }
                         PrototypeField::Children => {
                             if children.is_some() {
@@ -128,15 +148,75 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
                             }
                             requires_entity = Some(map.next_value::<bool>()?)
                         }
+                        PrototypeField::Markers => {
+                            if markers.is_some() {
+                                return Err(Error::duplicate_field(MARKERS));
+                            }
+
+                            let value = Markers(map.next_value::<Vec<String>>()?);
+                            value
+                                .validate(self.context.registry())
+                                .map_err(Error::custom)?;
+                            markers = Some(value);
+                        }
+                        PrototypeField::Include => {
+                            if mixins.is_some() {
+                                return Err(Error::duplicate_field(INCLUDE));
+                            }
+                            mixins = Some(Mixins::from_iter(map.next_value::<Vec<String>>()?));
+                        }
+                        PrototypeField::Abstract => {
+                            if is_abstract.is_some() {
+                                return Err(Error::duplicate_field(ABSTRACT));
+                            }
+                            is_abstract = Some(map.next_value::<bool>()?)
+                        }
+                        PrototypeField::DefaultTemplates => {
+                            if use_default_templates.is_some() {
+                                return Err(Error::duplicate_field(DEFAULT_TEMPLATES));
+                            }
+                            use_default_templates = Some(map.next_value::<bool>()?)
+                        }
+                        PrototypeField::Namespace => {
+                            if namespace.is_some() {
+                                return Err(Error::duplicate_field(NAMESPACE));
+                            }
+                            namespace = Some(map.next_value::<String>()?)
+                        }
+                    }
+                }
+
+                let mut schematics = schematics.unwrap_or_default();
+                if let Some(markers) = markers {
+                    if schematics.contains::<Markers>() {
+                        return Err(Error::custom(
+                            "cannot define both `markers` and a `Markers` schematic",
+                        ));
+                    }
+
+                    schematics.insert::<Markers>(markers);
+                }
+
+                let mut id = id.ok_or_else(|| Error::missing_field(NAME))?;
+                if let Some(namespace) = namespace {
+                    if is_namespaced(&id) {
+                        return Err(Error::custom(format!(
+                            "prototype {:?} cannot be given a namespace as it already has one",
+                            id
+                        )));
                     }
+                    id = namespaced(&namespace, &id);
                 }
 
                 Ok(Prototype {
-                    id: id.ok_or_else(|| Error::missing_field(NAME))?,
+                    id: id.into(),
                     path: self.context.base_path().into(),
                     requires_entity: requires_entity.unwrap_or(true),
+                    is_abstract: is_abstract.unwrap_or_default(),
+                    use_default_templates: use_default_templates.unwrap_or(true),
                     templates,
-                    schematics: schematics.unwrap_or_default(),
+                    mixins,
+                    schematics,
                     children,
                     dependencies: Default::default(),
                 })
@@ -145,7 +225,18 @@ impl<'a, 'ctx, 'load_ctx, 'de, L: Loader<Prototype>> DeserializeSeed<'de>
 
         deserializer.deserialize_struct(
             std::any::type_name::<Prototype>(),
-            &[NAME, TEMPLATES, SCHEMATICS, CHILDREN, ENTITY],
+            &[
+                NAME,
+                TEMPLATES,
+                SCHEMATICS,
+                CHILDREN,
+                ENTITY,
+                MARKERS,
+                INCLUDE,
+                ABSTRACT,
+                DEFAULT_TEMPLATES,
+                NAMESPACE,
+            ],
             PrototypeVisitor {
                 context: self.context,
             },
@@ -3,6 +3,8 @@
 //! [config]: crate::config::ProtoConfig
 
 use bevy::asset::Handle;
+use bevy::ecs::world::World;
+use bevy::reflect::Reflect;
 
 use bevy_proto_backend::cycles::{Cycle, CycleResponse};
 use bevy_proto_backend::schematics::{DynamicSchematic, SchematicContext, SchematicId};
@@ -12,6 +14,7 @@ use crate::proto::Prototype;
 pub type OnRegisterPrototype = Box<dyn FnMut(&Prototype, Handle<Prototype>) + Send + Sync>;
 pub type OnReloadPrototype = Box<dyn FnMut(&Prototype, Handle<Prototype>) + Send + Sync>;
 pub type OnUnregisterPrototype = Box<dyn FnMut(&String, Handle<Prototype>) + Send + Sync>;
+pub type OnPrototypeReady = Box<dyn FnMut(&Prototype, &mut World) + Send + Sync>;
 pub type OnBeforeApplyPrototype = Box<dyn FnMut(&Prototype, &mut SchematicContext) + Send + Sync>;
 pub type OnAfterApplyPrototype = Box<dyn FnMut(&Prototype, &mut SchematicContext) + Send + Sync>;
 pub type OnBeforeRemovePrototype = Box<dyn FnMut(&Prototype, &mut SchematicContext) + Send + Sync>;
@@ -24,4 +27,8 @@ pub type OnBeforeRemoveSchematic =
     Box<dyn FnMut(&DynamicSchematic, SchematicId, &mut SchematicContext) + Send + Sync>;
 pub type OnAfterRemoveSchematic =
     Box<dyn FnMut(&DynamicSchematic, SchematicId, &mut SchematicContext) + Send + Sync>;
+pub type SchematicTransform =
+    Box<dyn FnMut(&mut DynamicSchematic, &mut SchematicContext) + Send + Sync>;
 pub type OnCycle = Box<dyn Fn(&Cycle<Prototype>) -> CycleResponse + Send + Sync>;
+pub type NameFormatter = Box<dyn Fn(&str) -> String + Send + Sync>;
+pub type FieldValidator = Box<dyn Fn(&dyn Reflect) -> Result<(), String> + Send + Sync>;
@@ -45,7 +45,9 @@
 //!
 //! | Feature           | Default | Description                                                    |
 //! | ----------------- | ------- | -------------------------------------------------------------- |
+//! | analysis          | ❌      | Report unused prototypes once loading completes                |
 //! | auto_name         | ✅      | Automatically insert [`Name`] components on spawned prototypes |
+//! | compression       | ❌      | Enables loading compressed prototypes and [prototype bundles]  |
 //! | custom_schematics | ✅      | Enables some [custom schematics] defined by this crate         |
 //! | ron               | ✅      | Enables RON deserialization                                    |
 //! | yaml              | ❌      | Enables YAML deserialization                                   |
@@ -58,9 +60,22 @@
 //! | bevy_sprite       | ✅      | Registers types under Bevy's `bevy_sprite` feature             |
 //! | bevy_text         | ✅      | Registers types under Bevy's `bevy_text` feature               |
 //!
+//! ## Headless
+//!
+//! For headless apps, such as dedicated servers, the Bevy features can be disabled
+//! to avoid pulling in any rendering, UI, or audio types:
+//!
+//! ```toml
+//! bevy_proto = { version = "0.11", default-features = false, features = ["auto_name", "custom_schematics", "ron"] }
+//! ```
+//!
+//! In this configuration, only the non-rendering types are registered
+//! (e.g. transforms, `Name`, and windowing types), along with the `TransformBundle` custom schematic.
+//!
 //! [prototypes]: proto::Prototype
 //! [`Name`]: bevy::core::Name
 //! [custom schematics]: custom
+//! [prototype bundles]: loader::ProtoLoader#bundles
 
 mod conditions;
 pub mod config;
@@ -81,12 +96,16 @@ mod schematics;
 /// ```
 pub mod prelude {
     pub use crate::config::ProtoConfig;
-    pub use bevy_proto_backend::assets::{AssetSchematic, AssetSchematicAppExt};
+    pub use bevy_proto_backend::assets::{
+        AssetSchematic, AssetSchematicAppExt, ProtoAssetEventReader,
+    };
     pub use bevy_proto_backend::deps::DependenciesBuilder;
     pub use bevy_proto_backend::proto::Prototypical;
     pub use bevy_proto_backend::schematics::{
-        ReflectSchematic, Schematic, SchematicContext, SchematicId,
+        ProtoExpr, ProtoParams, ReflectSchematic, Schematic, SchematicContext, SchematicError,
+        SchematicId,
     };
+    pub use bevy_proto_backend::ProtoSet;
 
     pub use super::conditions::*;
     pub use super::plugin::ProtoPlugin;
@@ -122,6 +141,15 @@ pub mod prelude {
     pub type ProtoEntityCommands<'w, 's, 'a, C = ProtoConfig> =
         bevy_proto_backend::proto::ProtoEntityCommands<'w, 's, 'a, Prototype, C>;
 
+    /// A wrapper around a [`World`] for immediately spawning [prototypes] into it.
+    ///
+    /// This can be used to target worlds other than the main app's world.
+    ///
+    /// [`World`]: bevy::prelude::World
+    /// [prototypes]: Prototype
+    pub type ProtoWorld<'w, C = ProtoConfig> =
+        bevy_proto_backend::proto::ProtoWorld<'w, Prototype, C>;
+
     /// Asset lifecycle events for [prototype] assets.
     ///
     /// This is analogous to [`AssetEvent`], but accounts for prototype
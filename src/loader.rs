@@ -1,11 +1,20 @@
 use crate::de::PrototypeDeserializer;
 use crate::proto::{Prototype, PrototypeError};
-use bevy_proto_backend::load::{Loader, ProtoLoadContext};
+#[cfg(feature = "compression")]
+use bevy_proto_backend::load::ProtoBundleEntry;
+use bevy_proto_backend::load::{Loader, ProtoLoadContext, ProtoLoadMeta};
 use bevy_proto_backend::path::ProtoPathContext;
 use serde::de::DeserializeSeed;
+use std::path::{Path, PathBuf};
 
 const RON_FORMATS: &[&str] = &["prototype.ron", "proto.ron"];
 const YAML_FORMATS: &[&str] = &["prototype.yaml", "proto.yaml"];
+#[cfg(feature = "compression")]
+const RON_GZ_FORMATS: &[&str] = &["prototype.ron.gz", "proto.ron.gz"];
+#[cfg(feature = "compression")]
+const YAML_GZ_FORMATS: &[&str] = &["prototype.yaml.gz", "proto.yaml.gz"];
+#[cfg(feature = "compression")]
+const BUNDLE_FORMATS: &[&str] = &["prototypes.zip"];
 
 /// The default prototype loader.
 ///
@@ -16,11 +25,184 @@ const YAML_FORMATS: &[&str] = &["prototype.yaml", "proto.yaml"];
 /// | [RON]    | `ron`   | `.prototype.ron`, `.proto.ron` |
 /// | [YAML]   | `yaml`  | `.prototype.yaml`, `.proto.yaml` |
 ///
+/// Additional extensions can be mapped to one of the supported [formats]
+/// using [`ProtoLoader::with_extension`].
+///
+/// # Bundles
+///
+/// With the `compression` feature enabled, the following are also supported:
+///
+/// | Kind                | Extensions |
+/// | ------------------- | ---------- |
+/// | Gzipped prototype   | `.prototype.ron.gz`, `.proto.ron.gz`, `.prototype.yaml.gz`, `.proto.yaml.gz` |
+/// | Zip bundle          | `.prototypes.zip` |
+///
+/// A gzipped prototype is a single prototype file compressed with gzip.
+/// It is decompressed and then loaded like its uncompressed counterpart.
+///
+/// A zip bundle contains any number of prototype files, optionally nested in directories:
+///
+/// ```text
+/// prototypes.prototypes.zip
+/// ├── Player.prototype.ron
+/// ├── enemies/
+/// │   ├── Goblin.prototype.ron
+/// │   └── Orc.prototype.yaml.gz
+/// └── README.md
+/// ```
+///
+/// Each entry ending in one of the loader's prototype extensions (e.g. `.prototype.ron`)
+/// is deserialized on its own and registered as a labeled asset named after the entry's path
+/// within the archive (e.g. `prototypes.prototypes.zip#enemies/Goblin.prototype.ron`).
+/// All other entries are ignored, including plain data files like `settings.ron`.
+/// Prototypes are still registered by the name defined within their file,
+/// so they can be spawned by ID as usual.
+///
+/// Paths within a bundled prototype (such as templates or children)
+/// are resolved relative to the bundle itself.
+///
+/// # Namespaces
+///
+/// To avoid collisions between content packs that define prototypes with the same name,
+/// IDs can be namespaced as `namespace::Name`.
+///
+/// A namespace can be given explicitly within a prototype file using the `namespace` field,
+/// or assigned to every prototype within a directory using [`ProtoLoader::with_namespace`].
+/// An explicit namespace always takes precedence,
+/// and names that already contain a namespace (i.e. `"core::Sword"`) are left as-is.
+///
+/// Namespaced prototypes must be referenced by their full ID (e.g. `spawn("core::Sword")`).
+/// An unnamespaced ID only ever refers to a prototype without a namespace—
+/// there is no search across namespaces, so resolution is never ambiguous.
+///
 /// [RON]: https://github.com/ron-rs/ron
 /// [YAML]: https://github.com/dtolnay/serde-yaml
+/// [formats]: ProtoFormat
 #[derive(Clone)]
 pub struct ProtoLoader {
     extensions: Vec<&'static str>,
+    custom_extensions: Vec<(&'static str, ProtoFormat)>,
+    namespaces: Vec<(PathBuf, String)>,
+}
+
+/// The file formats supported by the [`ProtoLoader`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ProtoFormat {
+    /// [RON](https://github.com/ron-rs/ron) (requires the `ron` feature).
+    #[cfg(feature = "ron")]
+    Ron,
+    /// [YAML](https://github.com/dtolnay/serde-yaml) (requires the `yaml` feature).
+    #[cfg(feature = "yaml")]
+    Yaml,
+}
+
+impl ProtoLoader {
+    /// Register an additional extension to be loaded using the given [format].
+    ///
+    /// The extension should not be prepended by a dot (`.`).
+    ///
+    /// Note that extensions are registered with the asset server when the plugin is built,
+    /// so this must be called before then.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_proto::loader::{ProtoFormat, ProtoLoader};
+    /// let loader = ProtoLoader::default().with_extension("myproto", ProtoFormat::Ron);
+    /// ```
+    ///
+    /// [format]: ProtoFormat
+    pub fn with_extension(mut self, extension: &'static str, format: ProtoFormat) -> Self {
+        if !self.extensions.contains(&extension) {
+            self.extensions.push(extension);
+        }
+        self.custom_extensions.push((extension, format));
+        self
+    }
+
+    /// Assign a namespace to all prototypes loaded from within the given directory.
+    ///
+    /// The directory is relative to the assets folder.
+    /// If multiple directories contain a prototype, the most specific (longest) one is used.
+    ///
+    /// See the [namespaces] section for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_proto::loader::ProtoLoader;
+    /// let loader = ProtoLoader::default().with_namespace("packs/core", "core");
+    /// ```
+    ///
+    /// [namespaces]: ProtoLoader#namespaces
+    pub fn with_namespace(
+        mut self,
+        directory: impl Into<PathBuf>,
+        namespace: impl Into<String>,
+    ) -> Self {
+        self.namespaces.push((directory.into(), namespace.into()));
+        self
+    }
+
+    /// Get the namespace assigned to the directory containing the given path, if any.
+    fn get_namespace(&self, path: &Path) -> Option<&str> {
+        self.namespaces
+            .iter()
+            .filter(|(directory, _)| path.starts_with(directory))
+            .max_by_key(|(directory, _)| directory.components().count())
+            .map(|(_, namespace)| namespace.as_str())
+    }
+
+    /// Returns true if the file at the given path ends in one of this loader's
+    /// prototype extensions.
+    ///
+    /// Bundles themselves are not considered prototype files.
+    #[cfg(feature = "compression")]
+    fn is_prototype_file(&self, path: &Path) -> bool {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        self.extensions
+            .iter()
+            .filter(|extension| !BUNDLE_FORMATS.contains(extension))
+            .any(|extension| file_name.ends_with(&format!(".{}", extension.to_lowercase())))
+    }
+
+    /// Determine the format of the file at the given path.
+    fn get_format(&self, path: &Path) -> Result<ProtoFormat, PrototypeError> {
+        #[cfg(feature = "compression")]
+        let path = &strip_gz(path);
+
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+
+        for (extension, format) in &self.custom_extensions {
+            if file_name.ends_with(&format!(".{}", extension.to_lowercase())) {
+                return Ok(*format);
+            }
+        }
+
+        let ext = path
+            .extension()
+            .ok_or_else(|| PrototypeError::MissingExtension(path.to_path_buf()))?;
+
+        let ext = ext
+            .to_str()
+            .ok_or_else(|| PrototypeError::UnsupportedExtension(ext.to_string_lossy().to_string()))?
+            .to_lowercase();
+
+        match ext.as_str() {
+            #[cfg(feature = "ron")]
+            "ron" => Ok(ProtoFormat::Ron),
+            #[cfg(feature = "yaml")]
+            "yaml" => Ok(ProtoFormat::Yaml),
+            other => Err(PrototypeError::UnsupportedExtension(other.to_string())),
+        }
+    }
 }
 
 impl Default for ProtoLoader {
@@ -35,7 +217,24 @@ impl Default for ProtoLoader {
             extensions.extend(RON_FORMATS);
         }
 
-        Self { extensions }
+        #[cfg(feature = "compression")]
+        {
+            if cfg!(feature = "yaml") {
+                extensions.extend(YAML_GZ_FORMATS);
+            }
+
+            if cfg!(feature = "ron") {
+                extensions.extend(RON_GZ_FORMATS);
+            }
+
+            extensions.extend(BUNDLE_FORMATS);
+        }
+
+        Self {
+            extensions,
+            custom_extensions: Vec::new(),
+            namespaces: Vec::new(),
+        }
     }
 }
 
@@ -46,22 +245,26 @@ impl Loader<Prototype> for ProtoLoader {
         bytes: &[u8],
         ctx: &mut ProtoLoadContext<Prototype, Self>,
     ) -> Result<Prototype, Self::Error> {
-        let path = ctx.base_path().to_path_buf();
+        let path = match ctx.bundle_entry() {
+            Some(entry) => PathBuf::from(format!("{}#{}", ctx.base_path().display(), entry)),
+            None => ctx.base_path().to_path_buf(),
+        };
+        let format = ctx.loader().get_format(&path)?;
 
-        let ext = path
-            .extension()
-            .ok_or_else(|| PrototypeError::MissingExtension(path.clone()))?;
-
-        let ext = ext
-            .to_str()
-            .ok_or_else(|| PrototypeError::UnsupportedExtension(ext.to_string_lossy().to_string()))?
-            .to_lowercase();
+        #[cfg(feature = "compression")]
+        let decompressed = if is_gz(&path) {
+            Some(decompress_gz(bytes, &path)?)
+        } else {
+            None
+        };
+        #[cfg(feature = "compression")]
+        let bytes = decompressed.as_deref().unwrap_or(bytes);
 
         let deserializer = PrototypeDeserializer::new(ctx);
 
-        match ext.as_str() {
+        match format {
             #[cfg(feature = "ron")]
-            "ron" => {
+            ProtoFormat::Ron => {
                 let mut ron_de = ron::Deserializer::from_bytes(bytes)
                     .map_err(|err| PrototypeError::SpannedRonError(path.clone(), err))?;
                 deserializer.deserialize(&mut ron_de).map_err(|err| {
@@ -69,14 +272,152 @@ impl Loader<Prototype> for ProtoLoader {
                 })
             }
             #[cfg(feature = "yaml")]
-            "yaml" => deserializer
+            ProtoFormat::Yaml => deserializer
                 .deserialize(serde_yaml::Deserializer::from_slice(bytes))
                 .map_err(PrototypeError::from),
-            other => Err(PrototypeError::UnsupportedExtension(other.to_string())),
         }
     }
 
     fn extensions(&self) -> &[&'static str] {
         &self.extensions
     }
+
+    fn on_load_prototype(
+        &self,
+        mut prototype: Prototype,
+        meta: &ProtoLoadMeta<Prototype>,
+    ) -> Result<Prototype, Self::Error> {
+        if !is_namespaced(&prototype.id) {
+            if let Some(namespace) = self.get_namespace(meta.path.path()) {
+                prototype.id = namespaced(namespace, &prototype.id).into();
+            }
+        }
+
+        Ok(prototype)
+    }
+
+    #[cfg(feature = "compression")]
+    fn unbundle(
+        &self,
+        bytes: &[u8],
+        path: &Path,
+    ) -> Result<Option<Vec<ProtoBundleEntry>>, Self::Error> {
+        use std::io::Read;
+
+        let is_bundle = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_lowercase().ends_with(".zip"))
+            .unwrap_or_default();
+
+        if !is_bundle {
+            return Ok(None);
+        }
+
+        let to_error = |err: &dyn std::fmt::Display| {
+            PrototypeError::CompressionError(path.to_path_buf(), err.to_string())
+        };
+
+        let mut archive =
+            zip::ZipArchive::new(std::io::Cursor::new(bytes)).map_err(|err| to_error(&err))?;
+
+        let mut entries = Vec::new();
+        for index in 0..archive.len() {
+            let mut file = archive.by_index(index).map_err(|err| to_error(&err))?;
+
+            if file.is_dir() || !self.is_prototype_file(Path::new(file.name())) {
+                continue;
+            }
+
+            let name = file.name().to_string();
+            let mut bytes = Vec::new();
+            file.read_to_end(&mut bytes).map_err(|err| to_error(&err))?;
+
+            entries.push(ProtoBundleEntry { name, bytes });
+        }
+
+        Ok(Some(entries))
+    }
+}
+
+/// The separator between a namespace and a prototype's name.
+const NAMESPACE_SEPARATOR: &str = "::";
+
+/// Returns true if the given ID already contains a namespace.
+pub(crate) fn is_namespaced(id: &str) -> bool {
+    id.contains(NAMESPACE_SEPARATOR)
+}
+
+/// Prefixes the given name with the given namespace.
+pub(crate) fn namespaced(namespace: &str, name: &str) -> String {
+    format!("{}{}{}", namespace, NAMESPACE_SEPARATOR, name)
+}
+
+/// Returns true if the given path ends with a `.gz` extension.
+#[cfg(feature = "compression")]
+fn is_gz(path: &Path) -> bool {
+    path.extension()
+        .map(|ext| ext.eq_ignore_ascii_case("gz"))
+        .unwrap_or_default()
+}
+
+/// Removes the `.gz` extension from the given path, if any.
+#[cfg(feature = "compression")]
+fn strip_gz(path: &Path) -> PathBuf {
+    if is_gz(path) {
+        path.with_extension("")
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Decompresses the given gzipped bytes.
+#[cfg(feature = "compression")]
+fn decompress_gz(bytes: &[u8], path: &Path) -> Result<Vec<u8>, PrototypeError> {
+    use std::io::Read;
+
+    let mut decompressed = Vec::new();
+    flate2::read::GzDecoder::new(bytes)
+        .read_to_end(&mut decompressed)
+        .map_err(|err| PrototypeError::CompressionError(path.to_path_buf(), err.to_string()))?;
+    Ok(decompressed)
+}
+
+#[cfg(all(test, feature = "compression", feature = "ron"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_only_unbundle_prototype_files() {
+        use std::io::Write;
+
+        let mut bytes = Vec::new();
+        {
+            let mut zip = zip::ZipWriter::new(std::io::Cursor::new(&mut bytes));
+            let options = zip::write::FileOptions::default();
+            for name in [
+                "Player.prototype.ron",
+                "enemies/Goblin.proto.ron",
+                "settings.ron",
+                "README.md",
+            ] {
+                zip.start_file(name, options).unwrap();
+                zip.write_all(b"()").unwrap();
+            }
+            zip.finish().unwrap();
+        }
+
+        let entries = ProtoLoader::default()
+            .unbundle(&bytes, Path::new("content.prototypes.zip"))
+            .unwrap()
+            .unwrap();
+
+        let names = entries
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec!["Player.prototype.ron", "enemies/Goblin.proto.ron"],
+            names
+        );
+    }
 }
@@ -7,7 +7,7 @@ use bevy_proto_backend::proto::Config;
 use bevy_proto_backend::ProtoBackendPlugin;
 
 use crate::config::ProtoConfig;
-use crate::loader::ProtoLoader;
+use crate::loader::{ProtoFormat, ProtoLoader};
 use crate::proto::Prototype;
 
 /// Adds support for [`Prototype`] assets.
@@ -26,6 +26,7 @@ use crate::proto::Prototype;
 pub struct ProtoPlugin<L: Loader<Prototype> = ProtoLoader, C: Config<Prototype> = ProtoConfig> {
     loader: Mutex<Option<L>>,
     config: Mutex<Option<C>>,
+    validate_on_startup: bool,
 }
 
 impl ProtoPlugin {
@@ -34,6 +35,7 @@ impl ProtoPlugin {
         Self {
             loader: Mutex::new(None),
             config: Mutex::new(None),
+            validate_on_startup: false,
         }
     }
 }
@@ -44,6 +46,7 @@ impl<L: Loader<Prototype>> ProtoPlugin<L, ProtoConfig> {
         Self {
             loader: Mutex::new(Some(loader)),
             config: Mutex::new(None),
+            validate_on_startup: false,
         }
     }
 
@@ -55,6 +58,31 @@ impl<L: Loader<Prototype>> ProtoPlugin<L, ProtoConfig> {
 }
 
 impl<C: Config<Prototype>> ProtoPlugin<ProtoLoader, C> {
+    /// Register an additional file extension to be loaded using the given [format].
+    ///
+    /// This allows prototype files to use a custom extension (e.g. `.myproto`)
+    /// while reusing one of the existing formats.
+    /// The default extensions will continue to work as well.
+    ///
+    /// See [`ProtoLoader::with_extension`] for details.
+    ///
+    /// [format]: ProtoFormat
+    pub fn with_extension(self, extension: &'static str, format: ProtoFormat) -> Self {
+        let loader = self
+            .loader
+            .into_inner()
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+            .with_extension(extension, format);
+
+        Self {
+            loader: Mutex::new(Some(loader)),
+            config: self.config,
+            validate_on_startup: self.validate_on_startup,
+        }
+    }
+
     /// Create a new plugin instance with the given [`Config`].
     ///
     /// Note: If using a custom `Config` type, be sure to use that type as the generic
@@ -67,6 +95,7 @@ impl<C: Config<Prototype>> ProtoPlugin<ProtoLoader, C> {
         Self {
             loader: Mutex::new(None),
             config: Mutex::new(Some(config)),
+            validate_on_startup: false,
         }
     }
 }
@@ -84,13 +113,31 @@ impl<L: Loader<Prototype>, C: Config<Prototype>> ProtoPlugin<L, C> {
         Self {
             loader: Mutex::new(Some(loader)),
             config: Mutex::new(Some(config)),
+            validate_on_startup: false,
         }
     }
 }
 
+impl<L: Loader<Prototype>, C: Config<Prototype>> ProtoPlugin<L, C> {
+    /// Validate all loaded prototypes at startup and fail fast.
+    ///
+    /// Once every prototype queued for loading has finished, they are checked
+    /// with [`Prototypes::validate_all`]. If any failed to load or register,
+    /// the app panics with a report listing every problem.
+    ///
+    /// This is disabled by default and is mainly intended for development builds.
+    ///
+    /// [`Prototypes::validate_all`]: crate::prelude::Prototypes::validate_all
+    pub fn validate_on_startup(mut self, validate: bool) -> Self {
+        self.validate_on_startup = validate;
+        self
+    }
+}
+
 impl<L: Loader<Prototype>, C: Config<Prototype>> Plugin for ProtoPlugin<L, C> {
     fn build(&self, app: &mut App) {
-        let mut plugin = ProtoBackendPlugin::<Prototype, L, C>::new();
+        let mut plugin = ProtoBackendPlugin::<Prototype, L, C>::new()
+            .with_startup_validation(self.validate_on_startup);
 
         if let Ok(Some(config)) = self.config.lock().map(|mut config| config.take()) {
             plugin = plugin.with_config(config);
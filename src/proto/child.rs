@@ -8,8 +8,15 @@ use crate::proto::Prototype;
 /// The child type of a [`Prototype`].
 ///
 /// This can be deserialized either from a struct or a [`ProtoPath`] string.
+///
+/// When deserialized from a struct, an optional `id` may be given.
+/// This local ID must be unique within the file and allows the child's entity
+/// to be referenced from anywhere in the tree using [`ProtoEntity::Local`].
+///
+/// [`ProtoEntity::Local`]: bevy_proto_backend::tree::ProtoEntity::Local
 pub struct ProtoChild {
     pub(crate) merge_key: Option<String>,
+    pub(crate) local_id: Option<String>,
     pub(crate) handle: Handle<Prototype>,
 }
 
@@ -23,6 +30,10 @@ impl PrototypicalChild<Prototype> for ProtoChild {
     fn merge_key(&self) -> Option<&Self::Key> {
         self.merge_key.as_ref()
     }
+
+    fn local_id(&self) -> Option<&str> {
+        self.local_id.as_deref()
+    }
 }
 
 /// The enum representation of a serialized [`Prototype`] child.
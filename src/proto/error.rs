@@ -26,6 +26,10 @@ pub enum PrototypeError {
     #[cfg(feature = "yaml")]
     #[error(transparent)]
     YamlError(#[from] serde_yaml::Error),
+    /// Error decompressing or unpacking a compressed prototype or bundle.
+    #[cfg(feature = "compression")]
+    #[error("could not decompress {0:?}: {1}")]
+    CompressionError(PathBuf, String),
     #[error(transparent)]
     SchematicError(#[from] SchematicError),
 }
@@ -1,27 +1,194 @@
+use std::borrow::Cow;
+
 use crate::proto::ProtoChild;
 use bevy::reflect::{TypePath, TypeUuid};
-use bevy_proto_backend::children::Children;
+use bevy_proto_backend::children::{Children, PrototypicalChild};
 use bevy_proto_backend::deps::Dependencies;
 use bevy_proto_backend::path::ProtoPath;
 use bevy_proto_backend::proto::Prototypical;
 use bevy_proto_backend::schematics::Schematics;
-use bevy_proto_backend::templates::Templates;
+use bevy_proto_backend::templates::{Mixins, Templates};
 
 /// The core asset type used to create easily-configurable entity trees.
+///
+/// # Insert Modes
+///
+/// By default, applying a schematic overwrites any existing component on the entity.
+/// Prefixing a schematic's type name with `?` will instead skip the schematic
+/// if the entity already contains that component:
+///
+/// ```text
+/// (
+///   name: "Defaults",
+///   schematics: {
+///     "?my_game::Health": (100),
+///   },
+/// )
+/// ```
+///
+/// See [`InsertMode`] for details.
+///
+/// # Markers
+///
+/// Zero-sized marker components can be inserted by listing their type names
+/// under `markers`, without needing a full schematic entry for each:
+///
+/// ```text
+/// (
+///   name: "Bat",
+///   markers: ["my_game::Enemy", "my_game::Flying"],
+/// )
+/// ```
+///
+/// Each marker must be registered along with `ReflectComponent` and `ReflectDefault`.
+/// See [`Markers`] for details.
+///
+/// # Conditional Sections
+///
+/// Schematics may be wrapped in a `cfg(<flag>)` section to only include them
+/// when the given flag is active:
+///
+/// ```text
+/// (
+///   name: "Player",
+///   schematics: {
+///     "my_game::Speed": (5.0),
+///     "cfg(platform=web)": {
+///       "my_game::TouchControls": (),
+///     },
+///   },
+/// )
+/// ```
+///
+/// Sections whose flag is not active are skipped entirely at load time.
+/// Flags are arbitrary strings set in code using [`ProtoConfig::with_flag`],
+/// not read from the environment, so that loading stays deterministic.
+///
+/// # Mixins
+///
+/// Named groups of schematics can be included using `include`:
+///
+/// ```text
+/// (
+///   name: "Crate",
+///   include: ["Physics"],
+///   schematics: {
+///     "my_game::Breakable": (),
+///   },
+/// )
+/// ```
+///
+/// Mixins are applied after templates, but before the prototype's own schematics.
+/// See [`ProtoMixins`] for details on defining them.
+///
+/// # Abstract Prototypes
+///
+/// Prototypes that only serve as a base for other prototypes can be marked `abstract`:
+///
+/// ```text
+/// (
+///   name: "Enemy",
+///   abstract: true,
+///   schematics: {
+///     "my_game::Health": (100),
+///   },
+/// )
+/// ```
+///
+/// Abstract prototypes can still be used as templates,
+/// but attempting to spawn or apply one directly will panic.
+///
+/// # Default Templates
+///
+/// Templates added with [`ProtoConfig::with_default_template`] are implicitly inherited
+/// by every prototype, with a lower priority than any template the prototype lists itself.
+/// A prototype can opt out of them by setting `default_templates` to `false`:
+///
+/// ```text
+/// (
+///   name: "Neutral",
+///   default_templates: false,
+/// )
+/// ```
+///
+/// [`InsertMode`]: bevy_proto_backend::schematics::InsertMode
+/// [`Markers`]: bevy_proto_backend::schematics::Markers
+/// [`ProtoMixins`]: bevy_proto_backend::templates::ProtoMixins
+/// [`ProtoConfig::with_flag`]: crate::config::ProtoConfig::with_flag
+/// [`ProtoConfig::with_default_template`]: crate::config::ProtoConfig::with_default_template
 #[derive(Debug, TypeUuid, TypePath)]
 #[uuid = "cbc85a87-723a-4e61-83c7-26e96e54fe9f"]
 pub struct Prototype {
-    pub(crate) id: String,
+    pub(crate) id: Cow<'static, str>,
     pub(crate) path: ProtoPath,
     pub(crate) requires_entity: bool,
+    pub(crate) is_abstract: bool,
+    pub(crate) use_default_templates: bool,
     pub(crate) schematics: Schematics,
     pub(crate) templates: Option<Templates>,
+    pub(crate) mixins: Option<Mixins>,
     pub(crate) dependencies: Dependencies,
     pub(crate) children: Option<Children<Prototype>>,
 }
 
+impl Prototype {
+    /// Returns true if both prototypes define the same content.
+    ///
+    /// Unlike a direct comparison, this ignores differences that do not affect
+    /// how the prototype is applied, making it useful for comparing prototypes
+    /// loaded from different formats or files.
+    ///
+    /// The following are compared:
+    /// * The ID
+    /// * Each schematic (by type, [insert mode], and value using reflection)
+    /// * The paths of the templates (in order)
+    /// * The names of the mixins (in order)
+    /// * The number of children and their merge keys (in order)
+    ///
+    /// The following are _not_ compared:
+    /// * The source path of the prototype
+    /// * The order in which schematics were defined
+    /// * Handle identity (templates are compared by path)
+    /// * The contents of children (these are separate assets and must be compared separately)
+    /// * The preloaded dependencies (these are derived from the schematics)
+    ///
+    /// [insert mode]: bevy_proto_backend::schematics::InsertMode
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        fn template_paths(prototype: &Prototype) -> Vec<&ProtoPath> {
+            prototype
+                .templates
+                .iter()
+                .flat_map(|templates| templates.iter().map(|(path, _)| path))
+                .collect()
+        }
+
+        fn mixin_names(prototype: &Prototype) -> Vec<&str> {
+            prototype.mixins.iter().flat_map(Mixins::iter).collect()
+        }
+
+        fn child_keys(prototype: &Prototype) -> Vec<Option<&String>> {
+            prototype
+                .children
+                .iter()
+                .flat_map(|children| children.iter().map(|child| child.merge_key()))
+                .collect()
+        }
+
+        self.id == other.id
+            && self.requires_entity == other.requires_entity
+            && self.is_abstract == other.is_abstract
+            && self.use_default_templates == other.use_default_templates
+            && self.schematics.semantic_eq(&other.schematics)
+            && template_paths(self) == template_paths(other)
+            && mixin_names(self) == mixin_names(other)
+            && child_keys(self) == child_keys(other)
+    }
+}
+
 impl Prototypical for Prototype {
-    type Id = String;
+    /// Using a [`Cow`] allows IDs given as `&'static str` (e.g. `commands.spawn("Bullet")`)
+    /// to be used without allocating.
+    type Id = Cow<'static, str>;
     type Child = ProtoChild;
 
     fn id(&self) -> &Self::Id {
@@ -36,6 +203,14 @@ impl Prototypical for Prototype {
         self.requires_entity
     }
 
+    fn is_abstract(&self) -> bool {
+        self.is_abstract
+    }
+
+    fn uses_default_templates(&self) -> bool {
+        self.use_default_templates
+    }
+
     fn schematics(&self) -> &Schematics {
         &self.schematics
     }
@@ -52,6 +227,10 @@ impl Prototypical for Prototype {
         self.templates.as_mut()
     }
 
+    fn mixins(&self) -> Option<&Mixins> {
+        self.mixins.as_ref()
+    }
+
     fn dependencies(&self) -> &Dependencies {
         &self.dependencies
     }
@@ -1,19 +1,51 @@
 use std::fmt::Formatter;
 
-use bevy::reflect::serde::{TypeRegistrationDeserializer, TypedReflectDeserializer};
-use bevy::reflect::TypeRegistryInternal;
-use serde::de::{DeserializeSeed, Error, MapAccess, Visitor};
-use serde::Deserializer;
+use bevy::log::warn;
+use bevy::reflect::serde::TypedReflectDeserializer;
+use bevy::reflect::{TypeRegistration, TypeRegistryInternal};
+use bevy::utils::{HashMap, HashSet};
+use serde::de::{DeserializeSeed, Error, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
 
-use bevy_proto_backend::schematics::{ReflectSchematic, Schematics};
+use bevy_proto_backend::schematics::{InsertMode, ReflectSchematic, Schematics};
+
+/// The prefix used on a schematic's type name to mark it as [`InsertMode::KeepExisting`].
+const KEEP_EXISTING_PREFIX: char = '?';
+
+/// The prefix used on a key to mark it as a conditional section.
+const CONDITIONAL_PREFIX: &str = "cfg(";
+/// The suffix used on a key to mark it as a conditional section.
+const CONDITIONAL_SUFFIX: &str = ")";
 
 pub(crate) struct SchematicsDeserializer<'a> {
     registry: &'a TypeRegistryInternal,
+    flags: Option<&'a HashSet<String>>,
+    type_path_aliases: Option<&'a HashMap<String, String>>,
 }
 
 impl<'a> SchematicsDeserializer<'a> {
     pub fn new(registry: &'a TypeRegistryInternal) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            flags: None,
+            type_path_aliases: None,
+        }
+    }
+
+    /// Set the active flags used to resolve conditional sections.
+    ///
+    /// Without this, all conditional sections are skipped.
+    pub fn with_flags(mut self, flags: &'a HashSet<String>) -> Self {
+        self.flags = Some(flags);
+        self
+    }
+
+    /// Set the map of deprecated type paths to their replacements.
+    ///
+    /// Without this, unknown type paths always result in an error.
+    pub fn with_type_path_aliases(mut self, aliases: &'a HashMap<String, String>) -> Self {
+        self.type_path_aliases = Some(aliases);
+        self
     }
 }
 
@@ -26,6 +58,8 @@ impl<'de, 'a> DeserializeSeed<'de> for SchematicsDeserializer<'a> {
     {
         struct SchematicsVisitor<'a> {
             registry: &'a TypeRegistryInternal,
+            flags: Option<&'a HashSet<String>>,
+            type_path_aliases: Option<&'a HashMap<String, String>>,
         }
         impl<'de, 'a> Visitor<'de> for SchematicsVisitor<'a> {
             type Value = Schematics;
@@ -41,9 +75,46 @@ impl<'de, 'a> DeserializeSeed<'de> for SchematicsDeserializer<'a> {
                 let size_hint = map.size_hint().unwrap_or_default();
                 let mut schematics = Schematics::with_capacity(size_hint);
 
-                while let Some(registration) =
-                    map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
-                {
+                while let Some(key) = map.next_key_seed(SchematicKeyDeserializer {
+                    registry: self.registry,
+                    type_path_aliases: self.type_path_aliases,
+                })? {
+                    let (registration, insert_mode) = match key {
+                        SchematicKey::Schematic(registration, insert_mode) => {
+                            (registration, insert_mode)
+                        }
+                        SchematicKey::Conditional(flag) => {
+                            let is_active = self
+                                .flags
+                                .map(|flags| flags.contains(&flag))
+                                .unwrap_or_default();
+
+                            if !is_active {
+                                map.next_value::<IgnoredAny>()?;
+                                continue;
+                            }
+
+                            let section = map.next_value_seed(SchematicsDeserializer {
+                                registry: self.registry,
+                                flags: self.flags,
+                                type_path_aliases: self.type_path_aliases,
+                            })?;
+
+                            for (_, schematic) in section {
+                                if schematics.contains_by_name(schematic.type_info().type_name()) {
+                                    return Err(Error::custom(format_args!(
+                                        "duplicate schematic: `{}`",
+                                        schematic.type_info().type_name()
+                                    )));
+                                }
+
+                                schematics.insert_dynamic(schematic);
+                            }
+
+                            continue;
+                        }
+                    };
+
                     if schematics.contains_by_name(registration.type_name()) {
                         return Err(Error::custom(format_args!(
                             "duplicate schematic: `{}`",
@@ -66,9 +137,10 @@ impl<'de, 'a> DeserializeSeed<'de> for SchematicsDeserializer<'a> {
                         self.registry,
                     ))?;
 
-                    let schematic = reflect_schematic
+                    let mut schematic = reflect_schematic
                         .create_dynamic(input)
                         .map_err(Error::custom)?;
+                    schematic.set_insert_mode(insert_mode);
 
                     schematics.insert_dynamic(schematic);
                 }
@@ -79,10 +151,72 @@ impl<'de, 'a> DeserializeSeed<'de> for SchematicsDeserializer<'a> {
 
         deserializer.deserialize_map(SchematicsVisitor {
             registry: self.registry,
+            flags: self.flags,
+            type_path_aliases: self.type_path_aliases,
         })
     }
 }
 
+/// A key within a map of schematics.
+enum SchematicKey<'a> {
+    /// The registration of a schematic along with its [`InsertMode`].
+    Schematic(&'a TypeRegistration, InsertMode),
+    /// A conditional section that is only included if the given flag is active.
+    Conditional(String),
+}
+
+/// Deserializes a [`SchematicKey`].
+///
+/// Keys of the form `cfg(<flag>)` are treated as [conditional sections].
+/// Type names prefixed with [`KEEP_EXISTING_PREFIX`] use [`InsertMode::KeepExisting`].
+/// Type names that are not registered are resolved using the type path aliases, if any.
+///
+/// [conditional sections]: SchematicKey::Conditional
+struct SchematicKeyDeserializer<'a> {
+    registry: &'a TypeRegistryInternal,
+    type_path_aliases: Option<&'a HashMap<String, String>>,
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for SchematicKeyDeserializer<'a> {
+    type Value = SchematicKey<'a>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let key = String::deserialize(deserializer)?;
+
+        if let Some(flag) = key
+            .strip_prefix(CONDITIONAL_PREFIX)
+            .and_then(|key| key.strip_suffix(CONDITIONAL_SUFFIX))
+        {
+            return Ok(SchematicKey::Conditional(flag.trim().to_string()));
+        }
+
+        let (type_name, insert_mode) = match key.strip_prefix(KEEP_EXISTING_PREFIX) {
+            Some(type_name) => (type_name, InsertMode::KeepExisting),
+            None => (key.as_str(), InsertMode::Overwrite),
+        };
+
+        let registration = self
+            .registry
+            .get_with_name(type_name)
+            .or_else(|| {
+                let alias = self.type_path_aliases?.get(type_name)?;
+                warn!(
+                    "schematic type path `{}` is deprecated: use `{}` instead",
+                    type_name, alias
+                );
+                self.registry.get_with_name(alias)
+            })
+            .ok_or_else(|| {
+                Error::custom(format_args!("no registration found for `{}`", type_name))
+            })?;
+
+        Ok(SchematicKey::Schematic(registration, insert_mode))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bevy::prelude::Component;
@@ -127,6 +261,233 @@ mod tests {
         );
     }
 
+    #[derive(Reflect, Component, Schematic)]
+    #[schematic(input(vis = pub(crate)))]
+    struct MyRenamedSchematic {
+        #[schematic(rename = "hp")]
+        health_points: usize,
+    }
+
+    #[test]
+    fn should_deserialize_renamed_fields() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<MyRenamedSchematic>();
+        registry.register::<MyRenamedSchematicInput>();
+        registry.register_type_data::<MyRenamedSchematic, ReflectSchematic>();
+
+        let input = r#"
+{
+    "bevy_proto::schematics::tests::MyRenamedSchematic": (
+        hp: 123
+    )
+}"#;
+
+        let deserializer = SchematicsDeserializer::new(&registry);
+        let schematics = deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            123,
+            schematics
+                .get::<MyRenamedSchematic>()
+                .unwrap()
+                .input()
+                .downcast_ref::<MyRenamedSchematicInput>()
+                .unwrap()
+                .hp
+        );
+    }
+
+    #[test]
+    fn should_deserialize_insert_mode() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<MySchematic>();
+        registry.register_type_data::<MySchematic, ReflectSchematic>();
+
+        let input = r#"
+{
+    "?bevy_proto::schematics::tests::MySchematic": (
+        foo: 123
+    )
+}"#;
+
+        let deserializer = SchematicsDeserializer::new(&registry);
+        let schematics = deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            InsertMode::KeepExisting,
+            schematics.get::<MySchematic>().unwrap().insert_mode()
+        );
+    }
+
+    #[derive(Reflect, Component, Schematic, Default)]
+    struct MyMovement {
+        speed: f32,
+        jump_height: f32,
+    }
+
+    #[derive(Reflect, Component, Schematic)]
+    #[schematic(input(vis = pub(crate)))]
+    struct MyFlattenedSchematic {
+        name: String,
+        #[schematic(flatten(speed: f32, jump_height: f32))]
+        movement: MyMovement,
+    }
+
+    #[test]
+    fn should_deserialize_flattened_fields() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<MyFlattenedSchematic>();
+        registry.register::<MyFlattenedSchematicInput>();
+        registry.register_type_data::<MyFlattenedSchematic, ReflectSchematic>();
+
+        let input = r#"
+{
+    "bevy_proto::schematics::tests::MyFlattenedSchematic": (
+        name: "Player",
+        speed: 1.5,
+        jump_height: 2.0,
+    )
+}"#;
+
+        let deserializer = SchematicsDeserializer::new(&registry);
+        let schematics = deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+            .unwrap();
+
+        let input = schematics
+            .get::<MyFlattenedSchematic>()
+            .unwrap()
+            .input()
+            .downcast_ref::<MyFlattenedSchematicInput>()
+            .unwrap();
+
+        assert_eq!("Player", input.name);
+        assert_eq!(1.5, input.speed);
+        assert_eq!(2.0, input.jump_height);
+    }
+
+    #[derive(Reflect, Component, Schematic, Eq, PartialEq, Debug)]
+    struct MyGenericSchematic<A, B> {
+        first: A,
+        second: B,
+    }
+
+    #[test]
+    fn should_deserialize_generic_schematics() {
+        type Concrete = MyGenericSchematic<usize, String>;
+
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<Concrete>();
+        registry.register_type_data::<Concrete, ReflectSchematic>();
+
+        let input = r#"
+{
+    "bevy_proto::schematics::tests::MyGenericSchematic<usize, alloc::string::String>": (
+        first: 123,
+        second: "abc",
+    )
+}"#;
+
+        let deserializer = SchematicsDeserializer::new(&registry);
+        let schematics = deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            &MyGenericSchematic {
+                first: 123,
+                second: String::from("abc"),
+            },
+            schematics
+                .get::<Concrete>()
+                .unwrap()
+                .input()
+                .downcast_ref::<Concrete>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_deserialize_conditional_sections() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<MySchematic>();
+        registry.register_type_data::<MySchematic, ReflectSchematic>();
+
+        let input = r#"
+{
+    "cfg(platform=web)": {
+        "bevy_proto::schematics::tests::MySchematic": (
+            foo: 123
+        )
+    }
+}"#;
+
+        let deserializer = SchematicsDeserializer::new(&registry);
+        let schematics = deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+            .unwrap();
+        assert!(schematics.is_empty());
+
+        let flags = HashSet::from_iter([String::from("platform=web")]);
+        let deserializer = SchematicsDeserializer::new(&registry).with_flags(&flags);
+        let schematics = deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+            .unwrap();
+        assert!(schematics.contains::<MySchematic>());
+    }
+
+    #[test]
+    fn should_deserialize_aliased_type_paths() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<MySchematic>();
+        registry.register_type_data::<MySchematic, ReflectSchematic>();
+
+        let input = r#"
+{
+    "my_game::OldSchematic": (
+        foo: 123
+    )
+}"#;
+
+        let deserializer = SchematicsDeserializer::new(&registry);
+        let result = deserializer.deserialize(&mut ron::de::Deserializer::from_str(input).unwrap());
+        assert!(result.is_err());
+
+        let aliases = HashMap::from_iter([(
+            String::from("my_game::OldSchematic"),
+            String::from("bevy_proto::schematics::tests::MySchematic"),
+        )]);
+        let deserializer = SchematicsDeserializer::new(&registry).with_type_path_aliases(&aliases);
+        let schematics = deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+            .unwrap();
+        assert!(schematics.contains::<MySchematic>());
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown field `fooo`")]
+    fn should_not_deserialize_unknown_fields() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<MySchematic>();
+        registry.register_type_data::<MySchematic, ReflectSchematic>();
+
+        let input = r#"
+{
+    "bevy_proto::schematics::tests::MySchematic": (
+        fooo: 123
+    )
+}"#;
+
+        let deserializer = SchematicsDeserializer::new(&registry);
+        deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+            .unwrap();
+    }
+
     #[test]
     #[should_panic(expected = "missing `ReflectSchematic` registration for schematic")]
     fn should_not_deserialize_schematics() {
@@ -145,4 +506,123 @@ mod tests {
             .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
             .unwrap();
     }
+
+    #[derive(Reflect, bevy::prelude::Resource, Schematic)]
+    #[schematic(kind = "resource")]
+    struct MyResourceSchematic(usize);
+
+    #[test]
+    fn should_not_require_entity_for_resource_schematics() {
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<MySchematic>();
+        registry.register_type_data::<MySchematic, ReflectSchematic>();
+        registry.register::<MyResourceSchematic>();
+        registry.register_type_data::<MyResourceSchematic, ReflectSchematic>();
+
+        let input = r#"
+{
+    "bevy_proto::schematics::tests::MySchematic": (
+        foo: 123
+    ),
+    "bevy_proto::schematics::tests::MyResourceSchematic": (123),
+}"#;
+
+        let deserializer = SchematicsDeserializer::new(&registry);
+        let schematics = deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+            .unwrap();
+
+        assert!(schematics.get::<MySchematic>().unwrap().requires_entity());
+        assert!(!schematics
+            .get::<MyResourceSchematic>()
+            .unwrap()
+            .requires_entity());
+    }
+
+    #[derive(bevy::reflect::TypeUuid, bevy::reflect::TypePath)]
+    #[uuid = "3c6a0d54-8f4b-4a5e-b1d2-9e7f6a5c4b3d"]
+    struct MyAsset;
+
+    #[derive(Reflect, Component, Schematic)]
+    #[schematic(input(vis = pub(crate)))]
+    struct MySlot {
+        #[schematic(asset)]
+        item: bevy::asset::Handle<MyAsset>,
+        #[schematic(entity)]
+        target: bevy::prelude::Entity,
+    }
+
+    #[derive(Reflect, Component, Schematic)]
+    #[schematic(input(vis = pub(crate)))]
+    struct MySlots {
+        #[schematic(each)]
+        slots: Vec<MySlot>,
+    }
+
+    #[test]
+    fn should_deserialize_each_fields() {
+        use bevy_proto_backend::assets::ProtoAsset;
+        use bevy_proto_backend::tree::{EntityAccess, ProtoEntity};
+
+        let mut registry = TypeRegistryInternal::new();
+        registry.register::<MySlots>();
+        registry.register::<MySlotsInput>();
+        registry.register::<MySlotInput>();
+        registry.register::<Vec<MySlotInput>>();
+        registry.register::<ProtoAsset<MyAsset>>();
+        registry.register::<EntityAccess>();
+        registry.register_type_data::<MySlots, ReflectSchematic>();
+
+        let input = r#"
+{
+    "bevy_proto::schematics::tests::MySlots": (
+        slots: [
+            (item: AssetPath("a.png"), target: Root),
+            (item: AssetPath("b.png"), target: Parent),
+        ]
+    )
+}"#;
+
+        let deserializer = SchematicsDeserializer::new(&registry);
+        let schematics = deserializer
+            .deserialize(&mut ron::de::Deserializer::from_str(input).unwrap())
+            .unwrap();
+
+        let input = schematics
+            .get::<MySlots>()
+            .unwrap()
+            .input()
+            .downcast_ref::<MySlotsInput>()
+            .unwrap();
+
+        assert_eq!(2, input.slots.len());
+        assert!(matches!(&input.slots[0].item, ProtoAsset::AssetPath(path) if path == "a.png"));
+        assert!(matches!(&input.slots[1].item, ProtoAsset::AssetPath(path) if path == "b.png"));
+        assert_eq!(EntityAccess::from(ProtoEntity::Root), input.slots[0].target);
+        assert_eq!(
+            EntityAccess::from(ProtoEntity::Parent),
+            input.slots[1].target
+        );
+    }
+
+    #[derive(Reflect, Component, Schematic)]
+    #[schematic(input(name = MySerializedInput, vis = pub(crate), derive_serialize))]
+    #[schematic_attr(derive(Debug, PartialEq))]
+    struct MySerialized {
+        name: String,
+        count: u32,
+    }
+
+    #[test]
+    fn should_round_trip_serializable_input() {
+        let input = MySerializedInput {
+            name: String::from("Player"),
+            count: 3,
+        };
+
+        let serialized = ron::to_string(&input).unwrap();
+        let deserialized: MySerializedInput = ron::from_str(&serialized).unwrap();
+
+        assert_eq!(input, deserialized);
+    }
 }
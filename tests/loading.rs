@@ -0,0 +1,567 @@
+//! End-to-end tests that load prototype files through the `AssetServer`.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use bevy::asset::AssetPlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+
+use bevy_proto::prelude::*;
+
+#[derive(Component, Reflect, Schematic, Default, Debug, PartialEq)]
+#[reflect(Schematic)]
+struct Health(u32);
+
+/// Counts the [`ProtoAssetEvent::Modified`] events sent for each prototype.
+#[derive(Resource, Default)]
+struct ModifiedCount(usize);
+
+fn count_modified(mut events: EventReader<ProtoAssetEvent>, mut count: ResMut<ModifiedCount>) {
+    for event in events.iter() {
+        if matches!(event, ProtoAssetEvent::Modified { .. }) {
+            count.0 += 1;
+        }
+    }
+}
+
+/// A temporary asset folder, removed once dropped.
+struct TestAssets(PathBuf);
+
+impl TestAssets {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir()
+            .join(format!("bevy_proto_tests_{}", std::process::id()))
+            .join(name);
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    fn write(&self, file: &str, contents: &str) {
+        let path = self.0.join(file);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+}
+
+impl Drop for TestAssets {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+fn app(assets: &TestAssets) -> App {
+    app_with_config(assets, ProtoConfig::default())
+}
+
+fn app_with_config(assets: &TestAssets, config: ProtoConfig) -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            asset_folder: assets.0.to_string_lossy().into_owned(),
+            watch_for_changes: None,
+        },
+        ProtoPlugin::new().with_config(config),
+    ))
+    .register_type::<Health>()
+    .init_resource::<ModifiedCount>()
+    .add_systems(Update, count_modified);
+    app
+}
+
+/// Update the app until the given condition is met, panicking if it never is.
+fn update_until(app: &mut App, mut condition: impl FnMut(&mut App) -> bool) {
+    for _ in 0..1000 {
+        app.update();
+        if condition(app) {
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    panic!("condition was not met in time");
+}
+
+/// Update the app a number of times, giving any in-flight loads a chance to finish.
+fn settle(app: &mut App) {
+    for _ in 0..50 {
+        app.update();
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+fn is_ready(app: &mut App, id: &str) -> bool {
+    SystemState::<Prototypes>::new(&mut app.world)
+        .get(&app.world)
+        .is_ready(id)
+}
+
+fn load(app: &mut App, path: &str) -> Handle<Prototype> {
+    let mut state = SystemState::<PrototypesMut>::new(&mut app.world);
+    let handle = state.get_mut(&mut app.world).load(path);
+    state.apply(&mut app.world);
+    handle
+}
+
+fn spawn(app: &mut App, id: &str) -> Entity {
+    let mut state = SystemState::<ProtoCommands>::new(&mut app.world);
+    let entity = state.get_mut(&mut app.world).spawn(id).id();
+    state.apply(&mut app.world);
+    entity
+}
+
+#[test]
+fn should_skip_reloading_unchanged_files() {
+    let assets = TestAssets::new("should_skip_reloading_unchanged_files");
+    assets.write(
+        "Player.prototype.ron",
+        r#"(name: "Player", schematics: {"loading::Health": (10)})"#,
+    );
+
+    let mut app = app(&assets);
+    let _handle = load(&mut app, "Player.prototype.ron");
+    update_until(&mut app, |app| is_ready(app, "Player"));
+
+    // Reloading without changing the file should not re-register the prototype
+    app.world
+        .resource::<AssetServer>()
+        .reload_asset("Player.prototype.ron");
+    settle(&mut app);
+    assert_eq!(0, app.world.resource::<ModifiedCount>().0);
+
+    // Changing the file should
+    assets.write(
+        "Player.prototype.ron",
+        r#"(name: "Player", schematics: {"loading::Health": (20)})"#,
+    );
+    app.world
+        .resource::<AssetServer>()
+        .reload_asset("Player.prototype.ron");
+    update_until(&mut app, |app| app.world.resource::<ModifiedCount>().0 > 0);
+    settle(&mut app);
+    assert_eq!(1, app.world.resource::<ModifiedCount>().0);
+
+    let entity = spawn(&mut app, "Player");
+    assert_eq!(Some(&Health(20)), app.world.get::<Health>(entity));
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component, Default)]
+struct Enemy;
+
+#[test]
+fn should_insert_markers() {
+    let assets = TestAssets::new("should_insert_markers");
+    assets.write(
+        "Bat.prototype.ron",
+        r#"(name: "Bat", markers: ["loading::Enemy"])"#,
+    );
+
+    let mut app = app(&assets);
+    app.register_type::<Enemy>();
+    let _handle = load(&mut app, "Bat.prototype.ron");
+    update_until(&mut app, |app| is_ready(app, "Bat"));
+
+    let entity = spawn(&mut app, "Bat");
+    assert!(app.world.get::<Enemy>(entity).is_some());
+}
+
+#[test]
+fn should_only_load_active_conditional_sections() {
+    let assets = TestAssets::new("should_only_load_active_conditional_sections");
+    assets.write(
+        "Player.prototype.ron",
+        r#"(
+            name: "Player",
+            schematics: {
+                "cfg(platform=web)": {
+                    "loading::Health": (10),
+                },
+            },
+        )"#,
+    );
+
+    for (config, expected) in [
+        (ProtoConfig::default(), None),
+        (
+            ProtoConfig::default().with_flag("platform=web"),
+            Some(Health(10)),
+        ),
+    ] {
+        let mut app = app_with_config(&assets, config);
+        let _handle = load(&mut app, "Player.prototype.ron");
+        update_until(&mut app, |app| is_ready(app, "Player"));
+
+        let entity = spawn(&mut app, "Player");
+        assert_eq!(expected.as_ref(), app.world.get::<Health>(entity));
+    }
+}
+
+#[derive(bevy::reflect::TypeUuid, bevy::reflect::TypePath)]
+#[uuid = "5f0c2d7e-9a41-4b8e-a3c6-1d2e3f4a5b6c"]
+struct Item;
+
+#[derive(Component, Reflect, Schematic)]
+struct Slot {
+    #[schematic(asset)]
+    item: Handle<Item>,
+    #[schematic(entity)]
+    target: Entity,
+}
+
+#[derive(Component, Reflect, Schematic)]
+#[reflect(Schematic)]
+struct Inventory {
+    #[schematic(each)]
+    slots: Vec<Slot>,
+}
+
+#[test]
+fn should_spawn_each_fields() {
+    use bevy::asset::AddAsset;
+    use bevy_proto_backend::assets::ProtoAsset;
+
+    let assets = TestAssets::new("should_spawn_each_fields");
+    assets.write(
+        "Player.prototype.ron",
+        r#"(
+            name: "Player",
+            schematics: {
+                "loading::Inventory": (
+                    slots: [(item: AssetPath("sword.item"), target: Root)],
+                ),
+            },
+            children: ["Pet"],
+        )"#,
+    );
+    assets.write(
+        "Pet.prototype.ron",
+        r#"(
+            name: "Pet",
+            schematics: {
+                "loading::Inventory": (
+                    slots: [
+                        (item: AssetPath("bone.item"), target: Parent),
+                        (item: AssetPath("ball.item"), target: Root),
+                    ],
+                ),
+            },
+        )"#,
+    );
+
+    let mut app = app(&assets);
+    app.add_asset::<Item>()
+        .register_type::<Inventory>()
+        .register_type::<SlotInput>()
+        .register_type::<Vec<SlotInput>>()
+        .register_type::<ProtoAsset<Item>>();
+    let _handle = load(&mut app, "Player.prototype.ron");
+    update_until(&mut app, |app| is_ready(app, "Player"));
+
+    let player = spawn(&mut app, "Player");
+    let pet = app.world.get::<Children>(player).unwrap()[0];
+
+    let item = |app: &App, path: &str| {
+        app.world
+            .resource::<AssetServer>()
+            .get_handle::<Item, _>(path)
+            .id()
+    };
+    let slots = |app: &App, entity: Entity| {
+        app.world
+            .get::<Inventory>(entity)
+            .unwrap()
+            .slots
+            .iter()
+            .map(|slot| (slot.item.id(), slot.target))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        vec![(item(&app, "sword.item"), player)],
+        slots(&app, player)
+    );
+    assert_eq!(
+        vec![
+            (item(&app, "bone.item"), player),
+            (item(&app, "ball.item"), player),
+        ],
+        slots(&app, pet)
+    );
+}
+
+#[derive(Component, Reflect, Schematic, Default, Debug, PartialEq)]
+#[reflect(Schematic)]
+struct Speed(u32);
+
+/// Load the prototype at the given path in its own app, returning the app and handle.
+fn load_isolated(assets: &TestAssets, path: &str, id: &str) -> (App, Handle<Prototype>) {
+    let mut app = app(assets);
+    app.register_type::<Speed>();
+    let handle = load(&mut app, path);
+    update_until(&mut app, |app| is_ready(app, id));
+    (app, handle)
+}
+
+#[test]
+fn should_compare_prototypes_semantically() {
+    let assets = TestAssets::new("should_compare_prototypes_semantically");
+    assets.write(
+        "a/Player.prototype.ron",
+        r#"(name: "Player", schematics: {"loading::Health": (10), "loading::Speed": (5)})"#,
+    );
+    assets.write(
+        "b/Player.prototype.ron",
+        r#"(
+            name: "Player",
+            schematics: {
+                "loading::Speed": (5),
+                "loading::Health": (10),
+            },
+        )"#,
+    );
+    assets.write(
+        "c/Player.prototype.ron",
+        r#"(name: "Player", schematics: {"loading::Health": (20), "loading::Speed": (5)})"#,
+    );
+    assets.write(
+        "d/Player.prototype.ron",
+        r#"(name: "Player", schematics: {"?loading::Health": (10), "loading::Speed": (5)})"#,
+    );
+
+    let apps = ["a", "b", "c", "d"]
+        .into_iter()
+        .map(|dir| load_isolated(&assets, &format!("{dir}/Player.prototype.ron"), "Player"))
+        .collect::<Vec<_>>();
+    let prototypes = apps
+        .iter()
+        .map(|(app, handle)| {
+            app.world
+                .resource::<Assets<Prototype>>()
+                .get(handle)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let (a, b, c, d) = (prototypes[0], prototypes[1], prototypes[2], prototypes[3]);
+
+    // Source path and schematic order are ignored
+    assert!(a.semantic_eq(b));
+    assert!(b.semantic_eq(a));
+    // Schematic values and insert modes are not
+    assert!(!a.semantic_eq(c));
+    assert!(!a.semantic_eq(d));
+}
+
+#[derive(Component, Reflect, Schematic)]
+#[reflect(Schematic)]
+struct Held {
+    #[schematic(asset(reload))]
+    item: Handle<Item>,
+    #[schematic(asset)]
+    spare: Handle<Item>,
+}
+
+#[test]
+fn should_reload_marked_assets_of_parent_and_child() {
+    use bevy::asset::AddAsset;
+    use bevy_proto_backend::assets::ProtoAsset;
+
+    let held = |item: &str| {
+        format!(r#"{{"loading::Held": (item: AssetPath("{item}"), spare: AssetPath("{item}"))}}"#)
+    };
+    let player = |item: &str| {
+        format!(
+            r#"(name: "Player", schematics: {}, children: ["Pet"])"#,
+            held(item)
+        )
+    };
+    let pet = |item: &str| format!(r#"(name: "Pet", schematics: {})"#, held(item));
+
+    let assets = TestAssets::new("should_reload_marked_assets_of_parent_and_child");
+    assets.write("Player.prototype.ron", &player("sword.item"));
+    assets.write("Pet.prototype.ron", &pet("bone.item"));
+
+    let mut app = app(&assets);
+    app.add_asset::<Item>()
+        .register_type::<Held>()
+        .register_type::<ProtoAsset<Item>>();
+    let _handle = load(&mut app, "Player.prototype.ron");
+    update_until(&mut app, |app| is_ready(app, "Player"));
+
+    let player_entity = spawn(&mut app, "Player");
+    let pet_entity = app.world.get::<Children>(player_entity).unwrap()[0];
+
+    let item = |app: &App, path: &str| {
+        app.world
+            .resource::<AssetServer>()
+            .get_handle::<Item, _>(path)
+            .id()
+    };
+    let held_items = |app: &App, entity: Entity| {
+        let held = app.world.get::<Held>(entity).unwrap();
+        (held.item.id(), held.spare.id())
+    };
+
+    // Only modify the child prototype
+    assets.write("Pet.prototype.ron", &pet("ball.item"));
+    app.world
+        .resource::<AssetServer>()
+        .reload_asset("Pet.prototype.ron");
+    update_until(&mut app, |app| {
+        held_items(app, pet_entity).0 == item(app, "ball.item")
+    });
+    assert_eq!(item(&app, "bone.item"), held_items(&app, pet_entity).1);
+    assert_eq!(
+        (item(&app, "sword.item"), item(&app, "sword.item")),
+        held_items(&app, player_entity)
+    );
+
+    // Modifying the parent should only update its own marked field
+    assets.write("Player.prototype.ron", &player("axe.item"));
+    app.world
+        .resource::<AssetServer>()
+        .reload_asset("Player.prototype.ron");
+    update_until(&mut app, |app| {
+        held_items(app, player_entity).0 == item(app, "axe.item")
+    });
+    assert_eq!(item(&app, "sword.item"), held_items(&app, player_entity).1);
+    assert_eq!(
+        (item(&app, "ball.item"), item(&app, "bone.item")),
+        held_items(&app, pet_entity)
+    );
+}
+
+#[derive(Component, Reflect, Schematic)]
+#[reflect(Schematic)]
+struct Link {
+    #[schematic(entity)]
+    target: Entity,
+}
+
+#[test]
+fn should_resolve_local_ids_across_files() {
+    let assets = TestAssets::new("should_resolve_local_ids_across_files");
+    assets.write(
+        "Tank.prototype.ron",
+        r#"(
+            name: "Tank",
+            children: [(id: "mount", value: Path("Mount")), "Turret"],
+        )"#,
+    );
+    assets.write("Mount.prototype.ron", r#"(name: "Mount")"#);
+    assets.write(
+        "Turret.prototype.ron",
+        r#"(name: "Turret", schematics: {"loading::Link": (target: Local("mount"))})"#,
+    );
+
+    let mut app = app(&assets);
+    app.register_type::<Link>();
+    let _handle = load(&mut app, "Tank.prototype.ron");
+    update_until(&mut app, |app| is_ready(app, "Tank"));
+
+    let tank = spawn(&mut app, "Tank");
+    let children = app.world.get::<Children>(tank).unwrap();
+    let (mount, turret) = (children[0], children[1]);
+    assert_eq!(mount, app.world.get::<Link>(turret).unwrap().target);
+}
+
+#[test]
+fn should_reject_duplicate_local_ids_across_files() {
+    let assets = TestAssets::new("should_reject_duplicate_local_ids_across_files");
+    assets.write(
+        "Tank.prototype.ron",
+        r#"(
+            name: "Tank",
+            children: [(id: "mount", value: Path("Mount")), "Turret"],
+        )"#,
+    );
+    assets.write("Mount.prototype.ron", r#"(name: "Mount")"#);
+    assets.write(
+        "Turret.prototype.ron",
+        r#"(name: "Turret", children: [(id: "mount", value: Path("Mount"))])"#,
+    );
+
+    let mut app = app(&assets);
+    let _handle = load(&mut app, "Tank.prototype.ron");
+    update_until(&mut app, |app| is_ready(app, "Turret"));
+    settle(&mut app);
+
+    // Each file is valid on its own, but not once combined into a single tree
+    assert!(!is_ready(&mut app, "Tank"));
+}
+
+#[test]
+fn should_fail_to_load_duplicate_local_ids_in_file() {
+    use bevy::asset::LoadState;
+
+    let assets = TestAssets::new("should_fail_to_load_duplicate_local_ids_in_file");
+    assets.write(
+        "Tank.prototype.ron",
+        r#"(
+            name: "Tank",
+            children: [
+                (id: "mount", value: Path("Mount")),
+                (id: "mount", value: Path("Mount")),
+            ],
+        )"#,
+    );
+    assets.write("Mount.prototype.ron", r#"(name: "Mount")"#);
+
+    let mut app = app(&assets);
+    let handle = load(&mut app, "Tank.prototype.ron");
+    update_until(&mut app, |app| {
+        app.world.resource::<AssetServer>().get_load_state(&handle) == LoadState::Failed
+    });
+    assert!(!is_ready(&mut app, "Tank"));
+}
+
+#[derive(Component, Reflect, Schematic)]
+#[reflect(Schematic)]
+struct Frames {
+    #[schematic(asset(preload, collection, path = "frames"))]
+    frames: Vec<Handle<Item>>,
+}
+
+#[test]
+fn should_resolve_preloaded_collections_when_loading() {
+    use bevy::asset::AddAsset;
+
+    let assets = TestAssets::new("should_resolve_preloaded_collections_when_loading");
+    assets.write(
+        "Player.prototype.ron",
+        r#"(name: "Player", schematics: {"loading::Frames": ()})"#,
+    );
+    for frame in ["1", "2", "10"] {
+        assets.write(&format!("frames/{frame}.item"), "");
+    }
+
+    let mut app = app(&assets);
+    app.add_asset::<Item>().register_type::<Frames>();
+    let _handle = load(&mut app, "Player.prototype.ron");
+    update_until(&mut app, |app| is_ready(app, "Player"));
+
+    // The folder should not be read again when applying the prototype
+    std::fs::remove_dir_all(assets.0.join("frames")).unwrap();
+
+    let player = spawn(&mut app, "Player");
+    let frames = app
+        .world
+        .get::<Frames>(player)
+        .unwrap()
+        .frames
+        .iter()
+        .map(Handle::id)
+        .collect::<Vec<_>>();
+
+    let asset_server = app.world.resource::<AssetServer>();
+    assert_eq!(
+        vec![
+            asset_server.get_handle::<Item, _>("frames/1.item").id(),
+            asset_server.get_handle::<Item, _>("frames/2.item").id(),
+            asset_server.get_handle::<Item, _>("frames/10.item").id(),
+        ],
+        frames
+    );
+}